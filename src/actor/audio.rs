@@ -1,11 +1,14 @@
 //! TODO
 
 //---------------------------------------------------------------------------------------------------- Use
-use crossbeam::channel::{Receiver, Select, Sender};
-use symphonia::core::{audio::AudioBuffer, units::Time};
+use crossbeam::channel::{Receiver, RecvTimeoutError, Sender};
+use symphonia::core::{
+	audio::{AudioBuffer,SampleBuffer,SignalSpec},
+	units::Time,
+};
 use std::{
-	thread::JoinHandle,
-	time::Duration,
+	borrow::Cow,
+	time::{Duration,Instant},
 	sync::{
 		Arc,
 		Barrier,
@@ -14,16 +17,22 @@ use std::{
 };
 use crate::{
 	actor::actor::Actor,
+	free::RealtimeHandle,
 	state::AtomicState,
-	output::AudioOutput,
+	config::DeviceLoss,
+	signal::Volume,
+	output::{AudioOutput,OutputBackend,OpenConfig,Latency,Glitch,GlitchDetector,Sink,AUDIO_SAMPLE_BUFFER_LEN},
 	error::OutputError,
 	macros::error2,
 	actor::{kernel::KernelToAudio, decode::DecodeToAudio},
-	macros::{debug2,try_send,select_recv,recv,trace2},
+	macros::{debug2,info2,try_send,recv,trace2,stats},
 };
 
+#[cfg(feature = "stats")]
+use crate::{state::AtomicStats, output::render_spec};
+
 // Audio I/O backend.
-use crate::output::OutputStruct;
+use crate::output::AudioOutputStruct;
 
 // Resampler backend.
 use crate::resampler::ResamplerStruct;
@@ -44,6 +53,10 @@ pub(crate) const AUDIO_BUFFER_LEN: usize = 64;
 /// Actor name.
 const ACTOR: &str = "Audio";
 
+/// How often the device watcher thread checks
+/// if the default output device has changed.
+const DEVICE_CHECK: Duration = Duration::from_secs(1);
+
 //---------------------------------------------------------------------------------------------------- Audio
 /// TODO
 #[allow(clippy::missing_docs_in_private_items)]
@@ -55,8 +68,18 @@ pub(crate) struct Audio<Output: AudioOutput> {
 	elapsed_audio_state: f32,              // Elapsed time, used for the `atomic_state.elapsed_refresh_rate`
 	ready_to_recv:       Arc<AtomicBool>,  // [Audio]'s way of telling [Decode] it is ready for samples
 	output:              Output,           // Audio hardware/server connection
+	backends:            Cow<'static, [OutputBackend]>, // Ordered list of backends to try when opening `output`
+	device:              Option<String>,   // The `OutputDevice::id` of the device we're using (`None` == default)
+	device_loss:         DeviceLoss,       // What to do when the output device is lost/changed
+	device_lost:         bool,             // Was the output device lost and we are retrying?
+	default_changed:     Arc<AtomicBool>,  // Set by the device watcher when the default device changed
+	_device_watcher:     Option<Sender<()>>, // Dropping this stops the device watcher thread
+	audio_retry:         Duration,         // How long to wait in-between re-opening a lost device
+	open_config:         OpenConfig,       // Settings used when opening `output`
+	bit_perfect_active:  bool,             // Is our current `output` bit-perfect?
+	realtime:            Option<RealtimeHandle>, // Our real-time promotion, `None` if not requested
+	glitches:            GlitchDetector,   // Detects glitches in-between our writes to `output`
 	barrier:             Arc<Barrier>,     // Init/Shutdown barrier between all actors
-	shutdown_blocking:   bool,
 }
 
 //---------------------------------------------------------------------------------------------------- Channels
@@ -85,6 +108,13 @@ pub(crate) enum AudioToKernel {
 	/// We have already written the last audio buffer
 	/// and sent it.
 	EndOfTrack,
+	/// The output device was lost and `DeviceLoss::Pause`
+	/// is being used, pause and forward the error.
+	DeviceLost(OutputError),
+	/// Whether the output path is now bit-perfect or not.
+	///
+	/// This is only sent when it changes.
+	BitPerfect(bool),
 }
 
 //---------------------------------------------------------------------------------------------------- Audio Impl
@@ -94,6 +124,12 @@ pub(crate) struct InitArgs {
 	pub(crate) atomic_state:      Arc<AtomicState>,
 	pub(crate) ready_to_recv:     Arc<AtomicBool>,
 	pub(crate) audio_retry:       Duration,
+	pub(crate) output_backends:   Cow<'static, [OutputBackend]>,
+	pub(crate) output_device:     Option<String>,
+	pub(crate) device_loss:       DeviceLoss,
+	pub(crate) output_sink:       Option<Box<dyn Sink + Send>>,
+	pub(crate) open_config:       OpenConfig,
+	pub(crate) realtime:          bool,
 	pub(crate) to_gc:             Sender<AudioBuffer<f32>>,
 	pub(crate) to_caller_elapsed: Option<(Sender<Time>, f32)>, // seconds
 	pub(crate) from_decode:       Receiver<DecodeToAudio>,
@@ -103,7 +139,8 @@ pub(crate) struct InitArgs {
 }
 
 //---------------------------------------------------------------------------------------------------- Actor
-impl<Output: AudioOutput> Actor for Audio<Output> {
+// `InitConfig::output_sink` is only supported by `AudioOutputStruct`.
+impl Actor for Audio<AudioOutputStruct<ResamplerStruct>> {
 	const NAME: &'static str = "Audio";
 
 	type MainArgs = Channels;
@@ -121,6 +158,12 @@ impl<Output: AudioOutput> Actor for Audio<Output> {
 			atomic_state,
 			ready_to_recv,
 			audio_retry,
+			output_backends,
+			output_device,
+			device_loss,
+			output_sink,
+			open_config,
+			realtime,
 			to_gc,
 			to_caller_elapsed,
 			from_decode,
@@ -138,28 +181,51 @@ impl<Output: AudioOutput> Actor for Audio<Output> {
 			to_kernel_error,
 		};
 
-		// TODO:
-		// obtain audio output depending on user config, hang, try again, etc.
-		let audio_retry_secs = audio_retry.as_secs_f32();
-		let output: OutputStruct<ResamplerStruct> = loop {
-			match OutputStruct::dummy() {
-				Ok(output) => break output,
+		// If the output can't be opened, the `dummy` backend is used
+		// in the meanwhile, and the real output is re-opened every
+		// `audio_retry` by `run()`, which still handles messages
+		// (e.g. shutdown, switching devices) while it does so.
+		let mut device_lost = false;
+		let output = if let Some(sink) = output_sink {
+			// The user gave us their own output, always use it.
+			let (output, result) = AudioOutputStruct::custom(sink);
+			if let Err(e) = result {
+				debug2!("{ACTOR} (init) - custom output failed: {e}");
+				try_send!(channels.to_kernel_error, e);
+			}
+			output
+		} else {
+			match AudioOutputStruct::dummy(&output_backends, output_device.as_deref(), open_config) {
+				Ok(output) => output,
 				Err(e) => {
-					debug2!("{ACTOR} (init) - output failed: {e}, sleeping for: {audio_retry_secs}s");
-					channels.to_kernel_error.try_send(e);
-
-					// We need to make sure we don't infinitely
-					// loop and ignore shutdown signals, so handle them.
-					if matches!(channels.from_kernel.try_recv(), Ok(KernelToAudio::Shutdown)) {
-						crate::free::shutdown(ACTOR, shutdown_blocking, barrier);
-						return;
-					}
-
-					std::thread::sleep(audio_retry);
+					debug2!("{ACTOR} (init) - output failed: {e}, retrying every: {}s", audio_retry.as_secs_f32());
+					try_send!(channels.to_kernel_error, e);
+					device_lost = true;
+					// INVARIANT: the `dummy` backend is always compiled in and never fails.
+					AudioOutputStruct::dummy(&[OutputBackend::Dummy], None, open_config).unwrap()
 				},
 			}
 		};
 
+		atomic_state.output_backend.store(Some(output.backend()));
+		atomic_state.output_format.store(Some(output.sample_format()));
+		atomic_state.output_latency.store(latency_micros(output.latency()));
+
+		// Now that we know the output's spec, promote ourselves.
+		let realtime = realtime.then(|| {
+			let sample_rate = output.signal_spec().rate;
+			let frames = open_config.latency.frames(sample_rate);
+			let handle = crate::free::realtime(ACTOR, frames, sample_rate);
+			atomic_state.priority_audio.store(handle.priority());
+			handle
+		});
+
+		// Watch the default device on another thread, querying
+		// devices is far too slow for the real-time `Audio` thread.
+		let default_changed = Arc::new(AtomicBool::new(false));
+		let device_watcher = (output.backend() != OutputBackend::Custom && device_loss == DeviceLoss::Migrate)
+			.then(|| device_watcher(Arc::clone(&atomic_state), Arc::clone(&default_changed)));
+
 		let this = Audio {
 			atomic_state,
 			playing: false,
@@ -167,68 +233,51 @@ impl<Output: AudioOutput> Actor for Audio<Output> {
 			elapsed_audio_state: 0.0,
 			ready_to_recv,
 			output,
+			backends: output_backends,
+			device: output_device,
+			device_loss,
+			device_lost,
+			default_changed,
+			_device_watcher: device_watcher,
+			audio_retry,
+			open_config,
+			bit_perfect_active: false,
+			realtime,
+			glitches: GlitchDetector::default(),
 			barrier,
-			shutdown_blocking,
 		};
 
 		(this, channels)
 	}
 
 	#[cold] #[inline(never)]
-	#[allow(clippy::ignored_unit_patterns)]
-	fn main(self, _: Self::MainArgs) -> Arc<Barrier> {
-		let mut select = Select::new();
-
-		assert_eq!(0, select.recv(&self.from_audio));
-		assert_eq!(1, select.recv(&self.from_decode));
-		assert_eq!(2, select.recv(&self.from_kernel));
-		assert_eq!(3, select.recv(&self.shutdown));
-
-		// Reduce [Gc] to the lowest thread priority.
-		lpt::lpt();
-
-		// Loop, receive garbage, and immediately drop it.
-		loop {
-			match select.ready() {
-				0 => drop(select_recv!(self.from_audio)),
-				1 => drop(select_recv!(self.from_decode)),
-				2 => drop(select_recv!(self.from_kernel)),
-				3 => {
-					select_recv!(self.shutdown);
-					 return self.barrier;
-				},
-
-				_ => unreachable!(),
-			}
-		}
+	fn main(self, c: Self::MainArgs) -> Arc<Barrier> {
+		// INVARIANT: unlike `Gc` and `Caller`, we must not
+		// lower our priority, `init()` may have promoted us.
+		self.run(c)
 	}
 }
 
 //---------------------------------------------------------------------------------------------------- Audio Impl
 impl<Output: AudioOutput> Audio<Output> {
-	//---------------------------------------------------------------------------------------------------- Init
-	#[cold]
-	#[inline(never)]
-	/// Initialize `Audio`.
-	pub(crate) fn init(args: InitArgs) -> Result<JoinHandle<()>, std::io::Error> {
-		std::thread::Builder::new()
-			.name(ACTOR.into())
-			.spawn(move || {
-
-				crate::free::init(ACTOR, init_blocking, &this.barrier);
-
-				Audio::main(this, channels);
-			})
-	}
-
 	//---------------------------------------------------------------------------------------------------- Main Loop
 	#[cold]
 	#[inline(never)]
-	/// `Audio`'s main function.
-	fn main(mut self, c: Channels) {
+	/// `Audio`'s main loop, returns the shutdown barrier.
+	fn run(mut self, c: Channels) -> Arc<Barrier> {
 		loop {
 			// Attempt to receive signal from other actors.
-			let msg_result: Result<KernelToAudio, ()> = if self.atomic_state.playing.load(Ordering::Acquire) {
+			let msg_result: Result<KernelToAudio, ()> = if self.device_lost {
+				// Our output device was lost, try re-opening
+				// it every `audio_retry` while still handling
+				// messages (e.g. shutdown, switching devices).
+				if let Ok(msg) = c.from_kernel.recv_timeout(self.audio_retry) {
+					Ok(msg)
+				} else {
+					self.device_retry(&c);
+					continue;
+				}
+			} else if self.atomic_state.playing.load(Ordering::Acquire) {
 				if let Ok(msg) = c.from_decode.try_recv() {
 					match msg {
 						DecodeToAudio::Buffer(data) => self.play_audio_buffer(data, &c),
@@ -240,9 +289,12 @@ impl<Output: AudioOutput> Audio<Output> {
 			} else {
 				// Flush audio.
 				if let Err(output_error) = self.output.stop() {
-					try_send!(c.to_kernel_error, output_error);
+					self.output_error(output_error, &c);
 				}
 
+				// Running out of audio is expected once we've stopped.
+				self.glitches.reset(self.output.underruns());
+
 				// Else, hang until we receive a message from somebody.
 				debug2!("{ACTOR} - waiting for msgs on select.ready()");
 				c.from_kernel.recv().map_err(|_e| ())
@@ -255,16 +307,19 @@ impl<Output: AudioOutput> Audio<Output> {
 			// Route signal to its appropriate handler function [fn_*()].
 			match msg {
 				KernelToAudio::StartPlaying => {
+					// We'll start playing once the device is re-opened.
+					if self.device_lost {
+						continue;
+					}
 					if let Err(output_error) = self.output.play() {
-						try_send!(c.to_kernel_error, output_error);
+						self.output_error(output_error, &c);
 					}
 					continue;
 				},
 				KernelToAudio::DiscardAudio => self.discard_audio(&c.from_decode, &c.to_gc),
-				KernelToAudio::Shutdown => {
-					crate::free::shutdown(ACTOR, self.shutdown_blocking, self.barrier);
-					return;
-				},
+				KernelToAudio::OutputDevice(device, to_engine) => self.output_device(device, &to_engine),
+				KernelToAudio::OutputLatency(latency, to_engine) => self.output_latency(latency, &to_engine),
+				KernelToAudio::Shutdown => return self.barrier,
 			}
 		}
 	}
@@ -320,46 +375,97 @@ impl<Output: AudioOutput> Audio<Output> {
 
 		// If the spec/duration is different, we must re-open a
 		// matching audio output device or audio will get weird.
-		let output_spec     = self.output.spec();
+		let output_spec     = self.output.signal_spec();
 		let output_duration = self.output.duration();
 		if spec != *output_spec || duration != output_duration {
 			debug2!("{ACTOR} - diff in spec ({spec:?} - {output_spec:?}) and/or duration ({duration} - {output_duration}), re-opening AudioOutput");
 
-			match AudioOutput::try_open(
-				"TODO".to_string(), // TODO: name
-				spec,
-				duration,
-				false, // TODO: disable_device_switch
-				None,  // TODO: buffer_milliseconds
-			) {
-				// TODO: this isn't real-time safe...!
-				//
-				// We're dropping our old `Output` which
-				// contains `Vec`'s, channels, etc.
-				//
-				// Sending this to `Gc` is hard since some
-				// things within the `Output` struct aren't `Send`...
-				//
-				// The buffer could just be very big to compensate for this.
-				//
-				// We can also just use a pre-allocated memory pool,
-				// or make `AudioOutput` return the buffers which we re-use.
-				Ok(o) => self.output = o,
-
-				// And if we couldn't, tell `Kernel` we errored.
-				Err(output_error) => {
-					error2!("{ACTOR} - couldn't re-open AudioOutput: {output_error:?}");
-					try_send!(c.to_kernel_error, output_error);
+			// Stick to the backend we're already using.
+			let backend = [self.output.backend()];
+			match self.output.respec(spec, duration) {
+				// The output changed spec in-place, no need to re-open.
+				Some(Ok(())) => (),
+				Some(Err(output_error)) => {
+					error2!("{ACTOR} - couldn't change AudioOutput spec: {output_error:?}");
+					self.output_error(output_error, c);
 					return;
 				},
+				None => match Self::try_open(
+					&backend,
+					self.device.as_deref(),
+					self.disable_device_switch(),
+					spec,
+					duration,
+					self.open_config,
+				) {
+					// TODO: this isn't real-time safe...!
+					//
+					// We're dropping our old `Output` which
+					// contains `Vec`'s, channels, etc.
+					//
+					// Sending this to `Gc` is hard since some
+					// things within the `Output` struct aren't `Send`...
+					//
+					// The buffer could just be very big to compensate for this.
+					//
+					// We can also just use a pre-allocated memory pool,
+					// or make `AudioOutput` return the buffers which we re-use.
+					//
+					// We're playing, so the new `Output` must be too, else
+					// backends that only consume samples while playing hang.
+					Ok(mut o) => {
+						if let Err(output_error) = o.play() {
+							self.output_error(output_error, c);
+							return;
+						}
+						self.set_output(o);
+					},
+
+					// And if we couldn't, tell `Kernel` we errored.
+					Err(output_error) => {
+						error2!("{ACTOR} - couldn't re-open AudioOutput: {output_error:?}");
+						self.output_error(output_error, c);
+						return;
+					},
+				},
 			}
 		}
 
-		let volume = self.atomic_state.volume.load();
+		// In bit-perfect mode, volume is locked at unity
+		// (multiplying by `1.0` leaves the samples untouched).
+		let volume = if self.open_config.bit_perfect {
+			self.bit_perfect_update(&c.to_kernel);
+			Volume::ONE
+		} else {
+			self.atomic_state.volume.load()
+		};
 
 		// Write audio buffer (hangs).
-		if let Err(output_error) = self.output.write(audio, volume, &c.to_gc) {
-			try_send!(c.to_kernel_error, output_error);
+		let start = Instant::now();
+		if let Err(output_error) = self.output.write_audio(audio, volume, &c.to_gc) {
+			self.output_error(output_error, c);
+			return;
+		}
+		self.glitch_check(start, Duration::from_secs_f32(nominal_seconds), &c.to_kernel_error);
+
+		stats! {
+			let resampling = self.output.resampling();
+			let stats = &self.atomic_state.stats;
+			AtomicStats::inc(&stats.messages_audio);
+			stats.audio_backlog.store(c.from_decode.len(), Ordering::Relaxed);
+			stats.store_output_spec(render_spec(&spec), resampling);
+		}
+
+		// If we're following the default device,
+		// migrate when the device watcher says it changed.
+		if self.default_changed.load(Ordering::Acquire) {
+			self.default_changed.store(false, Ordering::Release);
+			if self.device.is_none() && self.device_loss == DeviceLoss::Migrate {
+				debug2!("{ACTOR} - default device changed, migrating");
+				if !self.device_migrate() {
+					error2!("{ACTOR} - couldn't migrate to new default device");
+				}
+			}
 		}
 	}
 
@@ -389,6 +495,277 @@ impl<Output: AudioOutput> Audio<Output> {
 		try_send!(to_kernel, AudioToKernel::EndOfTrack);
 	}
 
+	#[cold]
+	#[inline(never)]
+	/// Switch to a different audio output device.
+	///
+	/// This opens a new connection to `device` with the same
+	/// spec as our current output, and only replaces our
+	/// current output if that succeeds.
+	///
+	/// The result is sent directly back to `Engine`.
+	fn output_device(
+		&mut self,
+		device: Option<String>,
+		to_engine: &Sender<Result<(), OutputError>>,
+	) {
+		debug2!("{ACTOR} - output_device(), device: {device:?}");
+
+		// The user's `Sink` is our only output, it has no devices.
+		if self.output.backend() == OutputBackend::Custom {
+			try_send!(to_engine, Err(OutputError::BackendUnavailable));
+			return;
+		}
+
+		let spec     = self.output.signal_spec();
+		let duration = self.output.duration();
+
+		let disable_device_switch = device.is_some() || self.device_loss != DeviceLoss::Migrate;
+		let result = Self::try_open(
+			&self.backends,
+			device.as_deref(),
+			disable_device_switch,
+			spec,
+			duration,
+			self.open_config,
+		);
+
+		let mut output = match result {
+			Ok(o) => o,
+			Err(output_error) => {
+				error2!("{ACTOR} - couldn't open output device {device:?}: {output_error:?}");
+				try_send!(to_engine, Err(output_error));
+				return;
+			},
+		};
+
+		// The audio already buffered in the old device is
+		// discarded, continue playing on the new device.
+		self.output.discard();
+		if self.atomic_state.playing.load(Ordering::Acquire) {
+			if let Err(output_error) = output.play() {
+				try_send!(to_engine, Err(output_error));
+				return;
+			}
+		}
+
+		self.set_output(output);
+		self.device = device;
+		self.device_lost = false;
+		try_send!(to_engine, Ok(()));
+	}
+
+	#[cold]
+	#[inline(never)]
+	/// Re-open our current output device with a different latency.
+	///
+	/// Like `output_device()`, our current output is only
+	/// replaced if the new one opens successfully.
+	///
+	/// The result is sent directly back to `Engine`.
+	fn output_latency(
+		&mut self,
+		latency: Latency,
+		to_engine: &Sender<Result<(), OutputError>>,
+	) {
+		debug2!("{ACTOR} - output_latency(), latency: {latency:?}");
+
+		// We have no control over the user's `Sink` buffering.
+		if self.output.backend() == OutputBackend::Custom {
+			try_send!(to_engine, Err(OutputError::BackendUnavailable));
+			return;
+		}
+
+		let old = self.open_config.latency;
+		self.open_config.latency = latency;
+
+		// The audio already buffered is discarded, same as a device switch.
+		if let Err(output_error) = self.device_reopen() {
+			error2!("{ACTOR} - couldn't re-open with latency {latency:?}: {output_error:?}");
+			self.open_config.latency = old;
+			try_send!(to_engine, Err(output_error));
+			return;
+		}
+
+		try_send!(to_engine, Ok(()));
+	}
+
+	//---------------------------------------------------------------------------------------------------- Device loss
+	/// Should the backend ignore device switches?
+	///
+	/// If we're using the default device and migrating, the backend
+	/// is allowed to follow the default device itself, else we handle it.
+	fn disable_device_switch(&self) -> bool {
+		self.device.is_some() || self.device_loss != DeviceLoss::Migrate
+	}
+
+	/// Handle an `OutputError`.
+	///
+	/// If this error means our device was lost, act
+	/// upon our `DeviceLoss` policy, else forward it to `Kernel`.
+	fn output_error(&mut self, output_error: OutputError, c: &Channels) {
+		// There is no device to lose with a user's `Sink`, it handles that itself.
+		if self.output.backend() == OutputBackend::Custom
+			|| !matches!(output_error, OutputError::DeviceUnavailable | OutputError::StreamClosed)
+		{
+			try_send!(c.to_kernel_error, output_error);
+			return;
+		}
+
+		error2!("{ACTOR} - output device lost: {output_error:?}, policy: {}", self.device_loss);
+
+		match self.device_loss {
+			DeviceLoss::Migrate => {
+				if !self.device_migrate() {
+					try_send!(c.to_kernel_error, output_error);
+					self.device_lost = true;
+				}
+			},
+			DeviceLoss::Pause => try_send!(c.to_kernel, AudioToKernel::DeviceLost(output_error)),
+			DeviceLoss::Retry => {
+				try_send!(c.to_kernel_error, output_error);
+				self.device_lost = true;
+			},
+		}
+	}
+
+	/// Re-open our output on the default device.
+	///
+	/// Returns `true` on success.
+	fn device_migrate(&mut self) -> bool {
+		self.device = None;
+		self.device_reopen().is_ok()
+	}
+
+	/// Retry re-opening our lost output device.
+	///
+	/// Errors are forwarded to `Kernel` on each failure.
+	fn device_retry(&mut self, c: &Channels) {
+		debug2!("{ACTOR} - device_retry(), device: {:?}", self.device);
+
+		match self.device_reopen() {
+			Ok(()) => self.device_lost = false,
+			Err(output_error) => try_send!(c.to_kernel_error, output_error),
+		}
+	}
+
+	/// Re-open `self.device` with our current
+	/// spec, resuming playback if we were playing.
+	fn device_reopen(&mut self) -> Result<(), OutputError> {
+		let spec     = self.output.signal_spec();
+		let duration = self.output.duration();
+
+		let mut output = Self::try_open(
+			&self.backends,
+			self.device.as_deref(),
+			self.disable_device_switch(),
+			spec,
+			duration,
+			self.open_config,
+		)?;
+
+		if self.atomic_state.playing.load(Ordering::Acquire) {
+			output.play()?;
+		}
+
+		self.set_output(output);
+		Ok(())
+	}
+
+	//---------------------------------------------------------------------------------------------------- Output
+	#[cold]
+	#[inline(never)]
+	/// Open a new `Output` matching `spec` and `duration`,
+	/// trying each of `backends` in order.
+	///
+	/// TODO: this isn't real-time safe, new buffers are allocated
+	/// each time, ideally the old `Output`'s buffers are re-used.
+	fn try_open(
+		backends: &[OutputBackend],
+		device: Option<&str>,
+		disable_device_switch: bool,
+		spec: SignalSpec,
+		duration: u64,
+		open_config: OpenConfig,
+	) -> Result<Output, OutputError> {
+		Output::try_open_backends(
+			backends,
+			device,
+			spec,
+			duration,
+			disable_device_switch,
+			open_config,
+			SampleBuffer::new(duration, spec),
+			Vec::with_capacity(AUDIO_SAMPLE_BUFFER_LEN),
+			Arc::new(AtomicBool::new(false)),
+			None,
+		)
+	}
+
+	/// Replace our current `Output`, and report which backend,
+	/// sample format and latency is now being used.
+	fn set_output(&mut self, output: Output) {
+		let backend = output.backend();
+		if backend != self.output.backend() {
+			info2!("{ACTOR} - audio output backend: [{backend}]");
+		}
+		self.atomic_state.output_backend.store(Some(backend));
+		self.atomic_state.output_format.store(Some(output.sample_format()));
+		self.atomic_state.output_latency.store(latency_micros(output.latency()));
+		self.output = output;
+		self.glitches.reset(self.output.underruns());
+
+		// Our deadlines change with the rate and latency, re-promote.
+		if let Some(realtime) = self.realtime.as_mut() {
+			let sample_rate = self.output.signal_spec().rate;
+			if let Some(priority) = realtime.update(self.open_config.latency.frames(sample_rate), sample_rate) {
+				self.atomic_state.priority_audio.store(priority);
+			}
+		}
+	}
+
+	#[inline]
+	/// Check the write to our `Output` that started at `start`
+	/// and lasts `audio` for glitches, count and report them.
+	fn glitch_check(
+		&mut self,
+		start: Instant,
+		audio: Duration,
+		to_kernel_error: &Sender<OutputError>,
+	) {
+		let (underruns, overruns, late_writes) = self.glitches.write(
+			start,
+			Instant::now(),
+			audio,
+			self.output.latency(),
+			self.output.underruns(),
+		);
+
+		for (count, counter, glitch) in [
+			(underruns,   &self.atomic_state.underruns,   Glitch::Underrun),
+			(overruns,    &self.atomic_state.overruns,    Glitch::Overrun),
+			(late_writes, &self.atomic_state.late_writes, Glitch::LateWrite),
+		] {
+			if count != 0 {
+				debug2!("{ACTOR} - glitch: {glitch} x{count}");
+				counter.fetch_add(count, Ordering::Relaxed);
+				try_send!(to_kernel_error, OutputError::Glitch(glitch));
+			}
+		}
+	}
+
+	#[inline]
+	/// Check if our current `Output` is bit-perfect,
+	/// and tell `Kernel` if that changed.
+	fn bit_perfect_update(&mut self, to_kernel: &Sender<AudioToKernel>) {
+		let active = self.output.bit_perfect();
+		if active != self.bit_perfect_active {
+			debug2!("{ACTOR} - bit-perfect: {active}");
+			self.bit_perfect_active = active;
+			try_send!(to_kernel, AudioToKernel::BitPerfect(active));
+		}
+	}
+
 	#[inline]
 	/// Discard and all the audio available, _do not_ play it.
 	fn discard_audio(
@@ -414,6 +791,9 @@ impl<Output: AudioOutput> Audio<Output> {
 		self.elapsed_callback = 0.0;
 		self.elapsed_audio_state = 0.0;
 
+		// The gap until the next write is expected.
+		self.glitches.reset(self.output.underruns());
+
 		// `Time` is just `u64` + `f64`.
 		// Doesn't make sense sending stack variables to GC.
 		while let Ok(msg) = from_decode.try_recv() {
@@ -425,4 +805,48 @@ impl<Output: AudioOutput> Audio<Output> {
 
 		self.ready_to_recv.store(true, Ordering::Release);
 	}
-}
\ No newline at end of file
+}
+
+//---------------------------------------------------------------------------------------------------- Free functions
+/// Convert an output's latency into microseconds for `AtomicState`.
+fn latency_micros(latency: Option<Duration>) -> Option<u32> {
+	latency.map(|l| u32::try_from(l.as_micros()).unwrap_or(u32::MAX))
+}
+
+//---------------------------------------------------------------------------------------------------- Device watcher
+#[cold]
+#[inline(never)]
+/// Spawn a thread that polls the default output device of
+/// our current backend, setting `changed` when it changes.
+///
+/// The thread exits when the returned `Sender` is dropped.
+fn device_watcher(atomic_state: Arc<AtomicState>, changed: Arc<AtomicBool>) -> Sender<()> {
+	let (shutdown, recv) = crossbeam::channel::bounded::<()>(1);
+
+	let spawn = std::thread::Builder::new()
+		.name("AudioDeviceWatcher".into())
+		.spawn(move || {
+			let mut last: Option<(OutputBackend, String)> = None;
+
+			while let Err(RecvTimeoutError::Timeout) = recv.recv_timeout(DEVICE_CHECK) {
+				let Some(backend) = atomic_state.output_backend.load() else {
+					continue;
+				};
+
+				let device = AudioOutputStruct::<ResamplerStruct>::default_device_backend(backend);
+				if let (Some((last_backend, last_device)), Some(device)) = (&last, &device) {
+					if *last_backend == backend && last_device != device {
+						changed.store(true, Ordering::Release);
+					}
+				}
+
+				last = device.map(|device| (backend, device));
+			}
+		});
+
+	if let Err(e) = spawn {
+		error2!("{ACTOR} - couldn't spawn device watcher: {e}");
+	}
+
+	shutdown
+}
@@ -2,32 +2,52 @@
 
 //---------------------------------------------------------------------------------------------------- Use
 use std::thread::JoinHandle;
-use crossbeam::channel::{Receiver, Select};
+use crossbeam::channel::{Receiver, Select, Sender};
 use symphonia::core::units::Time;
 use crate::{
 	actor::actor::Actor,
 	extra_data::ExtraData,
 	config::{Callbacks, ErrorCallback},
 	error::{DecodeError,SourceError,OutputError},
-	state::{AudioState,AudioStateReader,Current},
-	macros::{debug2,trace2,select_recv},
+	event::{Event,EventFilter},
+	state::{AtomicState,AudioState,AudioStateReader,AudioStateSnapshot,Current},
+	macros::{debug2,trace2,select_recv,stats},
 	source::Source,
 };
 use std::sync::{
 	Arc,
 	Barrier,
+	atomic::Ordering,
 };
 
 //---------------------------------------------------------------------------------------------------- Constants
 /// Actor name.
 const NAME: &str = "Caller";
 
+//---------------------------------------------------------------------------------------------------- Subscribe
+/// An [`Event`] subscriber.
+///
+/// This is called with each [`Event`] that passes its filter,
+/// and returns `false` if the subscriber is gone (e.g. the
+/// receiving end of its channel was dropped).
+pub(crate) type Subscriber<Extra> = Box<dyn FnMut(Event<Extra>) -> bool + Send + 'static>;
+
+/// A new [`Event`] subscriber, sent by `Engine::subscribe()`.
+///
+/// The `Sender<()>` is used to acknowledge the subscription,
+/// so that `Engine::subscribe()` only returns once it is
+/// guaranteed to receive all events that happen afterwards.
+pub(crate) type Subscribe<Extra> = (EventFilter, Subscriber<Extra>, Sender<()>);
+
 //---------------------------------------------------------------------------------------------------- Caller
 /// TODO
 #[allow(clippy::missing_docs_in_private_items)]
 pub(crate) struct Caller<Extra: ExtraData> {
 	callbacks: Callbacks<Extra>,
 	barrier: Arc<Barrier>,
+	atomic_state: Arc<AtomicState>,
+	/// Everyone subscribed to [`Event`]'s.
+	subscribers: Vec<(EventFilter, Subscriber<Extra>)>,
 }
 
 //---------------------------------------------------------------------------------------------------- Channels
@@ -41,6 +61,8 @@ struct Channels<Extra: ExtraData> {
 	error_decode: Receiver<DecodeError>,
 	error_source: Receiver<SourceError>,
 	error_output: Receiver<OutputError>,
+	event:        Receiver<(Event<Extra>, AudioStateSnapshot<Extra>)>,
+	subscribe:    Receiver<Subscribe<Extra>>,
 }
 
 //---------------------------------------------------------------------------------------------------- Caller Impl
@@ -49,6 +71,7 @@ struct Channels<Extra: ExtraData> {
 pub(crate) struct InitArgs<Extra: ExtraData> {
 	pub(crate) barrier:           Arc<Barrier>,
 	pub(crate) callbacks:         Callbacks<Extra>,
+	pub(crate) atomic_state:      Arc<AtomicState>,
 	pub(crate) low_priority:      bool,
 	pub(crate) shutdown:          Receiver<()>,
 	pub(crate) source_new:        Receiver<Source<Extra>>,
@@ -57,6 +80,8 @@ pub(crate) struct InitArgs<Extra: ExtraData> {
 	pub(crate) error_decode:      Receiver<DecodeError>,
 	pub(crate) error_source:      Receiver<SourceError>,
 	pub(crate) error_output:      Receiver<OutputError>,
+	pub(crate) event:             Receiver<(Event<Extra>, AudioStateSnapshot<Extra>)>,
+	pub(crate) subscribe:         Receiver<Subscribe<Extra>>,
 }
 
 //---------------------------------------------------------------------------------------------------- Actor
@@ -76,6 +101,7 @@ impl<Extra: ExtraData> Actor for Caller<Extra> {
 		let InitArgs {
 			barrier,
 			callbacks,
+			atomic_state,
 			low_priority,
 			shutdown,
 			source_new,
@@ -84,6 +110,8 @@ impl<Extra: ExtraData> Actor for Caller<Extra> {
 			error_decode,
 			error_source,
 			error_output,
+			event,
+			subscribe,
 		} = init_args;
 
 		let channels = Channels {
@@ -94,11 +122,15 @@ impl<Extra: ExtraData> Actor for Caller<Extra> {
 			error_decode,
 			error_source,
 			error_output,
+			event,
+			subscribe,
 		};
 
 		let this = Self {
 			callbacks,
 			barrier,
+			atomic_state,
+			subscribers: vec![],
 		};
 
 		if low_priority {
@@ -121,7 +153,9 @@ impl<Extra: ExtraData> Actor for Caller<Extra> {
 		assert_eq!(3, select.recv(&c.error_decode));
 		assert_eq!(4, select.recv(&c.error_source));
 		assert_eq!(5, select.recv(&c.error_output));
-		assert_eq!(6, select.recv(&c.shutdown));
+		assert_eq!(6, select.recv(&c.event));
+		assert_eq!(7, select.recv(&c.subscribe));
+		assert_eq!(8, select.recv(&c.shutdown));
 
 		loop {
 			// Route signal to its appropriate handler function [fn_*()].
@@ -129,13 +163,36 @@ impl<Extra: ExtraData> Actor for Caller<Extra> {
 				0 => { self.source_new(select_recv!(c.source_new)); },
 				1 => { select_recv!(c.queue_end); self.queue_end() },
 				2 => { self.elapsed(select_recv!(c.elapsed));     },
-				3 => { Self::call_error(&mut self.callbacks.error_decode, select_recv!(c.error_decode)); },
-				4 => { Self::call_error(&mut self.callbacks.error_source, select_recv!(c.error_source)); },
-				5 => { Self::call_error(&mut self.callbacks.error_output, select_recv!(c.error_output)); },
+				3 => {
+					let error = select_recv!(c.error_decode);
+					self.event_error(Event::ErrorDecode, &error);
+					Self::call_error(&mut self.callbacks.error_decode, error);
+				},
+				4 => {
+					let error = select_recv!(c.error_source);
+					self.event_error(Event::ErrorSource, &error);
+					Self::call_error(&mut self.callbacks.error_source, error);
+				},
+				5 => {
+					let error = select_recv!(c.error_output);
+					self.event_error(Event::ErrorOutput, &error);
+					Self::call_error(&mut self.callbacks.error_output, error);
+				},
+				6 => { self.event(select_recv!(c.event)); },
+				7 => { self.subscribe(select_recv!(c.subscribe)); },
 
-				6 => return self.barrier,
+				8 => {
+					// `Kernel` sends `Event::Shutdown` right before
+					// telling us to shutdown, make sure it's handled.
+					while let Ok(event) = c.event.try_recv() {
+						self.event(event);
+					}
+					return self.barrier;
+				},
 				_ => unreachable!(),
 			}
+
+			stats!(crate::state::AtomicStats::inc(&self.atomic_state.stats.messages_caller));
 		}
 	}
 }
@@ -159,6 +216,7 @@ impl<Extra: ExtraData> Caller<Extra> {
 	#[inline]
 	fn queue_end(&mut self) {
 		trace2!("{NAME} - queue_end()");
+		self.publish(Event::QueueEnd);
 		if let Some(callback) = self.callbacks.queue_end.as_mut() {
 			callback();
 		}
@@ -174,6 +232,75 @@ impl<Extra: ExtraData> Caller<Extra> {
 		}
 	}
 
+	/// Handle an [`Event`] from `Kernel`.
+	///
+	/// This calls the matching lifecycle callback
+	/// (if any), then sends it to all subscribers.
+	fn event(&mut self, (event, snapshot): (Event<Extra>, AudioStateSnapshot<Extra>)) {
+		trace2!("{NAME} - event({})", event.kind());
+
+		let cb = &mut self.callbacks;
+		match &event {
+			Event::SourceEnded(source, end) => if let Some(f) = cb.source_end.as_mut() { f(source.clone(), *end, snapshot) },
+			Event::Playing                  => if let Some(f) = cb.playing.as_mut()    { f(true, snapshot) },
+			Event::Paused                   => if let Some(f) = cb.playing.as_mut()    { f(false, snapshot) },
+			Event::Seeked(elapsed)          => if let Some(f) = cb.seek.as_mut()       { f(*elapsed, snapshot) },
+			Event::VolumeChanged(volume)    => if let Some(f) = cb.volume.as_mut()     { f(*volume, snapshot) },
+			Event::RepeatChanged(repeat)    => if let Some(f) = cb.repeat.as_mut()     { f(*repeat, snapshot) },
+			Event::Shuffled(shuffle)        => if let Some(f) = cb.shuffle.as_mut()    { f(*shuffle, snapshot) },
+			Event::QueueChanged             => if let Some(f) = cb.queue.as_mut()      { f(snapshot) },
+			Event::Shutdown                 => if let Some(f) = cb.shutdown.as_mut()   { f(snapshot) },
+			Event::ErrorSignal(error)       => if let Some(f) = cb.error_signal.as_mut() { f(error.clone(), snapshot) },
+			// These have no lifecycle callback, or are
+			// handled by the other `Caller` channels.
+			Event::SourceChanged(_) |
+			Event::QueueEnd         |
+			Event::Elapsed(_)       |
+			Event::ErrorDecode(_)   |
+			Event::ErrorSource(_)   |
+			Event::ErrorOutput(_)   => (),
+		}
+
+		self.publish(event);
+	}
+
+	/// Send an [`Event`] to all subscribers that want it.
+	///
+	/// Subscribers that are gone are removed.
+	fn publish(&mut self, event: Event<Extra>) {
+		if self.subscribers.is_empty() {
+			return;
+		}
+
+		let kind = event.kind();
+		self.subscribers.retain_mut(|(filter, subscriber)| {
+			!filter.contains(kind) || subscriber(event.clone())
+		});
+
+		// The lifecycle callbacks still need `Event`'s.
+		if self.subscribers.is_empty() && !self.callbacks.events() {
+			debug2!("{NAME} - no more event subscribers");
+			self.atomic_state.events.store(false, Ordering::Release);
+		}
+	}
+
+	#[inline]
+	/// Send an error [`Event`], only cloning the error if there are subscribers.
+	fn event_error<Error: Clone>(&mut self, event: fn(Error) -> Event<Extra>, error: &Error) {
+		if !self.subscribers.is_empty() {
+			self.publish(event(error.clone()));
+		}
+	}
+
+	/// Add a new [`Event`] subscriber.
+	fn subscribe(&mut self, (filter, subscriber, ack): Subscribe<Extra>) {
+		trace2!("{NAME} - subscribe()");
+		self.subscribers.push((filter, subscriber));
+		self.atomic_state.events.store(true, Ordering::Release);
+		// `Engine` may have been dropped in the meantime, that's fine.
+		drop(ack.send(()));
+	}
+
 	#[inline]
 	/// Handle the error callbacks.
 	fn call_error<Error>(
@@ -182,8 +309,14 @@ impl<Extra: ExtraData> Caller<Extra> {
 	) {
 		if let Some(error_callback) = error_callback.as_mut() {
 			match error_callback {
-				ErrorCallback::Fn(f) | ErrorCallback::PauseAndFn(f) => f(error),
-				ErrorCallback::Pause => (),
+				ErrorCallback::Fn(f)          |
+				ErrorCallback::PauseAndFn(f)  |
+				ErrorCallback::SkipAndFn(f)   |
+				ErrorCallback::RemoveAndFn(f) => f(error),
+				// The actions themselves are handled by `Kernel`.
+				ErrorCallback::Pause  |
+				ErrorCallback::Skip   |
+				ErrorCallback::Remove => (),
 			}
 		};
 	}
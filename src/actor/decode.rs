@@ -6,12 +6,14 @@ use crossbeam::channel::{Receiver, Select, Sender, TrySendError};
 use crate::{
 	actor::actor::Actor,
 	signal::{self,SeekError,SeekedTime},
-	source::{Source, source_decode::SourceDecode},
-	state::AudioState,
+	source::{Source, source_decode::{SourceDecode,PacketDecode,Next}},
+	state::{AudioState,AtomicState},
 	extra_data::ExtraData,
 	actor::kernel::KernelToDecode,
-	macros::{recv,send,try_send,try_recv,debug2,trace2,select_recv, error2},
+	macros::{recv,send,try_send,try_recv,debug2,trace2,select_recv,error2,stats},
 	error::{SourceError,DecodeError},
+	output::Latency,
+	free::RealtimeHandle,
 };
 use symphonia::core::{
 	audio::AudioBuffer,
@@ -26,6 +28,11 @@ use std::{
 	},
 	collections::VecDeque,
 };
+#[cfg(feature = "stats")]
+use {
+	crate::state::{AtomicStats,DecodeRate},
+	std::time::Instant,
+};
 use strum::EnumCount;
 
 //---------------------------------------------------------------------------------------------------- Constants
@@ -54,6 +61,15 @@ pub(crate) struct Decode<Extra: ExtraData> {
 	source:              SourceDecode,                       // Our current [Source] that we are decoding
 	done_decoding:       bool,                               // Whether we have finished decoding our current [Source]
 	barrier:             Arc<Barrier>,
+	atomic_state:        Arc<AtomicState>,
+	#[cfg(feature = "stats")]
+	rate:                DecodeRate,                         // Packets decoded per second, for [Stats]
+
+	/// Corrupt packet handling, and the spec used to fill their gaps with silence.
+	packets:             PacketDecode,
+	/// The output's latency if we should promote ourselves to real-time
+	/// priority, and our promotion once we know the audio's sample rate.
+	realtime:            Option<(Latency, Option<RealtimeHandle>)>,
 	_p:                  PhantomData<Extra>,
 }
 
@@ -93,6 +109,9 @@ pub(crate) enum DecodeToGc {
 #[allow(clippy::missing_docs_in_private_items)]
 pub(crate) struct InitArgs<Extra: ExtraData> {
 	pub(crate) barrier:                Arc<Barrier>,
+	pub(crate) atomic_state:           Arc<AtomicState>,
+	pub(crate) packet_error_threshold: usize,
+	pub(crate) realtime:               Option<Latency>,
 	pub(crate) audio_ready_to_recv:    Arc<AtomicBool>,
 	pub(crate) to_gc:                  Sender<DecodeToGc>,
 	pub(crate) to_audio:               Sender<DecodeToAudio>,
@@ -119,6 +138,9 @@ impl<Extra: ExtraData> Actor for Decode<Extra> {
 	fn init(init_args: Self::InitArgs) -> (Self, Self::MainArgs) {
 		let InitArgs {
 			barrier,
+			atomic_state,
+			packet_error_threshold,
+			realtime,
 			audio_ready_to_recv,
 			to_gc,
 			to_audio,
@@ -145,6 +167,13 @@ impl<Extra: ExtraData> Actor for Decode<Extra> {
 			source: SourceDecode::dummy(),
 			done_decoding: true,
 			barrier,
+			atomic_state,
+			#[cfg(feature = "stats")]
+			rate: DecodeRate::new(),
+			packets: PacketDecode::new(packet_error_threshold),
+			// We don't know the audio's sample rate yet,
+			// we're promoted once the first source is set.
+			realtime: realtime.map(|latency| (latency, None)),
 			_p: PhantomData,
 		};
 
@@ -170,6 +199,7 @@ impl<Extra: ExtraData> Actor for Decode<Extra> {
 			// This falls through and continues
 			// executing the below code.
 			if let Ok(msg) = signal {
+				stats!(AtomicStats::inc(&self.atomic_state.stats.messages_decode));
 				match msg {
 					KernelToDecode::NewSource(source)     => self.new_source(source, &c),
 					KernelToDecode::Seek((seek, elapsed)) => self.seek(seek, elapsed, &c.to_gc, &c.to_kernel_seek),
@@ -185,47 +215,46 @@ impl<Extra: ExtraData> Actor for Decode<Extra> {
 			}
 
 			// Continue decoding our current [SourceDecode].
+			let skipped = self.packets.total;
+			#[cfg(feature = "stats")]
+			let decode_start = Instant::now();
+			let next = self.source.next(&mut self.packets);
+			#[cfg(feature = "stats")]
+			let decode_time = decode_start.elapsed();
+
+			// Corrupt packets were skipped.
+			let skipped = self.packets.total - skipped;
+			if skipped != 0 {
+				self.atomic_state.packets_skipped.fetch_add(skipped, Ordering::Relaxed);
+			}
+			if let Some(error) = self.packets.error.take() {
+				Self::handle_decode_error(&c, error);
+			}
 
-			let packet = match self.source.reader.next_packet() {
-				Ok(p) => p,
-
-				// We're done decoding.
-				// This "end of stream" error is currently the only way
-				// a [FormatReader] can indicate the media is complete.
-				Err(symphonia::core::errors::Error::IoError(_)) => {
-					debug2!("{NAME} - done decoding");
-					self.done_decoding = true;
-
-					// INVARIANT: If `Audio` is not ready, it means its
-					// discarding its audio data anyway, so we don't need
-					// to tell it we reached the end.
-					try_send!(c.to_audio, DecodeToAudio::EndOfTrack);
-
+			let packet = match next {
+				// Send to [Audio] if we can, else store locally.
+				Next::Audio(audio, time, packet) => {
+					self.send_or_store_audio(&c.to_audio, (audio, time));
+					packet
+				},
+				Next::Skipped(Some(packet)) => packet,
+				Next::Skipped(None) => continue,
+				Next::End => {
+					self.end_of_track(&c.to_audio);
 					continue;
 				},
-
-				// An actual error happened.
-				Err(e) => {
-					Self::handle_decode_error(&c, DecodeError::from(e));
+				Next::Source(error) => {
+					self.done_decoding = true;
+					Self::handle_source_error(&c, error);
 					continue;
 				},
 			};
 
-			// Decode the packet into audio samples.
-			match self.source.decoder.decode(&packet) {
-				Ok(decoded) => {
-					// Convert and take ownership of audio buffer.
-					let mut audio = decoded.make_equivalent::<f32>();
-					decoded.convert(&mut audio);
-
-					// Calculate timestamp.
-					let time = self.source.timebase.calc_time(packet.ts);
-
-					// Send to [Audio] if we can, else store locally.
-					self.send_or_store_audio(&c.to_audio, (audio, time));
-				}
-
-				Err(e) => Self::handle_decode_error(&c, DecodeError::from(e)),
+			stats! {
+				let stats = &self.atomic_state.stats;
+				self.rate.packet(stats, decode_time);
+				stats.decode_buffer.store(self.buffer.len(), Ordering::Relaxed);
+				stats.audio_backlog.store(c.to_audio.len(), Ordering::Relaxed);
 			}
 
 			// Send garbage to [Gc] instead of dropping locally.
@@ -276,6 +305,18 @@ impl<Extra: ExtraData> Decode<Extra> {
 		}
 	}
 
+	#[inline]
+	/// We've reached the end of our current [Source], tell [Audio].
+	fn end_of_track(&mut self, to_audio: &Sender<DecodeToAudio>) {
+		debug2!("{NAME} - done decoding, corrupt packets skipped: {}", self.packets.total);
+		self.done_decoding = true;
+
+		// INVARIANT: If `Audio` is not ready, it means its
+		// discarding its audio data anyway, so we don't need
+		// to tell it we reached the end.
+		try_send!(to_audio, DecodeToAudio::EndOfTrack);
+	}
+
 	#[inline]
 	/// TODO
 	fn new_source(&mut self, source: Source<Extra>, channels: &Channels<Extra>) {
@@ -287,12 +328,41 @@ impl<Extra: ExtraData> Decode<Extra> {
 				std::mem::swap(&mut self.source, &mut s);
 				try_send!(channels.to_gc, DecodeToGc::Source(s));
 				self.done_decoding = false;
+				self.packets.reset();
+				self.promote();
 			},
 
 			Err(e) => Self::handle_source_error(channels, e),
 		}
 	}
 
+	#[cold]
+	#[inline(never)]
+	/// Promote ourselves to real-time priority at our current
+	/// source's sample rate, re-promoting if the rate changed.
+	fn promote(&mut self) {
+		let Some((latency, handle)) = self.realtime.as_mut() else {
+			return;
+		};
+
+		let sample_rate = self.source.sample_rate;
+		let frames = latency.frames(sample_rate);
+
+		let priority = match handle {
+			Some(handle) => handle.update(frames, sample_rate),
+			None => {
+				let new = crate::free::realtime(NAME, frames, sample_rate);
+				let priority = new.priority();
+				*handle = Some(new);
+				Some(priority)
+			},
+		};
+
+		if let Some(priority) = priority {
+			self.atomic_state.priority_decode.store(priority);
+		}
+	}
+
 	#[inline]
 	/// TODO
 	fn seek(
@@ -363,4 +433,4 @@ impl<Extra: ExtraData> Decode<Extra> {
 			try_send!(to_gc, DecodeToGc::AudioBuffer(audio_buffer));
 		}
 	}
-}
\ No newline at end of file
+}
@@ -9,23 +9,29 @@ use crate::{
 	actor::actor::Actor,
 	actor::kernel::KernelToGc,
 	actor::decode::DecodeToGc,
-	state::{AudioState,Current},
+	state::{AtomicState,AudioState,Current},
 	extra_data::ExtraData,
-	macros::{debug2,warn2,try_recv,select_recv},
+	macros::{debug2,warn2,try_recv,select_recv,stats},
 	source::source_decode::SourceDecode,
 };
 use crossbeam::channel::{Receiver, Select};
+#[cfg(feature = "stats")]
+use {
+	crate::state::AtomicStats,
+	std::sync::atomic::Ordering,
+};
 use symphonia::core::audio::AudioBuffer;
 
 //---------------------------------------------------------------------------------------------------- Gc
 /// The [G]arbage [c]ollector.
 #[allow(clippy::missing_docs_in_private_items)]
 pub(crate) struct Gc<Extra: ExtraData> {
-	pub(crate) barrier:     Arc<Barrier>,
-	pub(crate) shutdown:    Receiver<()>,
-	pub(crate) from_audio:  Receiver<AudioBuffer<f32>>,
-	pub(crate) from_decode: Receiver<DecodeToGc>,
-	pub(crate) from_kernel: Receiver<KernelToGc<Extra>>,
+	pub(crate) barrier:      Arc<Barrier>,
+	pub(crate) atomic_state: Arc<AtomicState>,
+	pub(crate) shutdown:     Receiver<()>,
+	pub(crate) from_audio:   Receiver<AudioBuffer<f32>>,
+	pub(crate) from_decode:  Receiver<DecodeToGc>,
+	pub(crate) from_kernel:  Receiver<KernelToGc<Extra>>,
 }
 
 //---------------------------------------------------------------------------------------------------- Actor
@@ -71,6 +77,13 @@ impl<Extra: ExtraData> Actor for Gc<Extra> {
 
 				_ => unreachable!(),
 			}
+
+			stats! {
+				let stats = &self.atomic_state.stats;
+				AtomicStats::inc(&stats.messages_gc);
+				let backlog = self.from_audio.len() + self.from_decode.len() + self.from_kernel.len();
+				stats.gc_backlog.store(backlog, Ordering::Relaxed);
+			}
 		}
 	}
 }
\ No newline at end of file
@@ -180,9 +180,11 @@ impl<Extra: ExtraData> Kernel<Extra> {
 			};
 		});
 
+		self.queue_generation = self.queue_generation.wrapping_add(1);
+
 		// Forward potentially new `Source`.
 		if let Some(source) = maybe_source {
-			Self::new_source(to_decode, to_caller_source_new, source);
+			self.new_source(to_decode, to_caller_source_new, source);
 
 			if add_many.play {
 				self.atomic_state.playing.store(true, Ordering::Release);
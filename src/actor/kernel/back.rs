@@ -126,7 +126,7 @@ mod tests {
 
 			audio_state
 		};
-		let resp = engine.restore(audio_state.clone());
+		let resp = engine.restore(audio_state.clone()).unwrap();
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 
 		//---------------------------------- 1 backwards.
@@ -150,7 +150,7 @@ mod tests {
 		//---------------------------------- Threshold passed, restart index
 		audio_state.current.as_mut().unwrap().elapsed = 10.0; // passed threshold
 		audio_state.current.as_mut().unwrap().index = 2;
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		let current = resp.current.as_ref().unwrap();
 		assert_eq!(current.elapsed, 10.0);
 		assert_eq!(current.index, 2);
@@ -47,6 +47,9 @@ impl<Extra: ExtraData> Kernel<Extra> {
 				}
 			}
 		});
+		if clear == Clear::Queue {
+			self.queue_generation = self.queue_generation.wrapping_add(1);
+		}
 
 		try_send!(to_engine, self.audio_state_snapshot());
 	}
@@ -78,7 +81,7 @@ mod tests {
 			elapsed: 123.123,
 		});
 
-		let resp = engine.restore(audio_state.clone());
+		let resp = engine.restore(audio_state.clone()).unwrap();
 		assert_eq!(resp.queue.len(), 10);
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 
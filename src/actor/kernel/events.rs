@@ -0,0 +1,349 @@
+//! Generating `Event`'s from `AudioState` changes.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	actor::kernel::Kernel,
+	event::{Event,SourceEnd},
+	extra_data::ExtraData,
+	macros::try_send,
+	signal::{Repeat,Shuffle,SignalError,Volume},
+	source::Source,
+	state::AudioStateSnapshot,
+};
+use crossbeam::channel::Sender;
+use std::sync::atomic::Ordering;
+
+//---------------------------------------------------------------------------------------------------- Cause
+/// What `Kernel` was handling when the `AudioState` changed.
+///
+/// Most changes can be figured out by diffing
+/// the state, but these need the extra context.
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub(super) enum Cause {
+	/// Anything else.
+	Other,
+	/// `Audio` finished playing the `Current` track.
+	EndOfTrack,
+	/// A seek.
+	Seek,
+	/// A shuffle.
+	Shuffle(Shuffle),
+}
+
+//---------------------------------------------------------------------------------------------------- EventState
+/// The parts of the [`AudioState`](crate::state::AudioState) that [`Event`]'s are generated from.
+///
+/// This is captured before `Kernel` handles a message, and compared
+/// afterwards, so the handlers themselves don't need to know about events.
+///
+/// The queue and `Current` `Source` are not compared directly, `Kernel`
+/// bumps a generation counter whenever it changes them instead, so this
+/// is cheap to capture and duplicate `Source`'s are still told apart.
+pub(super) struct EventState<Extra: ExtraData> {
+	/// `AudioState::playing`.
+	playing: bool,
+	/// `AudioState::repeat`.
+	repeat: Repeat,
+	/// `AudioState::volume`.
+	volume: Volume,
+	/// `AudioState::current`'s `Source` and `elapsed`.
+	current: Option<(Source<Extra>, f32)>,
+	/// `Kernel::source_generation`.
+	source_generation: usize,
+	/// `Kernel::queue_generation`.
+	queue_generation: usize,
+}
+
+//----------------------------------------------------------------------------------------------------
+impl<Extra: ExtraData> Kernel<Extra> {
+	#[inline]
+	/// Capture the [`EventState`], if anyone (a subscriber
+	/// or a callback) wants [`Event`]'s.
+	pub(super) fn event_state(&self) -> Option<EventState<Extra>> {
+		if self.atomic_state.events.load(Ordering::Acquire) {
+			Some(EventState {
+				playing: self.w.playing,
+				repeat: self.w.repeat,
+				volume: self.w.volume,
+				current: self.w.current.as_ref().map(|c| (c.source.clone(), c.elapsed)),
+				source_generation: self.source_generation,
+				queue_generation: self.queue_generation,
+			})
+		} else {
+			None
+		}
+	}
+
+	/// Compare the [`EventState`] captured before
+	/// handling a message with the current state,
+	/// and send the resulting [`Event`]'s to `Caller`.
+	///
+	/// All [`Event`]'s caused by the same message
+	/// share the same [`AudioStateSnapshot`].
+	pub(super) fn events(
+		&self,
+		before: EventState<Extra>,
+		cause: Cause,
+		to_caller_event: &Sender<(Event<Extra>, AudioStateSnapshot<Extra>)>,
+	) {
+		let Some(after) = self.event_state() else {
+			return;
+		};
+		let mut snapshot = None;
+		let mut send = |event: Event<Extra>| {
+			let snapshot = snapshot.get_or_insert_with(|| self.audio_state_snapshot());
+			try_send!(to_caller_event, (event, snapshot.clone()));
+		};
+
+		// `Current`.
+		let end = if cause == Cause::EndOfTrack {
+			SourceEnd::Completed
+		} else {
+			SourceEnd::Skipped
+		};
+		match (before.current, after.current) {
+			(None, None) => (),
+			(None, Some(_)) => send(Event::SourceChanged(self.w.current.clone())),
+			(Some((source, _)), None) => {
+				send(Event::SourceEnded(source, end));
+				send(Event::SourceChanged(None));
+			},
+			(Some((b_source, b_elapsed)), Some((_, a_elapsed))) => {
+				if before.source_generation != after.source_generation {
+					send(Event::SourceEnded(b_source, end));
+					send(Event::SourceChanged(self.w.current.clone()));
+				} else if cause == Cause::Seek {
+					send(Event::Seeked(a_elapsed));
+				} else if a_elapsed != b_elapsed {
+					send(Event::Elapsed(a_elapsed));
+				}
+			},
+		}
+
+		// Queue.
+		if before.queue_generation != after.queue_generation {
+			send(Event::QueueChanged);
+			if let Cause::Shuffle(shuffle) = cause {
+				send(Event::Shuffled(shuffle));
+			}
+		}
+
+		// Playback.
+		if before.playing != after.playing {
+			if after.playing {
+				send(Event::Playing);
+			} else {
+				send(Event::Paused);
+			}
+		}
+
+		// Settings.
+		if before.volume != after.volume {
+			send(Event::VolumeChanged(after.volume));
+		}
+		if before.repeat != after.repeat {
+			send(Event::RepeatChanged(after.repeat));
+		}
+	}
+
+	#[inline]
+	/// Send [`Event::Elapsed`] to `Caller` (if anyone wants it).
+	///
+	/// `Audio` reports the elapsed time for every buffer it
+	/// writes, so this is sent directly instead of capturing
+	/// and comparing an [`EventState`] for each one.
+	pub(super) fn event_elapsed(&self, elapsed: f32, to_caller_event: &Sender<(Event<Extra>, AudioStateSnapshot<Extra>)>) {
+		if self.atomic_state.events.load(Ordering::Acquire) {
+			try_send!(to_caller_event, (Event::Elapsed(elapsed), self.audio_state_snapshot()));
+		}
+	}
+
+	#[cold]
+	/// Send [`Event::Shutdown`] to `Caller` (if anyone wants it).
+	pub(super) fn event_shutdown(&self, to_caller_event: &Sender<(Event<Extra>, AudioStateSnapshot<Extra>)>) {
+		if self.atomic_state.events.load(Ordering::Acquire) {
+			try_send!(to_caller_event, (Event::Shutdown, self.audio_state_snapshot()));
+		}
+	}
+
+	#[cold]
+	/// Send [`Event::ErrorSignal`] to `Caller` (if anyone wants it).
+	///
+	/// This is for signals that nobody is waiting on the response of.
+	pub(super) fn event_signal_error(&self, error: SignalError, to_caller_event: &Sender<(Event<Extra>, AudioStateSnapshot<Extra>)>) {
+		if self.atomic_state.events.load(Ordering::Acquire) {
+			try_send!(to_caller_event, (Event::ErrorSignal(error), self.audio_state_snapshot()));
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+	use crate::{
+		config::{Callbacks,InitConfig},
+		event::{Event,EventFilter,EventKind},
+		signal::{Add,AddMany,AddMethod,Clear,Repeat,Seek,Shuffle,Volume},
+	};
+	use std::time::Duration;
+	use pretty_assertions::assert_eq;
+
+	/// Receive the next event, and return its kind.
+	fn kind(events: &crossbeam::channel::Receiver<Event<usize>>) -> EventKind {
+		events.recv_timeout(Duration::from_secs(5)).unwrap().kind()
+	}
+
+	#[test]
+	fn events() {
+		let mut engine = crate::tests::init();
+
+		// Ignore `Elapsed` and errors, they're timing/device dependent.
+		let filter = EventFilter::ALL
+			.without(EventKind::Elapsed)
+			.without(EventKind::ErrorOutput);
+		let events = engine.subscribe(filter);
+		// Independent subscriber.
+		let volume = engine.subscribe(EventKind::VolumeChanged.into());
+
+		engine.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		assert_eq!(kind(&events), EventKind::SourceChanged);
+		assert_eq!(kind(&events), EventKind::QueueChanged);
+		assert_eq!(kind(&events), EventKind::Playing);
+
+		engine.pause();
+		assert_eq!(kind(&events), EventKind::Paused);
+
+		engine.play();
+		assert_eq!(kind(&events), EventKind::Playing);
+
+		engine.pause();
+		assert_eq!(kind(&events), EventKind::Paused);
+
+		engine.seek(Seek::Absolute(5.0)).unwrap();
+		assert_eq!(kind(&events), EventKind::Seeked);
+
+		engine.volume(Volume::new(0.1));
+		assert_eq!(kind(&events), EventKind::VolumeChanged);
+		match volume.recv().unwrap() {
+			Event::VolumeChanged(v) => assert_eq!(v, Volume::new(0.1)),
+			e => panic!("unexpected event: {e:?}"),
+		}
+
+		engine.repeat(Repeat::Queue);
+		assert_eq!(kind(&events), EventKind::RepeatChanged);
+
+		engine.clear(Clear::Queue);
+		assert_eq!(kind(&events), EventKind::SourceEnded);
+		assert_eq!(kind(&events), EventKind::SourceChanged);
+		assert_eq!(kind(&events), EventKind::QueueChanged);
+
+		// No-ops don't emit anything.
+		engine.repeat(Repeat::Queue);
+		assert!(events.try_recv().is_err());
+		assert!(volume.try_recv().is_err());
+
+		// Dropped subscribers are removed.
+		drop(events);
+		engine.volume(Volume::new(0.2));
+		assert!(matches!(volume.recv().unwrap(), Event::VolumeChanged(_)));
+	}
+
+	#[test]
+	fn duplicate_sources() {
+		let mut engine = crate::tests::init();
+		let filter = EventFilter::ALL
+			.without(EventKind::Elapsed)
+			.without(EventKind::ErrorOutput);
+		let events = engine.subscribe(filter);
+
+		// The same `Source` (and audio data) twice.
+		let source = crate::tests::source(0);
+		engine.add_many(AddMany {
+			sources: crate::source::Sources::from_1_and_iter(source.clone(), [source].into_iter()),
+			method: AddMethod::Back,
+			clear: false,
+			play: false,
+		});
+		assert_eq!(kind(&events), EventKind::QueueChanged);
+
+		engine.next();
+		assert_eq!(kind(&events), EventKind::SourceChanged);
+
+		// Moving onto the duplicate is still a new `Source`.
+		engine.next();
+		assert_eq!(kind(&events), EventKind::SourceEnded);
+		assert_eq!(kind(&events), EventKind::SourceChanged);
+		assert_eq!(engine.reader().get().current.as_ref().unwrap().index, 1);
+	}
+
+	#[test]
+	fn callbacks() {
+		// Each callback sends what it received (and a
+		// value from the `AudioStateSnapshot`) down this channel.
+		let (send, recv) = crossbeam::channel::unbounded();
+
+		let mut callbacks = Callbacks::new();
+		let s = send.clone();
+		callbacks.source_end(move |source, end, state| {
+			s.send(format!("source_end {} {end:?} {:?}", source.extra(), state.current.as_ref().map(|c| c.index))).unwrap();
+		});
+		let s = send.clone();
+		callbacks.playing(move |playing, state| s.send(format!("playing {playing} {}", state.playing)).unwrap());
+		let s = send.clone();
+		callbacks.seek(move |elapsed, state| s.send(format!("seek {elapsed} {}", state.current.as_ref().unwrap().elapsed)).unwrap());
+		let s = send.clone();
+		callbacks.volume(move |volume, state| s.send(format!("volume {} {}", volume.inner(), state.volume.inner())).unwrap());
+		let s = send.clone();
+		callbacks.repeat(move |repeat, state| s.send(format!("repeat {repeat:?} {:?}", state.repeat)).unwrap());
+		let s = send.clone();
+		callbacks.shuffle(move |shuffle, state| s.send(format!("shuffle {shuffle:?} {}", state.queue.len())).unwrap());
+		let s = send;
+		callbacks.queue(move |state| s.send(format!("queue {}", state.queue.len())).unwrap());
+
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			callbacks,
+			..InitConfig::DEFAULT
+		});
+
+		let next = || recv.recv_timeout(Duration::from_secs(5)).unwrap();
+
+		engine.add_many(AddMany {
+			sources: crate::tests::sources(),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		assert_eq!(next(), "queue 10");
+		assert_eq!(next(), "playing true true");
+
+		engine.pause();
+		assert_eq!(next(), "playing false false");
+
+		engine.next();
+		assert_eq!(next(), "source_end 0 Skipped Some(1)");
+
+		engine.seek(Seek::Absolute(5.0)).unwrap();
+		assert_eq!(next(), "seek 5 5");
+
+		engine.volume(Volume::new(0.5));
+		assert_eq!(next(), "volume 0.5 0.5");
+
+		engine.repeat(Repeat::Current);
+		assert_eq!(next(), "repeat Current Current");
+
+		engine.shuffle(Shuffle::Queue);
+		assert_eq!(next(), "queue 10");
+		assert_eq!(next(), "shuffle Queue 10");
+
+		engine.clear(Clear::Queue);
+		let end = next();
+		assert!(end.starts_with("source_end") && end.ends_with("Skipped None"), "{end}");
+		assert_eq!(next(), "queue 0");
+	}
+}
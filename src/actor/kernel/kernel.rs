@@ -6,7 +6,7 @@ use crossbeam::channel::{Sender, Receiver, Select};
 use rand::SeedableRng;
 use symphonia::core::units::Time;
 use crate::{
-	macros::{send,recv,try_recv,try_send,debug2,select_recv, trace2},
+	macros::{send,recv,try_recv,try_send,debug2,select_recv, trace2,stats},
 	extra_data::ExtraData,
 	state::{
 		AudioState,
@@ -14,7 +14,10 @@ use crate::{
 		AudioStateSnapshot,
 		Current
 	},
-	actor::audio::AudioToKernel,
+	actor::{
+		audio::AudioToKernel,
+		kernel::events::Cause,
+	},
 	signal::{
 		Play,
 		Toggle,
@@ -43,11 +46,18 @@ use crate::{
 		Remove,
 		RemoveError,
 		RemoveRange,
+		SendSignal,
 	},
 	error::{SourceError, OutputError, DecodeError},
-	source::Source, config::ErrorCallback,
+	event::Event,
+	output::Latency,
+	source::Source,
+	config::{ErrorCallback,ErrorAction},
+};
+use std::{
+	collections::VecDeque,
+	ops::Bound,
 };
-use std::collections::VecDeque;
 use std::sync::{
 	Arc,
 	Barrier,
@@ -72,6 +82,29 @@ pub(crate) struct Kernel<Extra: ExtraData> {
 	pub(super) w: someday::Writer<AudioState<Extra>>,
 	pub(super) barrier: Arc<Barrier>,
 	pub(super) shutdown_blocking: bool,
+
+	/// How many `DecodeError`'s in a row we tolerate
+	/// before acting upon the `ErrorCallback`.
+	pub(super) error_decode_retry: usize,
+	/// How many `DecodeError`'s have occurred in a row
+	/// (without any audio being played in-between).
+	pub(super) error_decode_count: usize,
+	/// How many `Source`'s have been skipped in a row due
+	/// to `ErrorCallback::Skip` (without any audio being played).
+	///
+	/// This prevents endlessly cycling through a queue
+	/// where every single `Source` is broken.
+	pub(super) error_skip_count: usize,
+
+	/// Incremented every time a (maybe the same) `Source`
+	/// is sent to `Decode` to be played as the `Current`.
+	///
+	/// Used by `events()` to detect `Source` changes.
+	pub(super) source_generation: usize,
+	/// Incremented every time the queue is modified.
+	///
+	/// Used by `events()` to detect queue changes.
+	pub(super) queue_generation: usize,
 }
 
 //---------------------------------------------------------------------------------------------------- Msg
@@ -101,10 +134,52 @@ pub(crate) enum KernelToAudio {
 	StartPlaying,
 	/// Discard all of your current audio buffers.
 	DiscardAudio,
+	/// Switch to this audio output device (`None` == default device).
+	///
+	/// The result is sent directly to the `Engine`, so
+	/// `Kernel` doesn't wait while the device is opened.
+	OutputDevice(Option<String>, Sender<Result<(), OutputError>>),
+	/// Re-open the audio output device with this latency.
+	///
+	/// The result is sent directly to the `Engine`.
+	OutputLatency(Latency, Sender<Result<(), OutputError>>),
 	/// Shutdown and exit thread.
 	Shutdown,
 }
 
+/// Requests from `Engine` that `Audio` must handle, as it owns the output device.
+pub(crate) enum OutputRequest {
+	/// Switch to this audio output device (`None` == default device).
+	Device(Option<String>),
+	/// Re-open the audio output device with this latency.
+	Latency(Latency),
+}
+
+/// Requests from `Media`, i.e. the OS media controls.
+///
+/// These are handled exactly like the equivalent `Engine` signals,
+/// although the response is sent back to `Media` instead.
+#[derive(Copy,Clone,Debug,PartialEq)]
+#[cfg_attr(not(feature = "media_controls"), allow(dead_code))]
+pub(crate) enum MediaToKernel {
+	/// `Engine::toggle()`.
+	Toggle,
+	/// `Engine::play()`.
+	Play,
+	/// `Engine::pause()`.
+	Pause,
+	/// `Engine::stop()`.
+	Stop,
+	/// `Engine::next()`.
+	Next,
+	/// `Engine::previous()`.
+	Previous,
+	/// `Engine::seek()`.
+	Seek(Seek),
+	/// `Engine::volume()`.
+	Volume(Volume),
+}
+
 /// TODO
 pub(crate) enum KernelToGc<Extra: ExtraData> {
 	/// TODO
@@ -113,6 +188,24 @@ pub(crate) enum KernelToGc<Extra: ExtraData> {
 	AudioState(AudioState<Extra>),
 }
 
+//---------------------------------------------------------------------------------------------------- Request
+/// A signal, and where to send its response.
+///
+/// Each request carries its own response channel, so any
+/// amount of `EngineHandle`'s can send signals concurrently
+/// without receiving each other's responses.
+pub(crate) type Request<Signal, Response> = (Signal, Sender<Response>);
+
+/// Call a signal handler that nobody is waiting on the response of.
+///
+/// The handlers always send a response, so this
+/// gives them somewhere to send it, and returns it.
+fn detached<Response>(handler: impl FnOnce(&Sender<Response>)) -> Response {
+	let (to_engine, response) = crossbeam::channel::bounded(1);
+	handler(&to_engine);
+	try_recv!(response)
+}
+
 //---------------------------------------------------------------------------------------------------- Recv
 /// TL;DR - this structs exists because [self] borrowing rules are too strict
 ///
@@ -134,13 +227,16 @@ pub(crate) struct Channels<Extra: ExtraData> {
 	// the shutdown channels for all the actors
 	// (that don't receive an enum signal).
 	//
-	// Caller, Gc.
-	pub(crate) shutdown_actor: [Sender<()>; 2],
+	// Caller, Gc, Media (if spawned).
+	pub(crate) shutdown_actor: Vec<Sender<()>>,
 
 	// [Audio]
 	pub(crate) to_audio:         Sender<KernelToAudio>,
 	pub(crate) from_audio:       Receiver<AudioToKernel>,
 	pub(crate) from_audio_error: Receiver<OutputError>,
+	// In pull mode, the `Pull`'s ends of the above channels, so
+	// they stay connected even if the host drops the `Pull`.
+	pub(crate) _pull: Option<crate::output::PullKeepAlive>,
 
 	// [Decode]
 	pub(crate) to_decode:                Sender<KernelToDecode<Extra>>,
@@ -154,46 +250,49 @@ pub(crate) struct Channels<Extra: ExtraData> {
 	// since it has the 1st access to time data.
 	pub(crate) to_caller_source_new: Sender<Source<Extra>>,
 	pub(crate) to_caller_queue_end: Sender<()>,
-	pub(crate) to_caller_error_decode: (Sender<DecodeError>, bool), // Should we `pause()`?
-	pub(crate) to_caller_error_source: (Sender<SourceError>, bool), // Should we `pause()`?
-	pub(crate) to_caller_error_output: (Sender<OutputError>, bool), // Should we `pause()`?
+	pub(crate) to_caller_error_decode: (Sender<DecodeError>, ErrorAction), // What should we do?
+	pub(crate) to_caller_error_source: (Sender<SourceError>, ErrorAction), // What should we do?
+	pub(crate) to_caller_error_output: (Sender<OutputError>, ErrorAction), // What should we do?
+	pub(crate) to_caller_event: Sender<(Event<Extra>, AudioStateSnapshot<Extra>)>, // Only sent to if `AtomicState::events`.
 
 	// [Gc]
 	pub(crate) to_gc: Sender<KernelToGc<Extra>>,
 
-	// Shared common return channel for signals that don't have special output.
-	pub(crate) send_audio_state: Sender<AudioStateSnapshot<Extra>>,
-
 	// Signals that have no input and output `AudioStateSnapshot`
-	pub(crate) recv_toggle:   Receiver<()>,
-	pub(crate) recv_play:     Receiver<()>,
-	pub(crate) recv_pause:    Receiver<()>,
-	pub(crate) recv_next:     Receiver<()>,
-	pub(crate) recv_previous: Receiver<()>,
-	pub(crate) recv_stop:     Receiver<()>,
+	pub(crate) recv_toggle:   Receiver<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_play:     Receiver<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_pause:    Receiver<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_next:     Receiver<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_previous: Receiver<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_stop:     Receiver<Request<(), AudioStateSnapshot<Extra>>>,
 
 	// Signals that have input and output `AudioStateSnapshot`
-	pub(crate) recv_add:            Receiver<Add<Extra>>,
-	pub(crate) recv_add_many:       Receiver<AddMany<Extra>>,
-	pub(crate) recv_clear:          Receiver<Clear>,
-	pub(crate) recv_repeat:         Receiver<Repeat>,
-	pub(crate) recv_volume:         Receiver<Volume>,
-	pub(crate) recv_shuffle:        Receiver<Shuffle>,
-	pub(crate) recv_restore:        Receiver<AudioState<Extra>>,
+	pub(crate) recv_add:            Receiver<Request<Add<Extra>, AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_add_many:       Receiver<Request<AddMany<Extra>, AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_clear:          Receiver<Request<Clear, AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_repeat:         Receiver<Request<Repeat, AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_volume:         Receiver<Request<Volume, AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_shuffle:        Receiver<Request<Shuffle, AudioStateSnapshot<Extra>>>,
+	pub(crate) recv_restore:        Receiver<Request<AudioState<Extra>, AudioStateSnapshot<Extra>>>,
 
 	// Signals that return `Result<T, E>`
-	pub(crate) send_seek:         Sender<Result<AudioStateSnapshot<Extra>, SeekError>>,
-	pub(crate) recv_seek:         Receiver<Seek>,
-	pub(crate) send_skip:         Sender<Result<AudioStateSnapshot<Extra>, SkipError>>,
-	pub(crate) recv_skip:         Receiver<Skip>,
-	pub(crate) send_back:         Sender<Result<AudioStateSnapshot<Extra>, BackError>>,
-	pub(crate) recv_back:         Receiver<Back>,
-	pub(crate) send_set_index:    Sender<Result<AudioStateSnapshot<Extra>, SetIndexError>>,
-	pub(crate) recv_set_index:    Receiver<SetIndex>,
-	pub(crate) send_remove:       Sender<Result<AudioStateSnapshot<Extra>, RemoveError>>,
-	pub(crate) recv_remove:       Receiver<Remove>,
-	pub(crate) send_remove_range: Sender<Result<AudioStateSnapshot<Extra>, RemoveError>>,
-	pub(crate) recv_remove_range: Receiver<RemoveRange>,
+	pub(crate) recv_seek:          Receiver<Request<Seek, Result<AudioStateSnapshot<Extra>, SeekError>>>,
+	pub(crate) recv_skip:          Receiver<Request<Skip, Result<AudioStateSnapshot<Extra>, SkipError>>>,
+	pub(crate) recv_back:          Receiver<Request<Back, Result<AudioStateSnapshot<Extra>, BackError>>>,
+	pub(crate) recv_set_index:     Receiver<Request<SetIndex, Result<AudioStateSnapshot<Extra>, SetIndexError>>>,
+	pub(crate) recv_remove:        Receiver<Request<Remove, Result<AudioStateSnapshot<Extra>, RemoveError>>>,
+	pub(crate) recv_remove_range:  Receiver<Request<RemoveRange, Result<AudioStateSnapshot<Extra>, RemoveError>>>,
+	pub(crate) recv_output_device: Receiver<Request<OutputRequest, Result<(), OutputError>>>,
+
+	// Signals sent with `Engine::send`, nobody waits for these.
+	pub(crate) recv_signal: Receiver<SendSignal<Extra>>,
+
+	// [Media]
+	//
+	// If `Media` is not spawned, `from_media` never receives anything.
+	pub(crate) from_media:    Receiver<MediaToKernel>,
+	pub(crate) to_media:      Sender<AudioStateSnapshot<Extra>>,
+	pub(crate) to_media_seek: Sender<Result<AudioStateSnapshot<Extra>, SeekError>>,
 }
 
 //---------------------------------------------------------------------------------------------------- Kernel Impl
@@ -203,6 +302,7 @@ pub(crate) struct InitArgs<Extra: ExtraData> {
 	pub(crate) shutdown_blocking: bool,
 	pub(crate) barrier:           Arc<Barrier>,
 	pub(crate) atomic_state:      Arc<AtomicState>,
+	pub(crate) error_decode_retry: usize,
 	pub(crate) w:                 someday::Writer<AudioState<Extra>>,
 	pub(crate) channels:          Channels<Extra>,
 }
@@ -222,6 +322,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 					shutdown_blocking,
 					barrier,
 					atomic_state,
+					error_decode_retry,
 					w,
 					channels,
 				} = args;
@@ -231,6 +332,11 @@ impl<Extra: ExtraData> Kernel<Extra> {
 					w,
 					barrier,
 					shutdown_blocking,
+					error_decode_retry,
+					error_decode_count: 0,
+					error_skip_count: 0,
+					source_generation: 0,
+					queue_generation: 0,
 				};
 
 				crate::free::init(ACTOR, init_blocking, &this.barrier);
@@ -275,12 +381,20 @@ impl<Extra: ExtraData> Kernel<Extra> {
 		assert_eq!(17, select.recv(&c.recv_set_index));
 		assert_eq!(18, select.recv(&c.recv_remove));
 		assert_eq!(19, select.recv(&c.recv_remove_range));
+		assert_eq!(20, select.recv(&c.recv_output_device));
+		assert_eq!(21, select.recv(&c.recv_signal));
+		// From `Media`.
+		assert_eq!(22, select.recv(&c.from_media));
 		// Errors
-		assert_eq!(20, select.recv(&c.from_audio_error));
-		assert_eq!(21, select.recv(&c.from_decode_error_decode));
-		assert_eq!(22, select.recv(&c.from_decode_error_source));
+		assert_eq!(23, select.recv(&c.from_audio_error));
+		assert_eq!(24, select.recv(&c.from_decode_error_decode));
+		assert_eq!(25, select.recv(&c.from_decode_error_source));
+		// `Audio`'s responses to `Engine::send` output requests,
+		// nobody else waits for these so we forward the errors.
+		let (to_kernel_output, from_audio_output) = crossbeam::channel::unbounded();
+		assert_eq!(26, select.recv(&from_audio_output));
 		// Shutdown
-		assert_eq!(23, select.recv(&c.shutdown));
+		assert_eq!(27, select.recv(&c.shutdown));
 
 		loop {
 			// 1. Receive a signal
@@ -295,7 +409,24 @@ impl<Extra: ExtraData> Kernel<Extra> {
 			// (e.g: `repeat()` is called, but our current `Repeat` is the same)
 			// so `Kernel` must check all requests and return early (or with
 			// and error) if invalid.
-			match select.ready() {
+			let mut signal = select.ready();
+			// Signals sent with `Engine::send` before a waiting
+			// signal must be applied first, the waiting signal
+			// stays in its channel until the next loop.
+			if (1..=20).contains(&signal) && !c.recv_signal.is_empty() {
+				signal = 21;
+			}
+			stats!(crate::state::AtomicStats::inc(&self.atomic_state.stats.messages_kernel));
+
+			// If anyone wants `Event`'s, capture the state before
+			// handling the message, and send what changed after.
+			//
+			// `Audio` messages are captured below, the most common
+			// one (`WroteAudioBuffer`) only changes the elapsed time.
+			let mut before = if signal == 0 { None } else { self.event_state() };
+			let mut cause = if signal == 14 { Cause::Seek } else { Cause::Other };
+
+			match signal {
 				// From `Audio`.
 				//
 				// Should be at the top of the list since this
@@ -303,17 +434,27 @@ impl<Extra: ExtraData> Kernel<Extra> {
 				// during runtime.
 				0 => {
 					let msg = select_recv!(c.from_audio);
+					if !matches!(msg, AudioToKernel::WroteAudioBuffer(_)) {
+						before = self.event_state();
+					}
 					match msg {
 						// `Audio` just played back this audio buffer with
 						// this timestamp, update the `AudioState` with it.
-						AudioToKernel::WroteAudioBuffer(time) => self.wrote_audio_buffer(time),
+						AudioToKernel::WroteAudioBuffer(time) => self.wrote_audio_buffer(time, &c.to_caller_event),
 						// This message represents that:
 						// 1. `Audio` has played the last audio buffer
 						// 2. `Decode` has sent all its cached audio buffers
 						// 3. `Decode` can start decoding the next track
 						AudioToKernel::EndOfTrack => {
+							cause = Cause::EndOfTrack;
 							self.next_inner(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode);
 						},
+						// `Audio` lost its output device and
+						// `DeviceLoss::Pause` is being used.
+						AudioToKernel::DeviceLost(error) => self.device_lost(error, &c.to_caller_error_output.0),
+						// `Audio` (re-)opened its output and
+						// the bit-perfect status changed.
+						AudioToKernel::BitPerfect(bit_perfect) => self.bit_perfect(bit_perfect),
 					}
 				},
 
@@ -334,35 +475,114 @@ impl<Extra: ExtraData> Kernel<Extra> {
 				//      |                                 |                                 |
 				//      |                                 |      |------------------------------------------------------|
 				//      v                                 v      v                                                      v
-				1  => { select_recv!(c.recv_toggle); self.toggle(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state); },
-				2  => { select_recv!(c.recv_play); self.play(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state); },
-				3  => { select_recv!(c.recv_pause); self.pause(&c.send_audio_state); },
-				4  => { select_recv!(c.recv_stop); self.stop(&c.send_audio_state); },
-				5  => { select_recv!(c.recv_next); self.next(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state); },
-				6  => { select_recv!(c.recv_previous); self.previous(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state); },
-				7  => self.clear(select_recv!(c.recv_clear), &c.to_gc, &c.send_audio_state),
-				8  => self.shuffle(select_recv!(c.recv_shuffle), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state),
-				9  => self.repeat(select_recv!(c.recv_repeat), &c.send_audio_state),
-				10 => self.volume(select_recv!(c.recv_volume), &c.send_audio_state),
-				11 => self.restore(select_recv!(c.recv_restore), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state),
-				12 => self.add(select_recv!(c.recv_add), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state),
-				13 => self.add_many(select_recv!(c.recv_add_many), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_audio_state),
-				14 => self.seek(select_recv!(c.recv_seek), &c.to_audio, &c.to_decode, &c.from_decode_seek, &c.send_seek),
-				15 => self.skip(select_recv!(c.recv_skip), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_skip),
-				16 => self.back(select_recv!(c.recv_back), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_back),
-				17 => self.set_index(select_recv!(c.recv_set_index), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_set_index),
-				18 => self.remove(select_recv!(c.recv_remove), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_remove),
-				19 => self.remove_range(select_recv!(c.recv_remove_range), &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.send_remove_range),
+				1  => { let ((), to_engine) = select_recv!(c.recv_toggle); self.toggle(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				2  => { let ((), to_engine) = select_recv!(c.recv_play); self.play(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				3  => { let ((), to_engine) = select_recv!(c.recv_pause); self.pause(&to_engine); },
+				4  => { let ((), to_engine) = select_recv!(c.recv_stop); self.stop(&to_engine); },
+				5  => { let ((), to_engine) = select_recv!(c.recv_next); self.next(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				6  => { let ((), to_engine) = select_recv!(c.recv_previous); self.previous(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				7  => { let (clear, to_engine) = select_recv!(c.recv_clear); self.clear(clear, &c.to_gc, &to_engine); },
+				8  => {
+					let (shuffle, to_engine) = select_recv!(c.recv_shuffle);
+					cause = Cause::Shuffle(shuffle);
+					self.shuffle(shuffle, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine);
+				},
+				9  => { let (repeat, to_engine) = select_recv!(c.recv_repeat); self.repeat(repeat, &to_engine); },
+				10 => { let (volume, to_engine) = select_recv!(c.recv_volume); self.volume(volume, &to_engine); },
+				11 => { let (restore, to_engine) = select_recv!(c.recv_restore); self.restore(restore, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				12 => { let (add, to_engine) = select_recv!(c.recv_add); self.add(add, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				13 => { let (add_many, to_engine) = select_recv!(c.recv_add_many); self.add_many(add_many, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				14 => { let (seek, to_engine) = select_recv!(c.recv_seek); self.seek(seek, &c.to_audio, &c.to_decode, &c.from_decode_seek, &to_engine); },
+				15 => { let (skip, to_engine) = select_recv!(c.recv_skip); self.skip(skip, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				16 => { let (back, to_engine) = select_recv!(c.recv_back); self.back(back, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				17 => { let (set_index, to_engine) = select_recv!(c.recv_set_index); self.set_index(set_index, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				18 => { let (remove, to_engine) = select_recv!(c.recv_remove); self.remove(remove, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				19 => { let (remove_range, to_engine) = select_recv!(c.recv_remove_range); self.remove_range(remove_range, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &to_engine); },
+				20 => { let (request, to_engine) = select_recv!(c.recv_output_device); self.output_device(request, &c.to_audio, to_engine); },
+
+				// From `Engine::send`.
+				//
+				// Same as above, although nobody is waiting
+				// for the response, so errors become `Event`'s.
+				21 => match select_recv!(c.recv_signal) {
+					SendSignal::Toggle   => { detached(|to_engine| self.toggle(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+					SendSignal::Play     => { detached(|to_engine| self.play(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+					SendSignal::Pause    => { detached(|to_engine| self.pause(to_engine)); },
+					SendSignal::Stop     => { detached(|to_engine| self.stop(to_engine)); },
+					SendSignal::Next     => { detached(|to_engine| self.next(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+					SendSignal::Previous => { detached(|to_engine| self.previous(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+					SendSignal::Clear(clear) => { detached(|to_engine| self.clear(clear, &c.to_gc, to_engine)); },
+					SendSignal::Shuffle(shuffle) => {
+						cause = Cause::Shuffle(shuffle);
+						detached(|to_engine| self.shuffle(shuffle, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine));
+					},
+					SendSignal::Repeat(repeat)    => { detached(|to_engine| self.repeat(repeat, to_engine)); },
+					SendSignal::Volume(volume)    => { detached(|to_engine| self.volume(volume, to_engine)); },
+					SendSignal::Add(add)          => { detached(|to_engine| self.add(add, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+					SendSignal::AddMany(add_many) => { detached(|to_engine| self.add_many(add_many, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+					SendSignal::Seek(seek) => {
+						cause = Cause::Seek;
+						if let Err(e) = detached(|to_engine| self.seek(seek, &c.to_audio, &c.to_decode, &c.from_decode_seek, to_engine)) {
+							self.event_signal_error(e.into(), &c.to_caller_event);
+						}
+					},
+					SendSignal::Skip(skip) => if let Err(e) = detached(|to_engine| self.skip(skip, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)) {
+						self.event_signal_error(e.into(), &c.to_caller_event);
+					},
+					SendSignal::Back(back) => if let Err(e) = detached(|to_engine| self.back(back, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)) {
+						self.event_signal_error(e.into(), &c.to_caller_event);
+					},
+					SendSignal::SetIndex(set_index) => if let Err(e) = detached(|to_engine| self.set_index(set_index, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)) {
+						self.event_signal_error(e.into(), &c.to_caller_event);
+					},
+					SendSignal::Remove(remove) => if let Err(e) = detached(|to_engine| self.remove(remove, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)) {
+						self.event_signal_error(e.into(), &c.to_caller_event);
+					},
+					SendSignal::RemoveRange(remove_range) => if let Err(e) = detached(|to_engine| self.remove_range(remove_range, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)) {
+						self.event_signal_error(e.into(), &c.to_caller_event);
+					},
+					SendSignal::Restore(restore) => match restore {
+						Ok(audio_state) => { detached(|to_engine| self.restore(audio_state, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, to_engine)); },
+						Err(e) => self.event_signal_error(e.into(), &c.to_caller_event),
+					},
+					// `Audio` responds later, see `26`.
+					SendSignal::OutputDevice(device) => self.output_device(OutputRequest::Device(device), &c.to_audio, to_kernel_output.clone()),
+					SendSignal::OutputLatency(latency) => self.output_device(OutputRequest::Latency(latency), &c.to_audio, to_kernel_output.clone()),
+				},
+
+				// From `Media`.
+				//
+				// The same handlers as the `Engine` signals above, but
+				// the response is sent back to `Media` instead.
+				22 => match select_recv!(c.from_media) {
+					MediaToKernel::Toggle       => self.toggle(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.to_media),
+					MediaToKernel::Play         => self.play(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.to_media),
+					MediaToKernel::Pause        => self.pause(&c.to_media),
+					MediaToKernel::Stop         => self.stop(&c.to_media),
+					MediaToKernel::Next         => self.next(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.to_media),
+					MediaToKernel::Previous     => self.previous(&c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode, &c.to_media),
+					MediaToKernel::Seek(seek)   => {
+						cause = Cause::Seek;
+						self.seek(seek, &c.to_audio, &c.to_decode, &c.from_decode_seek, &c.to_media_seek);
+					},
+					MediaToKernel::Volume(volume) => self.volume(volume, &c.to_media),
+				},
 
 				// Errors.
-				20 => self.error_output(select_recv!(c.from_audio_error), &c.to_caller_error_output),
-				21 => self.error_decode(select_recv!(c.from_decode_error_decode), &c.to_caller_error_decode),
-				22 => self.error_source(select_recv!(c.from_decode_error_source), &c.to_caller_error_source),
+				23 => self.error_output(select_recv!(c.from_audio_error), &c.to_caller_error_output, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode),
+				24 => self.error_decode(select_recv!(c.from_decode_error_decode), &c.to_caller_error_decode, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode),
+				25 => self.error_source(select_recv!(c.from_decode_error_source), &c.to_caller_error_source, &c.to_gc, &c.to_caller_source_new, &c.to_audio, &c.to_decode),
+				26 => if let Err(e) = select_recv!(from_audio_output) {
+					self.event_signal_error(e.into(), &c.to_caller_event);
+				},
 
 				// Shutdown.
-				23 => {
+				27 => {
 					let blocking = select_recv!(c.shutdown);
 
+					// Let `Caller` know before it shuts down.
+					self.event_shutdown(&c.to_caller_event);
+
 					// Tell all actors to shutdown.
 					try_send!(c.to_decode, KernelToDecode::Shutdown);
 					try_send!(c.to_audio, KernelToAudio::Shutdown);
@@ -381,6 +601,10 @@ impl<Extra: ExtraData> Kernel<Extra> {
 
 				_ => unreachable!(),
 			}
+
+			if let Some(before) = before {
+				self.events(before, cause, &c.to_caller_event);
+			}
 		}
 	}
 
@@ -389,10 +613,18 @@ impl<Extra: ExtraData> Kernel<Extra> {
 	fn error_decode(
 		&mut self,
 		error: DecodeError,
-		to_caller: &(Sender<DecodeError>, bool),
+		to_caller: &(Sender<DecodeError>, ErrorAction),
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
 	) {
-		if to_caller.1 {
-			self.pause_inner();
+		// Some decode errors are transient (a few bad packets),
+		// so only act once we've hit the user's retry limit.
+		self.error_decode_count = self.error_decode_count.saturating_add(1);
+		if self.error_decode_count > self.error_decode_retry {
+			self.error_decode_count = 0;
+			self.error_action(to_caller.1, to_gc, to_caller_source_new, to_audio, to_decode);
 		}
 		try_send!(to_caller.0, error);
 	}
@@ -401,11 +633,13 @@ impl<Extra: ExtraData> Kernel<Extra> {
 	fn error_source(
 		&mut self,
 		error: SourceError,
-		to_caller: &(Sender<SourceError>, bool),
+		to_caller: &(Sender<SourceError>, ErrorAction),
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
 	) {
-		if to_caller.1 {
-			self.pause_inner();
-		}
+		self.error_action(to_caller.1, to_gc, to_caller_source_new, to_audio, to_decode);
 		try_send!(to_caller.0, error);
 	}
 
@@ -413,27 +647,143 @@ impl<Extra: ExtraData> Kernel<Extra> {
 	fn error_output(
 		&mut self,
 		error: OutputError,
-		to_caller: &(Sender<OutputError>, bool),
+		to_caller: &(Sender<OutputError>, ErrorAction),
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
 	) {
-		if to_caller.1 {
-			self.pause_inner();
+		// Glitches are not fatal, only report them.
+		if !matches!(error, OutputError::Glitch(_)) {
+			self.error_action(to_caller.1, to_gc, to_caller_source_new, to_audio, to_decode);
 		}
 		try_send!(to_caller.0, error);
 	}
 
+	/// Act upon an `ErrorCallback`'s action.
+	///
+	/// The function part of the `ErrorCallback` (if any)
+	/// is executed by `Caller`, not us.
+	fn error_action(
+		&mut self,
+		action: ErrorAction,
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
+	) {
+		match action {
+			ErrorAction::Nothing => (),
+			ErrorAction::Pause => {
+				if self.current_is_some() && self.playing() {
+					self.pause_inner();
+				}
+			},
+			ErrorAction::Skip => self.error_skip(to_gc, to_caller_source_new, to_audio, to_decode),
+			ErrorAction::Remove => self.error_remove(to_gc, to_caller_source_new, to_audio, to_decode),
+		}
+	}
+
+	/// `ErrorCallback::Skip`.
+	///
+	/// Skip to the next entry in the queue, ignoring `Repeat::Current`
+	/// (repeating a broken `Source` would just error again).
+	fn error_skip(
+		&mut self,
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
+	) {
+		let Some(current) = self.w.current.as_ref() else {
+			return;
+		};
+
+		// If every `Source` in the queue has been tried and errored
+		// in a row, there is nothing playable, so end the queue (like
+		// any other end-of-queue) instead of cycling through it forever.
+		self.error_skip_count = self.error_skip_count.saturating_add(1);
+		let next_index = current.index.saturating_add(1);
+		let maybe_source_index = if self.error_skip_count >= self.w.queue.len() {
+			debug2!("{ACTOR} - error_skip(), all sources errored, ending queue");
+			self.error_skip_count = 0;
+			None
+		} else if next_index < self.w.queue.len() {
+			Some(next_index)
+		} else if self.w.repeat == Repeat::Queue {
+			Some(0)
+		} else {
+			None
+		};
+
+		self.skip_to(maybe_source_index, to_gc, to_caller_source_new, to_audio, to_decode);
+	}
+
+	/// `ErrorCallback::Remove`.
+	///
+	/// Remove the current `Source` from the queue,
+	/// the next entry (if any) will take its place.
+	fn error_remove(
+		&mut self,
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
+	) {
+		let Some(index) = self.w.current.as_ref().map(|c| c.index) else {
+			return;
+		};
+
+		let remove_range = RemoveRange {
+			start_bound: Bound::Included(index),
+			end_bound: Bound::Included(index),
+		};
+
+		if let Err(e) = self.remove_range_inner(remove_range, to_gc, to_caller_source_new, to_audio, to_decode) {
+			debug2!("{ACTOR} - error_remove(), {e:?}");
+		}
+	}
+
 	//---------------------------------------------------------------------------------------------------- From Audio
 	#[inline]
 	/// Handler to when `Audio` messages us.
-	fn wrote_audio_buffer(&mut self, time: Time) {
+	fn wrote_audio_buffer(
+		&mut self,
+		time: Time,
+		to_caller_event: &Sender<(Event<Extra>, AudioStateSnapshot<Extra>)>,
+	) {
+		// Audio is successfully playing,
+		// reset our error counters.
+		self.error_decode_count = 0;
+		self.error_skip_count = 0;
+
 		// Calculate total time elapsed.
 		let elapsed = time.seconds as f32 + time.frac as f32;
 
+		if self.w.current.as_ref().map_or(true, |c| c.elapsed == elapsed) {
+			return;
+		}
+
 		// Update the `AudioState`.
 		self.w.add_commit_push(|w, _| {
 			if let Some(current) = w.current.as_mut() {
 				current.elapsed = elapsed;
 			}
 		});
+
+		self.event_elapsed(elapsed, to_caller_event);
+	}
+
+	#[inline]
+	/// `Audio` is telling us whether the output path is bit-perfect.
+	fn bit_perfect(&mut self, bit_perfect: bool) {
+		if self.w.bit_perfect == bit_perfect {
+			return;
+		}
+
+		self.w.add_commit_push(|w, _| {
+			w.bit_perfect = bit_perfect;
+		});
 	}
 
 	//---------------------------------------------------------------------------------------------------- Misc Functions
@@ -443,7 +793,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 	#[inline]
 	/// TODO
 	pub(super) fn reset_source(
-		&self,
+		&mut self,
 		to_audio: &Sender<KernelToAudio>,
 		to_decode: &Sender<KernelToDecode<Extra>>,
 		to_caller_source_new: &Sender<Source<Extra>>,
@@ -464,6 +814,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 
 		// Send over the new `Source` to be decoded.
 		try_send!(to_decode, KernelToDecode::NewSource(source.clone()));
+		self.source_generation = self.source_generation.wrapping_add(1);
 
 		// Tell `Caller` that there is a new source.
 		try_send!(to_caller_source_new, source);
@@ -479,11 +830,13 @@ impl<Extra: ExtraData> Kernel<Extra> {
 	/// - `Decode` should not wipe any current data
 	/// - `Decode` should get started decoding this new `Source` ASAP
 	pub(super) fn new_source(
+		&mut self,
 		to_decode: &Sender<KernelToDecode<Extra>>,
 		to_caller_new_source: &Sender<Source<Extra>>,
 		source: Source<Extra>,
 	) {
 		try_send!(to_decode, KernelToDecode::NewSource(source.clone()));
+		self.source_generation = self.source_generation.wrapping_add(1);
 		try_send!(to_caller_new_source, source);
 	}
 
@@ -528,4 +881,162 @@ impl<Extra: ExtraData> Kernel<Extra> {
 
 //---------------------------------------------------------------------------------------------------- Tests
 #[cfg(test)]
-mod tests {}
\ No newline at end of file
+mod tests {
+	use super::*;
+	use crate::{
+		config::InitConfig,
+		event::EventKind,
+		source::Sources,
+		signal::SetIndex,
+	};
+	use pretty_assertions::assert_eq;
+	use std::{thread::sleep, time::Duration};
+
+	/// `[broken, 1, 2]`
+	fn sources_broken_first() -> Sources<usize> {
+		Sources::from_1_and_iter(
+			crate::tests::source_broken(0),
+			[crate::tests::source(1), crate::tests::source(2)].into_iter(),
+		)
+	}
+
+	/// Receive `Event::SourceChanged`'s until `f` returns `true` for one.
+	fn wait_source_changed(
+		events: &Receiver<Event<usize>>,
+		f: impl Fn(Option<&Current<usize>>) -> bool,
+	) {
+		loop {
+			match events.recv_timeout(Duration::from_secs(5)).unwrap() {
+				Event::SourceChanged(current) if f(current.as_ref()) => return,
+				_ => (),
+			}
+		}
+	}
+
+	#[test]
+	fn error_source_skip() {
+		let mut config = InitConfig::DEFAULT;
+		config.callbacks.error_source(ErrorCallback::Skip);
+		let mut engine = crate::tests::init_with_config(config);
+		let events = engine.subscribe(EventKind::SourceChanged.into());
+
+		engine.add_many(AddMany {
+			sources: sources_broken_first(),
+			method: AddMethod::Back,
+			clear: false,
+			play: false,
+		});
+		let resp = engine.set_index(SetIndex { index: 0, start_playing: true }).unwrap();
+		assert_eq!(resp.current.as_ref().unwrap().index, 0);
+
+		// Wait for `Decode` to error and `Kernel` to skip.
+		wait_source_changed(&events, |c| c.is_some_and(|c| c.index == 1));
+
+		let state = engine.reader().get();
+		assert_eq!(state.queue.len(), 3);
+		assert_eq!(state.current.as_ref().unwrap().index, 1);
+		assert_eq!(*state.current.as_ref().unwrap().source.extra(), 1);
+		assert!(state.playing);
+	}
+
+	#[test]
+	fn error_source_remove() {
+		let mut config = InitConfig::DEFAULT;
+		config.callbacks.error_source(ErrorCallback::Remove);
+		let mut engine = crate::tests::init_with_config(config);
+		let events = engine.subscribe(EventKind::SourceChanged.into());
+
+		engine.add_many(AddMany {
+			sources: sources_broken_first(),
+			method: AddMethod::Back,
+			clear: false,
+			play: false,
+		});
+		engine.set_index(SetIndex { index: 0, start_playing: true }).unwrap();
+
+		// Wait for `Decode` to error and `Kernel` to remove.
+		wait_source_changed(&events, |c| c.is_some_and(|c| *c.source.extra() == 1));
+
+		let state = engine.reader().get();
+		let extra: Vec<usize> = state.queue.iter().map(|s| *s.extra()).collect();
+		assert_eq!(extra, [1, 2]);
+		assert_eq!(state.current.as_ref().unwrap().index, 0);
+		assert_eq!(*state.current.as_ref().unwrap().source.extra(), 1);
+	}
+
+	#[test]
+	fn error_source_skip_all_broken() {
+		let mut config = InitConfig::DEFAULT;
+		config.callbacks.error_source(ErrorCallback::Skip);
+		let mut engine = crate::tests::init_with_config(config);
+		let events = engine.subscribe(EventKind::SourceChanged.into());
+
+		engine.add_many(AddMany {
+			sources: Sources::from_1_and_iter(
+				crate::tests::source_broken(0),
+				[crate::tests::source_broken(1), crate::tests::source_broken(2)].into_iter(),
+			),
+			method: AddMethod::Back,
+			clear: false,
+			play: false,
+		});
+		engine.repeat(Repeat::Queue);
+		engine.set_index(SetIndex { index: 0, start_playing: true }).unwrap();
+
+		// Every `Source` is broken, once each has been
+		// tried, `Kernel` should end the queue instead
+		// of looping forever (even with `Repeat::Queue`).
+		wait_source_changed(&events, Option::is_none);
+
+		let state = engine.reader().get();
+		assert!(!state.playing);
+		assert!(state.current.is_none());
+	}
+
+	#[test]
+	fn error_source_skip_single_broken() {
+		let mut config = InitConfig::DEFAULT;
+		config.callbacks.error_source(ErrorCallback::Skip);
+		let mut engine = crate::tests::init_with_config(config);
+		let events = engine.subscribe(EventKind::SourceChanged.into());
+
+		// A queue with 1 broken `Source` ends, it does not pause.
+		engine.add(Add {
+			source: crate::tests::source_broken(0),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		wait_source_changed(&events, Option::is_none);
+
+		let state = engine.reader().get();
+		assert!(!state.playing);
+		assert!(state.current.is_none());
+	}
+
+	#[test]
+	fn bit_perfect() {
+		// The default dummy device only supports 44.1kHz,
+		// the test source is 48kHz, so it needs resampling.
+		let _lock = crate::tests::dummy_devices_lock();
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			bit_perfect: true,
+			..InitConfig::DEFAULT
+		});
+		// Dummy outputs play faster than real-time, keep the track going.
+		engine.repeat(Repeat::Current);
+		engine.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		sleep(Duration::from_millis(500));
+		assert!(!engine.reader().get().bit_perfect);
+
+		// The dummy DAC supports 48kHz.
+		engine.output_device(Some("dummy_usb_dac".into())).unwrap();
+		sleep(Duration::from_millis(500));
+		assert!(engine.reader().get().bit_perfect);
+	}
+}
@@ -4,12 +4,18 @@ mod kernel;
 pub(crate) use kernel::{
 	Kernel,
 	KernelToAudio,
+	OutputRequest,
+	MediaToKernel,
 	Channels,
 	InitArgs,
 	KernelToDecode,
 	KernelToGc,
+	Request,
 };
 
+// `Event` generation.
+mod events;
+
 // Signal handlers.
 mod toggle;
 mod play;
@@ -30,3 +36,4 @@ mod back;
 mod set_index;
 mod remove;
 mod remove_range;
+mod output_device;
@@ -0,0 +1,157 @@
+//! TODO
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	actor::kernel::kernel::{Kernel,KernelToAudio,OutputRequest},
+	extra_data::ExtraData,
+	error::OutputError,
+	macros::{try_send,debug2},
+};
+use crossbeam::channel::Sender;
+
+//----------------------------------------------------------------------------------------------------
+impl<Extra: ExtraData> Kernel<Extra> {
+	/// Switch the audio output device, or change its latency.
+	///
+	/// This doesn't touch the `AudioState`, `Audio`
+	/// is the one holding the actual device connection,
+	/// so this just forwards the request.
+	///
+	/// Opening a device can take a while, so `Audio` sends
+	/// the result directly to `Engine`, and we continue
+	/// handling other messages in the meanwhile.
+	pub(super) fn output_device(
+		&self,
+		request: OutputRequest,
+		to_audio: &Sender<KernelToAudio>,
+		to_engine: Sender<Result<(), OutputError>>,
+	) {
+		let msg = match request {
+			OutputRequest::Device(device) => {
+				debug2!("Kernel - output_device(), device: {device:?}");
+				KernelToAudio::OutputDevice(device, to_engine)
+			},
+			OutputRequest::Latency(latency) => {
+				debug2!("Kernel - output_device(), latency: {latency:?}");
+				KernelToAudio::OutputLatency(latency, to_engine)
+			},
+		};
+		try_send!(to_audio, msg);
+	}
+
+	/// `Audio` lost its output device and `DeviceLoss::Pause` is
+	/// being used, pause and forward the error to `Caller`.
+	///
+	/// The `ErrorCallback` action is not used here, the
+	/// `DeviceLoss` policy has already decided what to do.
+	pub(super) fn device_lost(
+		&mut self,
+		error: OutputError,
+		to_caller_error_output: &Sender<OutputError>,
+	) {
+		debug2!("Kernel - device_lost(), error: {error}");
+
+		if self.current_is_some() && self.playing() {
+			self.pause_inner();
+		}
+
+		try_send!(to_caller_error_output, error);
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::output::OutputBackend;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn output_device() {
+		let _lock = crate::tests::dummy_devices_lock();
+		let mut engine = crate::tests::init();
+
+		//---------------------------------- Devices are listed
+		let devices = crate::output::devices(OutputBackend::Dummy).unwrap();
+		assert_eq!(devices.iter().filter(|d| d.default).count(), 1);
+		assert!(devices.iter().any(|d| d.id == "dummy_usb_dac"));
+
+		//---------------------------------- Switch to existing device
+		engine.output_device(Some("dummy_usb_dac".into())).unwrap();
+		assert_eq!(engine.output_backend(), Some(OutputBackend::Dummy));
+
+		//---------------------------------- Switch to non-existent device
+		let resp = engine.output_device(Some("this device does not exist".into()));
+		assert!(matches!(resp, Err(OutputError::DeviceUnavailable)));
+
+		//---------------------------------- Switch back to the default
+		engine.output_device(None).unwrap();
+	}
+
+	#[test]
+	fn output_latency() {
+		use crate::output::Latency;
+		use std::time::Duration;
+
+		let mut engine = crate::tests::init();
+
+		//---------------------------------- The (fake) device's latency is the requested latency
+		assert_eq!(engine.latency(), Some(Latency::DEFAULT.duration()));
+
+		//---------------------------------- Low latency
+		engine.output_latency(Latency::LOW).unwrap();
+		assert_eq!(engine.latency(), Some(Duration::from_millis(10)));
+
+		//---------------------------------- Large buffer
+		engine.output_latency(Latency::HIGH).unwrap();
+		assert_eq!(engine.latency(), Some(Duration::from_millis(500)));
+		assert_eq!(engine.output_backend(), Some(OutputBackend::Dummy));
+	}
+
+	#[test]
+	fn device_lost_pause() {
+		use crate::{
+			config::{InitConfig,DeviceLoss},
+			event::{Event,EventKind},
+			output::{OutputDevice,dummy_devices},
+			signal::{Add,AddMethod},
+		};
+
+		// INVARIANT: This must keep the default devices available,
+		// other tests that don't hold the lock use them.
+		let _lock = crate::tests::dummy_devices_lock();
+		let mut devices = crate::output::devices(OutputBackend::Dummy).unwrap();
+		let unplugged = OutputDevice {
+			name: "Dummy Headphones".into(),
+			id: "dummy_headphones".into(),
+			sample_rates: vec![44_100],
+			channels: vec![2],
+			default: false,
+		};
+		devices.push(unplugged);
+		dummy_devices(devices.clone());
+
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			output_device: Some("dummy_headphones".into()),
+			device_loss: DeviceLoss::Pause,
+			..InitConfig::DEFAULT
+		});
+		let paused = engine.subscribe(EventKind::Paused.into());
+
+		engine.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		assert!(engine.reader().get().playing);
+
+		//---------------------------------- Unplug
+		devices.pop();
+		dummy_devices(devices);
+		let event = paused.recv_timeout(std::time::Duration::from_secs(5)).unwrap();
+		assert!(matches!(event, Event::Paused));
+
+		assert_eq!(engine.reader().get().playing, false);
+	}
+}
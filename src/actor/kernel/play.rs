@@ -39,7 +39,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 
 		// Tell audio/decode to start if we're starting a new source.
 		if let Some(source) = maybe_source.clone() {
-			Self::new_source(to_decode, to_caller_source_new, source);
+			self.new_source(to_decode, to_caller_source_new, source);
 		}
 
 		self.atomic_state.playing.store(true, Ordering::Release);
@@ -80,7 +80,7 @@ mod tests {
 		};
 
 		//---------------------------------- 1 backwards.
-		let resp = engine.restore(audio_state.clone());
+		let resp = engine.restore(audio_state.clone()).unwrap();
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 		let resp = engine.previous();
 		let current = resp.current.as_ref().unwrap();
@@ -95,7 +95,7 @@ mod tests {
 		//---------------------------------- Threshold passed, restart index
 		audio_state.current.as_mut().unwrap().elapsed = 123.123; // passed threshold
 		audio_state.current.as_mut().unwrap().index = 1;
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		let current = resp.current.as_ref().unwrap();
 		assert_eq!(current.index, 1);
 		assert_eq!(current.elapsed, 123.123);
@@ -33,9 +33,22 @@ impl<Extra: ExtraData> Kernel<Extra> {
 		to_decode: &Sender<KernelToDecode<Extra>>,
 		to_engine: &Sender<Result<AudioStateSnapshot<Extra>, RemoveError>>
 	) {
+		let result = self.remove_range_inner(remove_range, to_gc, to_caller_source_new, to_audio, to_decode);
+		try_send!(to_engine, result.map(|()| self.audio_state_snapshot()));
+	}
+
+	/// The inner part of `remove_range()`, also
+	/// used by `ErrorCallback::Remove`.
+	pub(super) fn remove_range_inner(
+		&mut self,
+		remove_range: RemoveRange,
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
+	) -> Result<(), RemoveError> {
 		if self.queue_empty() {
-			try_send!(to_engine, Err(RemoveError::QueueEmpty));
-			return;
+			return Err(RemoveError::QueueEmpty);
 		}
 
 		// Acquire the _index_ of the start and end bounds.
@@ -64,8 +77,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 		// If the range is empty, or the end is larger
 		// than the queue length, return bad index error.
 		if (start > end) || (end >= self.w.queue.len()) {
-			try_send!(to_engine, Err(RemoveError::BadIndex));
-			return;
+			return Err(RemoveError::BadIndex);
 		}
 
 		// This `'scope` returns an `Option<usize>` calculating
@@ -207,8 +219,9 @@ impl<Extra: ExtraData> Kernel<Extra> {
 				w.playing = false;
 			}
 		});
+		self.queue_generation = self.queue_generation.wrapping_add(1);
 
-		try_send!(to_engine, Ok(self.audio_state_snapshot()));
+		Ok(())
 	}
 }
 
@@ -250,7 +263,7 @@ mod tests {
 				elapsed: 0.0,
 			});
 
-			let resp = engine.restore(audio_state);
+			let resp = engine.restore(audio_state).unwrap();
 			assert_eq!(resp.queue.len(), 10);
 			assert_eq!(resp.current.as_ref().unwrap().index, 4);
 		}
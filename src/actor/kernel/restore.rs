@@ -16,7 +16,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 	/// TODO
 	pub(super) fn restore(
 		&mut self,
-		audio_state: AudioState<Extra>,
+		mut audio_state: AudioState<Extra>,
 		to_gc: &Sender<KernelToGc<Extra>>,
 		to_caller_source_new: &Sender<Source<Extra>>,
 		to_audio: &Sender<KernelToAudio>,
@@ -28,9 +28,13 @@ impl<Extra: ExtraData> Kernel<Extra> {
 		let atomic_state_volume  = audio_state.volume;
 		let atomic_state_playing = audio_state.playing;
 
+		// This is reported by `Audio`, not something that can be restored.
+		audio_state.bit_perfect = self.w.bit_perfect;
+
 		// Overwrite our state and send the old to `Gc`.
 		let old_audio_state = self.w.overwrite(audio_state);
 		try_send!(to_gc, KernelToGc::AudioState(old_audio_state.data));
+		self.queue_generation = self.queue_generation.wrapping_add(1);
 
 		// This scope returns an `Option<Source>` when the restore
 		// operation has made it such that we are setting our [current]
@@ -72,7 +76,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 		}
 
 		if let Some(source) = maybe_source {
-			Self::new_source(to_decode, to_caller_source_new, source);
+			self.new_source(to_decode, to_caller_source_new, source);
 		}
 
 		try_send!(to_engine, self.audio_state_snapshot());
@@ -88,7 +92,7 @@ mod tests {
 		state::AudioState,
 		source::Source,
 		engine::Engine,
-		signal::{repeat::Repeat,volume::Volume,add::AddMany}, state::Current,
+		signal::{repeat::Repeat,volume::Volume,add::AddMany,Restore,Repair,RestoreError}, state::Current,
 	};
 	use std::collections::VecDeque;
 	use pretty_assertions::assert_eq;
@@ -112,19 +116,124 @@ mod tests {
 			playing: true,
 			repeat: Repeat::Current,
 			volume: Volume::ONE,
+			bit_perfect: false,
 		};
 
 		// Assert our current `AudioState` matches the restored version.
-		let resp = engine.restore(audio_state.clone());
+		let resp = engine.restore(audio_state.clone()).unwrap();
 		assert_eq!(*resp, audio_state);
 
 		// Try restoring `AudioState` with a messed up index.
 		audio_state.current.as_mut().unwrap().index = usize::MAX;
 		let resp = engine.restore(audio_state.clone());
+		assert_eq!(resp, Err(RestoreError::CurrentIndex { index: usize::MAX, len: 10 }));
+		// Nothing was restored.
+		assert_eq!(engine.reader().get().current.as_ref().unwrap().index, 0);
+
+		// Try again, but repair it.
+		let resp = engine.restore(Restore {
+			audio_state: audio_state.clone(),
+			repair: Repair { reset_current: true, ..Repair::NONE },
+		}).unwrap();
 		// Assert our current `AudioState` matches the restored version,
 		// with the exception of `Current`, which got purged since it
 		// had a bad index.
 		audio_state.current = None;
 		assert_eq!(*resp, audio_state);
 	}
+
+	#[test]
+	fn repair() {
+		let mut engine = crate::tests::init();
+		let missing = Source::from((std::path::Path::new("assets/audio/missing.mp3"), 100));
+
+		// [0, 1, missing, 3, missing]
+		let mut queue: VecDeque<Source<usize>> = (0..5).map(crate::tests::source).collect();
+		queue[2] = missing.clone();
+		queue[4] = missing;
+		let audio_state = AudioState {
+			current: Some(Current {
+				source: queue[3].clone(),
+				index: 3,
+				elapsed: 1.0,
+			}),
+			queue,
+			..AudioState::DEFAULT
+		};
+
+		//---------------------------------- Missing files
+		let resp = engine.restore(audio_state.clone());
+		assert_eq!(resp, Err(RestoreError::Missing(2)));
+
+		let resp = engine.restore(Restore {
+			audio_state: audio_state.clone(),
+			repair: Repair { drop_missing: true, ..Repair::NONE },
+		}).unwrap();
+		assert_eq!(resp.queue.len(), 3);
+		// `Current` was re-indexed.
+		let current = resp.current.as_ref().unwrap();
+		assert_eq!(current.index, 2);
+		assert_eq!(current.source, resp.queue[2]);
+		assert_eq!(current.elapsed, 1.0);
+
+		// `Current` itself was missing.
+		let mut state = audio_state.clone();
+		state.current.as_mut().unwrap().index = 4;
+		let resp = engine.restore(Restore {
+			audio_state: state,
+			repair: Repair { drop_missing: true, ..Repair::NONE },
+		}).unwrap();
+		assert_eq!(resp.queue.len(), 3);
+		assert_eq!(resp.current, None);
+
+		//---------------------------------- Out-of-bounds index
+		let mut state = audio_state.clone();
+		state.current.as_mut().unwrap().index = 10;
+		let resp = engine.restore(Restore {
+			audio_state: state,
+			repair: Repair { drop_missing: true, clamp: true, ..Repair::NONE },
+		}).unwrap();
+		let current = resp.current.as_ref().unwrap();
+		assert_eq!(current.index, 2);
+		assert_eq!(current.elapsed, 0.0);
+
+		//---------------------------------- Source mismatch
+		// [0, 1, 2], all existing.
+		let mut audio_state = audio_state;
+		audio_state.queue = (0..3).map(crate::tests::source).collect();
+		audio_state.current.as_mut().unwrap().index = 2;
+
+		let mut state = audio_state.clone();
+		state.queue[2] = crate::tests::source_broken(2);
+		let resp = engine.restore(state.clone());
+		assert_eq!(resp, Err(RestoreError::CurrentSource(2)));
+		let resp = engine.restore(Restore {
+			audio_state: state,
+			repair: Repair::ALL,
+		}).unwrap();
+		assert_eq!(resp.current, None);
+
+		//---------------------------------- Elapsed
+		let mut state = audio_state;
+		state.current.as_mut().unwrap().elapsed = f32::MAX;
+		let resp = engine.restore(state.clone());
+		assert!(matches!(resp, Err(RestoreError::Elapsed { .. })));
+
+		let resp = engine.restore(Restore {
+			audio_state: state.clone(),
+			repair: Repair { clamp: true, ..Repair::NONE },
+		}).unwrap();
+		let elapsed = resp.current.as_ref().unwrap().elapsed;
+		assert!(elapsed > 0.0 && elapsed < f32::MAX);
+
+		// Negative elapsed is repaired without needing the runtime.
+		state.current.as_mut().unwrap().elapsed = -1.0;
+		let resp = engine.restore(state.clone());
+		assert!(matches!(resp, Err(RestoreError::Elapsed { .. })));
+		let resp = engine.restore(Restore {
+			audio_state: state,
+			repair: Repair { clamp: true, ..Repair::NONE },
+		}).unwrap();
+		assert_eq!(resp.current.as_ref().unwrap().elapsed, 0.0);
+	}
 }
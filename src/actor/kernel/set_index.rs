@@ -100,7 +100,7 @@ mod tests {
 			elapsed: 150.5,
 		});
 
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		assert_eq!(resp.queue.len(), 10);
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 
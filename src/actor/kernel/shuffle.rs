@@ -161,6 +161,8 @@ impl<Extra: ExtraData> Kernel<Extra> {
 			}
 		});
 
+		self.queue_generation = self.queue_generation.wrapping_add(1);
+
 		// This shuffle might be [Shuffle::Reset] which _may_
 		// set our [current] to queue[0], so we must forward
 		// it to [Decode].
@@ -219,7 +221,7 @@ mod tests {
 			elapsed: 150.5,
 		});
 
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		assert_eq!(resp.queue.len(), 10);
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 		let queue_data: Vec<usize> = resp.queue.iter().map(|s| *s.extra()).collect();
@@ -70,6 +70,25 @@ impl<Extra: ExtraData> Kernel<Extra> {
 		// TODO: debug log
 		// println!("maybe_source_index: {maybe_source_index:?}");
 
+		self.skip_to(maybe_source_index, to_gc, to_caller_source_new, to_audio, to_decode);
+	}
+
+	/// Set our `Current` to the `Source` at `maybe_source_index`.
+	///
+	/// `None` means the queue has ended.
+	///
+	/// This is the 2nd half of `skip_inner()`, also used
+	/// by `ErrorCallback::Skip` which calculates its own index.
+	///
+	/// INVARIANT: `maybe_source_index` must be a valid queue index.
+	pub(super) fn skip_to(
+		&mut self,
+		maybe_source_index: Option<usize>,
+		to_gc: &Sender<KernelToGc<Extra>>,
+		to_caller_source_new: &Sender<Source<Extra>>,
+		to_audio: &Sender<KernelToAudio>,
+		to_decode: &Sender<KernelToDecode<Extra>>,
+	) {
 		// Get a `Option<Current>` based off the `Option<usize>` above.
 		let current = maybe_source_index.map(|index| {
 			Current {
@@ -101,6 +120,10 @@ impl<Extra: ExtraData> Kernel<Extra> {
 			}
 		});
 
+		if !playing && queue_end_clear {
+			self.queue_generation = self.queue_generation.wrapping_add(1);
+		}
+
 		// Forward potential `Source` to `Audio/Decode`
 		if let Some(current) = current {
 			self.reset_source(to_audio, to_decode, to_caller_source_new, current.source);
@@ -175,7 +198,7 @@ mod tests {
 
 			audio_state
 		};
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 		assert_eq!(resp.repeat, Repeat::Off);
 
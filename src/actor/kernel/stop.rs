@@ -29,6 +29,7 @@ impl<Extra: ExtraData> Kernel<Extra> {
 			w.current = None;
 			w.playing = false;
 		});
+		self.queue_generation = self.queue_generation.wrapping_add(1);
 
 		try_send!(to_engine, self.audio_state_snapshot());
 	}
@@ -69,7 +70,7 @@ mod tests {
 
 			audio_state
 		};
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		assert_eq!(resp.current.as_ref().unwrap().index, 4);
 		assert_eq!(resp.playing, true);
 
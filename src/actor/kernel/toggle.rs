@@ -58,7 +58,7 @@ mod tests {
 
 			audio_state
 		};
-		let resp = engine.restore(audio_state);
+		let resp = engine.restore(audio_state).unwrap();
 		assert_eq!(resp.current.as_ref(), None);
 		assert_eq!(resp.playing, false);
 
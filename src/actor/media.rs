@@ -0,0 +1,649 @@
+//! The OS media controls actor.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	actor::{actor::Actor, kernel::MediaToKernel},
+	config::MediaControls,
+	extra_data::ExtraData,
+	macros::{recv,try_send,select_recv,trace2,debug2,warn2},
+	signal::{Seek,SeekError,Volume},
+	source::{
+		Source,
+		source_decode::{FORMAT_OPTIONS,METADATA_OPTIONS,MEDIA_SOURCE_STREAM_OPTIONS},
+	},
+	state::{AtomicState,AudioStateReader,AudioStateSnapshot},
+};
+use crossbeam::channel::{Receiver, Select, Sender, unbounded};
+use souvlaki::{
+	MediaControlEvent,
+	MediaControls as Controls,
+	MediaMetadata,
+	MediaPlayback,
+	MediaPosition,
+	PlatformConfig,
+	SeekDirection,
+};
+use symphonia::{
+	core::{
+		io::MediaSourceStream,
+		meta::{StandardTagKey,Tag},
+		probe::Hint,
+	},
+	default::get_probe,
+};
+use std::{
+	fs::File,
+	io::Cursor,
+	ops::ControlFlow,
+	sync::{
+		Arc,
+		Barrier,
+		atomic::Ordering,
+	},
+	time::{Duration,Instant},
+};
+
+//---------------------------------------------------------------------------------------------------- Constants
+/// Actor name.
+const ACTOR: &str = "Media";
+
+/// How far (in seconds) the published position may drift from the
+/// real position before it is re-published, on top of the elapsed
+/// refresh rate (`AudioState`'s elapsed time lags behind by that much).
+///
+/// The OS media controls keep track of the position themselves once it
+/// is published, so it only needs to be re-published after seeks, etc.
+const POSITION_TOLERANCE: f32 = 1.0;
+
+//---------------------------------------------------------------------------------------------------- Media
+/// Publishes the `AudioState` to the OS media controls,
+/// and forwards the OS media controls' requests to `Kernel`.
+#[allow(clippy::missing_docs_in_private_items)]
+pub(crate) struct Media<Extra: ExtraData> {
+	barrier:      Arc<Barrier>,
+	atomic_state: Arc<AtomicState>,
+	reader:       AudioStateReader<Extra>,
+	controls:     Option<Controls>, // `None` if they couldn't be created
+	seek_step:    f32,              // `MediaControls::seek_step` in seconds
+	refresh_rate: Duration,
+	published:    Published<Extra>,
+}
+
+/// What was last published to the OS media controls.
+#[allow(clippy::missing_docs_in_private_items)]
+struct Published<Extra: ExtraData> {
+	source:  Option<Source<Extra>>,
+	playing: bool,
+	elapsed: f32,
+	at:      Instant, // When `elapsed` was published
+	volume:  Volume,
+}
+
+/// See [src/actor/kernel.rs]'s [Channels]
+#[allow(clippy::missing_docs_in_private_items)]
+pub(crate) struct Channels<Extra: ExtraData> {
+	shutdown:         Receiver<()>,
+	events:           Receiver<MediaControlEvent>,
+	to_kernel:        Sender<MediaToKernel>,
+	from_kernel:      Receiver<AudioStateSnapshot<Extra>>,
+	from_kernel_seek: Receiver<Result<AudioStateSnapshot<Extra>, SeekError>>,
+}
+
+//---------------------------------------------------------------------------------------------------- InitArgs
+/// TODO
+#[allow(clippy::missing_docs_in_private_items)]
+pub(crate) struct InitArgs<Extra: ExtraData> {
+	pub(crate) barrier:          Arc<Barrier>,
+	pub(crate) atomic_state:     Arc<AtomicState>,
+	pub(crate) reader:           AudioStateReader<Extra>,
+	pub(crate) config:           MediaControls,
+	pub(crate) shutdown:         Receiver<()>,
+	pub(crate) to_kernel:        Sender<MediaToKernel>,
+	pub(crate) from_kernel:      Receiver<AudioStateSnapshot<Extra>>,
+	pub(crate) from_kernel_seek: Receiver<Result<AudioStateSnapshot<Extra>, SeekError>>,
+}
+
+//---------------------------------------------------------------------------------------------------- Actor
+impl<Extra: ExtraData> Actor for Media<Extra> {
+	const NAME: &'static str = ACTOR;
+
+	type MainArgs = Channels<Extra>;
+	type InitArgs = InitArgs<Extra>;
+
+	#[cold] #[inline(never)]
+	fn barrier(&self) -> &Barrier {
+		&self.barrier
+	}
+
+	#[cold] #[inline(never)]
+	fn init(init_args: Self::InitArgs) -> (Self, Self::MainArgs) {
+		let InitArgs {
+			barrier,
+			atomic_state,
+			reader,
+			config,
+			shutdown,
+			to_kernel,
+			from_kernel,
+			from_kernel_seek,
+		} = init_args;
+
+		// The OS media controls call us back on their own thread.
+		let (to_media, events) = unbounded();
+
+		// The media controls are not essential, if
+		// they fail, the `Engine` continues without them.
+		let controls = match Self::controls(&config, to_media) {
+			Ok(controls) => {
+				debug2!("{ACTOR} - media controls registered as: {}", config.dbus_name);
+				atomic_state.media_controls.store(true, Ordering::Release);
+				Some(controls)
+			},
+			Err(error) => {
+				warn2!("{ACTOR} - couldn't create media controls: {error}");
+				None
+			},
+		};
+
+		let channels = Channels {
+			shutdown,
+			events,
+			to_kernel,
+			from_kernel,
+			from_kernel_seek,
+		};
+
+		let this = Self {
+			published: Published {
+				source:  None,
+				playing: false,
+				elapsed: 0.0,
+				at:      Instant::now(),
+				volume:  reader.volume(),
+			},
+			barrier,
+			atomic_state,
+			reader,
+			controls,
+			seek_step: config.seek_step.as_secs_f32(),
+			refresh_rate: config.refresh_rate,
+		};
+
+		(this, channels)
+	}
+
+	#[cold] #[inline(never)]
+	fn main(mut self, c: Self::MainArgs) -> Arc<Barrier> {
+		// Nothing to do without media controls, wait until shutdown.
+		if self.controls.is_none() {
+			recv!(c.shutdown);
+			return self.barrier;
+		}
+
+		let mut select = Select::new();
+
+		assert_eq!(0, select.recv(&c.events));
+		assert_eq!(1, select.recv(&c.shutdown));
+
+		self.publish(true);
+
+		loop {
+			match select.ready_timeout(self.refresh_rate) {
+				// A request from the OS media controls.
+				Ok(0) => {
+					let event = select_recv!(c.events);
+					if self.event(event, &c).is_break() {
+						return self.barrier;
+					}
+				},
+
+				Ok(1) => {
+					select_recv!(c.shutdown);
+					return self.barrier;
+				},
+
+				// Timeout, check if anything changed.
+				Err(_) => (),
+
+				Ok(_) => unreachable!(),
+			}
+
+			self.publish(false);
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Media Impl
+impl<Extra: ExtraData> Media<Extra> {
+	#[cold]
+	#[inline(never)]
+	/// Create and attach to the OS media controls,
+	/// forwarding all their requests to `to_media`.
+	fn controls(
+		config: &MediaControls,
+		to_media: Sender<MediaControlEvent>,
+	) -> Result<Controls, String> {
+		let platform = PlatformConfig {
+			dbus_name: &config.dbus_name,
+			display_name: &config.display_name,
+			hwnd: config.hwnd.map(|hwnd| hwnd as *mut std::ffi::c_void),
+		};
+
+		let mut controls = Controls::new(platform).map_err(|e| format!("{e:?}"))?;
+
+		controls
+			.attach(move |event| {
+				// We've shutdown, the OS will stop
+				// calling us once `Controls` is dropped.
+				drop(to_media.send(event));
+			})
+			.map_err(|e| format!("{e:?}"))?;
+
+		Ok(controls)
+	}
+
+	/// Handle a request from the OS media controls.
+	///
+	/// This forwards the request to `Kernel`, waits for the
+	/// response, then immediately publishes the new state.
+	///
+	/// Returns `ControlFlow::Break` if we were told to shutdown while waiting.
+	fn event(&mut self, event: MediaControlEvent, c: &Channels<Extra>) -> ControlFlow<()> {
+		let Some(signal) = signal(event, self.seek_step) else {
+			return ControlFlow::Continue(());
+		};
+
+		trace2!("{ACTOR} - {signal:?}");
+		try_send!(c.to_kernel, signal);
+
+		// `Kernel` may have been told to shutdown before
+		// handling our request, so listen for that as well.
+		let mut select = Select::new();
+		assert_eq!(0, select.recv(&c.from_kernel));
+		assert_eq!(1, select.recv(&c.from_kernel_seek));
+		assert_eq!(2, select.recv(&c.shutdown));
+
+		loop {
+			match select.ready() {
+				// The response itself is not needed, the
+				// `AudioStateReader` already has the new state.
+				0 => { select_recv!(c.from_kernel); break; },
+				1 => { select_recv!(c.from_kernel_seek); break; },
+				2 => {
+					select_recv!(c.shutdown);
+					return ControlFlow::Break(());
+				},
+				_ => unreachable!(),
+			}
+		}
+
+		self.publish(false);
+		ControlFlow::Continue(())
+	}
+
+	/// Publish the current `AudioState` to the OS media controls.
+	///
+	/// Only what has changed since the last publish is
+	/// published again, unless `force` is `true`.
+	fn publish(&mut self, force: bool) {
+		let Some(controls) = self.controls.as_mut() else {
+			return;
+		};
+
+		let state   = self.reader.get();
+		let source  = state.current.as_ref().map(|current| &current.source);
+		let elapsed = state.current.as_ref().map_or(0.0, |current| current.elapsed);
+		let p       = &mut self.published;
+
+		// Track.
+		let new_track = force || source != p.source.as_ref();
+		if new_track {
+			let track = source.map(Track::probe).unwrap_or_default();
+			debug2!("{ACTOR} - new track: {track:?}");
+			if let Err(e) = controls.set_metadata(track.as_metadata()) {
+				warn2!("{ACTOR} - couldn't publish metadata: {e:?}");
+			}
+			p.source = source.cloned();
+		}
+
+		// Playback status and position.
+		let expected = if p.playing {
+			p.elapsed + p.at.elapsed().as_secs_f32()
+		} else {
+			p.elapsed
+		};
+		let tolerance = POSITION_TOLERANCE + self.atomic_state.elapsed_refresh_rate.load();
+		if new_track || state.playing != p.playing || (elapsed - expected).abs() > tolerance {
+			let progress = Duration::try_from_secs_f32(elapsed).ok().map(MediaPosition);
+			let playback = match (source, state.playing) {
+				(None, _)        => MediaPlayback::Stopped,
+				(Some(_), true)  => MediaPlayback::Playing { progress },
+				(Some(_), false) => MediaPlayback::Paused { progress },
+			};
+			if let Err(e) = controls.set_playback(playback) {
+				warn2!("{ACTOR} - couldn't publish playback: {e:?}");
+			}
+			p.playing = state.playing;
+			p.elapsed = elapsed;
+			p.at      = Instant::now();
+		}
+
+		// Volume, only MPRIS has a notion of the player's volume.
+		if force || state.volume != p.volume {
+			#[cfg(target_os = "linux")]
+			if let Err(e) = controls.set_volume(f64::from(state.volume.inner())) {
+				warn2!("{ACTOR} - couldn't publish volume: {e:?}");
+			}
+			p.volume = state.volume;
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Free functions
+#[allow(clippy::cast_possible_truncation)]
+/// Map a request from the OS media controls to a `Kernel` signal.
+///
+/// Returns `None` for requests that have no `Engine` equivalent
+/// (opening URIs, raising/quitting the application, etc),
+/// those are up to the application itself.
+fn signal(event: MediaControlEvent, seek_step: f32) -> Option<MediaToKernel> {
+	use MediaControlEvent as E;
+	use SeekDirection as D;
+
+	Some(match event {
+		E::Play     => MediaToKernel::Play,
+		E::Pause    => MediaToKernel::Pause,
+		E::Toggle   => MediaToKernel::Toggle,
+		E::Stop     => MediaToKernel::Stop,
+		E::Next     => MediaToKernel::Next,
+		E::Previous => MediaToKernel::Previous,
+
+		E::Seek(D::Forward)          => MediaToKernel::Seek(Seek::Forward(seek_step)),
+		E::Seek(D::Backward)         => MediaToKernel::Seek(Seek::Backward(seek_step)),
+		E::SeekBy(D::Forward, time)  => MediaToKernel::Seek(Seek::Forward(time.as_secs_f32())),
+		E::SeekBy(D::Backward, time) => MediaToKernel::Seek(Seek::Backward(time.as_secs_f32())),
+		E::SetPosition(position)     => MediaToKernel::Seek(Seek::Absolute(position.0.as_secs_f32())),
+
+		E::SetVolume(volume) => MediaToKernel::Volume(Volume::new(volume as f32)),
+
+		_ => return None,
+	})
+}
+
+//---------------------------------------------------------------------------------------------------- Track
+/// The tags of a `Source` that are published to the OS media controls.
+#[derive(Clone,Debug,Default,PartialEq)]
+#[allow(clippy::missing_docs_in_private_items)]
+struct Track {
+	title:    Option<String>,
+	artist:   Option<String>,
+	album:    Option<String>,
+	duration: Option<Duration>,
+}
+
+impl Track {
+	/// Read the tags of a `Source`.
+	///
+	/// This never fails, missing tags are left as `None`,
+	/// and the title of `Source::Path`'s fall back to the file name.
+	fn probe<Extra: ExtraData>(source: &Source<Extra>) -> Self {
+		let mut this = Self::default();
+
+		let mss = match source {
+			Source::Path { source, .. } => {
+				this.title = source.file_stem().map(|stem| stem.to_string_lossy().into_owned());
+				let Ok(file) = File::open(source) else {
+					return this;
+				};
+				MediaSourceStream::new(Box::new(file), MEDIA_SOURCE_STREAM_OPTIONS)
+			},
+			Source::Byte { source, .. } => {
+				let cursor = Cursor::new(Arc::clone(source));
+				MediaSourceStream::new(Box::new(cursor), MEDIA_SOURCE_STREAM_OPTIONS)
+			},
+		};
+
+		let Ok(mut probed) = get_probe().format(&Hint::new(), mss, &FORMAT_OPTIONS, &METADATA_OPTIONS) else {
+			return this;
+		};
+
+		// Tags may be before the audio container (e.g. ID3v2 in MP3's),
+		// or within it, the tags read first take precedence.
+		let mut tags = Self::default();
+		if let Some(metadata) = probed.metadata.get() {
+			if let Some(revision) = metadata.current() {
+				tags.tags(revision.tags());
+			}
+		}
+		if let Some(revision) = probed.format.metadata().current() {
+			tags.tags(revision.tags());
+		}
+
+		this.title  = tags.title.or(this.title);
+		this.artist = tags.artist;
+		this.album  = tags.album;
+
+		this.duration = probed.format.default_track().and_then(|track| {
+			let time_base = track.codec_params.time_base?;
+			let n_frames  = track.codec_params.n_frames?;
+			let time      = time_base.calc_time(n_frames);
+			Some(Duration::from_secs(time.seconds) + Duration::from_secs_f64(time.frac))
+		});
+
+		this
+	}
+
+	/// Fill in any missing fields from these `tags`.
+	fn tags(&mut self, tags: &[Tag]) {
+		for tag in tags {
+			let field = match tag.std_key {
+				Some(StandardTagKey::TrackTitle) => &mut self.title,
+				Some(StandardTagKey::Artist)     => &mut self.artist,
+				Some(StandardTagKey::Album)      => &mut self.album,
+				_ => continue,
+			};
+
+			if field.is_none() {
+				*field = Some(tag.value.to_string());
+			}
+		}
+	}
+
+	/// Borrow as `souvlaki`'s metadata.
+	fn as_metadata(&self) -> MediaMetadata<'_> {
+		MediaMetadata {
+			title:     self.title.as_deref(),
+			album:     self.album.as_deref(),
+			artist:    self.artist.as_deref(),
+			cover_url: None,
+			duration:  self.duration,
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn signals() {
+		const STEP: f32 = 5.0;
+		let secs = Duration::from_secs(10);
+
+		let tests = [
+			(MediaControlEvent::Play,                                 Some(MediaToKernel::Play)),
+			(MediaControlEvent::Pause,                                Some(MediaToKernel::Pause)),
+			(MediaControlEvent::Toggle,                               Some(MediaToKernel::Toggle)),
+			(MediaControlEvent::Stop,                                 Some(MediaToKernel::Stop)),
+			(MediaControlEvent::Next,                                 Some(MediaToKernel::Next)),
+			(MediaControlEvent::Previous,                             Some(MediaToKernel::Previous)),
+			(MediaControlEvent::Seek(SeekDirection::Forward),         Some(MediaToKernel::Seek(Seek::Forward(STEP)))),
+			(MediaControlEvent::Seek(SeekDirection::Backward),        Some(MediaToKernel::Seek(Seek::Backward(STEP)))),
+			(MediaControlEvent::SeekBy(SeekDirection::Forward, secs), Some(MediaToKernel::Seek(Seek::Forward(10.0)))),
+			(MediaControlEvent::SeekBy(SeekDirection::Backward, secs),Some(MediaToKernel::Seek(Seek::Backward(10.0)))),
+			(MediaControlEvent::SetPosition(MediaPosition(secs)),     Some(MediaToKernel::Seek(Seek::Absolute(10.0)))),
+			(MediaControlEvent::SetVolume(0.5),                       Some(MediaToKernel::Volume(Volume::new(0.5)))),
+			(MediaControlEvent::Raise,                                None),
+			(MediaControlEvent::Quit,                                 None),
+		];
+
+		for (event, expected) in tests {
+			assert_eq!(signal(event, STEP), expected);
+		}
+	}
+
+	#[test]
+	/// OS requests reach `Kernel` without a D-Bus session, and a
+	/// shutdown while waiting for `Kernel`'s response is honoured.
+	fn event() {
+		let engine = crate::tests::init();
+		let (to_kernel, from_media)           = unbounded();
+		let (to_media, from_kernel)           = unbounded();
+		let (to_media_seek, from_kernel_seek) = unbounded();
+		let (to_media_shutdown, shutdown)     = unbounded();
+		let c = Channels {
+			shutdown,
+			events: unbounded().1,
+			to_kernel,
+			from_kernel,
+			from_kernel_seek,
+		};
+
+		let mut media = Media {
+			barrier:      Arc::new(Barrier::new(1)),
+			atomic_state: Arc::new(AtomicState::DEFAULT),
+			reader:       engine.reader().clone(),
+			controls:     None,
+			seek_step:    5.0,
+			refresh_rate: MediaControls::DEFAULT.refresh_rate,
+			published: Published {
+				source:  None,
+				playing: false,
+				elapsed: 0.0,
+				at:      Instant::now(),
+				volume:  Volume::DEFAULT,
+			},
+		};
+
+		to_media.send(engine.reader().get()).unwrap();
+		assert!(media.event(MediaControlEvent::Next, &c).is_continue());
+		assert_eq!(from_media.try_recv().unwrap(), MediaToKernel::Next);
+
+		// Seeks are responded to on their own channel.
+		to_media_seek.send(Ok(engine.reader().get())).unwrap();
+		let position = MediaPosition(Duration::from_secs(1));
+		assert!(media.event(MediaControlEvent::SetPosition(position), &c).is_continue());
+		assert_eq!(from_media.try_recv().unwrap(), MediaToKernel::Seek(Seek::Absolute(1.0)));
+
+		// Requests without an `Engine` equivalent never reach `Kernel`.
+		assert!(media.event(MediaControlEvent::Raise, &c).is_continue());
+		assert!(from_media.try_recv().is_err());
+
+		// Shutdown before `Kernel` responded.
+		to_media_shutdown.send(()).unwrap();
+		assert!(media.event(MediaControlEvent::Play, &c).is_break());
+		assert_eq!(from_media.try_recv().unwrap(), MediaToKernel::Play);
+	}
+
+	#[test]
+	fn track() {
+		let track = Track::probe(&crate::tests::source(0));
+		assert!(track.title.is_some());
+		assert!(track.duration.is_some());
+
+		// Broken audio still has (empty) tags.
+		assert_eq!(Track::probe(&crate::tests::source_broken(0)), Track::default());
+	}
+
+	#[test]
+	#[cfg(target_os = "linux")]
+	#[ignore = "requires `dbus-daemon` and `dbus-send`"]
+	/// Control the `Engine` through MPRIS on a private D-Bus session bus.
+	fn mpris() {
+		use std::process::{Command,Stdio};
+		use std::io::{BufRead,BufReader};
+
+		const DEST: &str = "--dest=org.mpris.MediaPlayer2.sansan_test";
+		/// Only set in the child process the test actually runs in.
+		const CHILD: &str = "SANSAN_TEST_MPRIS_CHILD";
+
+		// Setting `DBUS_SESSION_BUS_ADDRESS` in this process races
+		// with other tests reading the environment, so this test
+		// re-runs itself in a child process that has it set.
+		if std::env::var_os(CHILD).is_none() {
+			// Start a private session bus.
+			let mut daemon = Command::new("dbus-daemon")
+				.args(["--session", "--nofork", "--print-address"])
+				.stdout(Stdio::piped())
+				.spawn()
+				.unwrap();
+			let mut address = String::new();
+			BufReader::new(daemon.stdout.take().unwrap()).read_line(&mut address).unwrap();
+
+			let status = Command::new(std::env::current_exe().unwrap())
+				.args(["actor::media::tests::mpris", "--exact", "--ignored", "--nocapture"])
+				.env("DBUS_SESSION_BUS_ADDRESS", address.trim())
+				.env(CHILD, "1")
+				.status()
+				.unwrap();
+
+			daemon.kill().unwrap();
+			assert!(status.success());
+			return;
+		}
+
+		let mut engine = crate::tests::init_with_config(crate::config::InitConfig {
+			media_controls: Some(MediaControls {
+				dbus_name: "sansan_test".into(),
+				..MediaControls::DEFAULT
+			}),
+			init_blocking: true,
+			..crate::config::InitConfig::DEFAULT
+		});
+		assert!(engine.media_controls());
+
+		let dbus_send = |args: &[&str]| -> String {
+			let output = Command::new("dbus-send")
+				.args(["--session", "--print-reply", DEST, "/org/mpris/MediaPlayer2"])
+				.args(args)
+				.output()
+				.unwrap();
+			assert!(output.status.success(), "{output:?}");
+			String::from_utf8(output.stdout).unwrap()
+		};
+		let playback_status = || dbus_send(&[
+			"org.freedesktop.DBus.Properties.Get",
+			"string:org.mpris.MediaPlayer2.Player",
+			"string:PlaybackStatus",
+		]);
+		let wait = || std::thread::sleep(MediaControls::DEFAULT.refresh_rate * 2);
+
+		engine.add(crate::signal::Add {
+			source: crate::tests::source(0),
+			method: crate::signal::AddMethod::Back,
+			clear: false,
+			play: false,
+		});
+		wait();
+		assert!(playback_status().contains("Stopped"));
+
+		// OS -> `Engine`.
+		dbus_send(&["org.mpris.MediaPlayer2.Player.Play"]);
+		wait();
+		assert!(engine.reader().playing());
+		assert!(playback_status().contains("Playing"));
+
+		dbus_send(&["org.mpris.MediaPlayer2.Player.Pause"]);
+		wait();
+		assert!(!engine.reader().playing());
+
+		// `Engine` -> OS.
+		engine.play();
+		wait();
+		assert!(playback_status().contains("Playing"));
+
+		drop(engine);
+	}
+}
@@ -6,4 +6,6 @@ pub(crate) mod decode;
 pub(crate) mod kernel;
 pub(crate) use kernel::Kernel;
 pub(crate) mod gc;
-pub(crate) mod caller;
\ No newline at end of file
+pub(crate) mod caller;
+#[cfg(feature = "media_controls")]
+pub(crate) mod media;
@@ -5,6 +5,10 @@ use crate::{
 	extra_data::ExtraData,
 	config::error_callback::ErrorCallback,
 	error::{DecodeError, SourceError, OutputError},
+	event::SourceEnd,
+	signal::{Repeat,Shuffle,SignalError,Volume},
+	source::Source,
+	state::AudioStateSnapshot,
 };
 use std::{
 	fmt,
@@ -14,10 +18,8 @@ use std::{
 #[allow(unused_imports)] // docs
 use crate::{
 	Engine,
-	source::Source,
 	state::{AudioState,Current},
 	config::{RuntimeConfig,InitConfig},
-	signal::Repeat,
 };
 
 //---------------------------------------------------------------------------------------------------- Callbacks
@@ -57,6 +59,16 @@ use crate::{
 /// }
 /// ```
 ///
+/// ## `AudioStateSnapshot`
+/// The lifecycle callbacks ([`Callbacks::source_end`], [`Callbacks::playing`], etc)
+/// also receive the [`AudioStateSnapshot`] right after the change, so the handler
+/// sees consistent state without another [`Engine::reader`] call.
+///
+/// These describe _why_ the state changed, for example, [`Callbacks::source_end`]
+/// tells apart a track that finished from one that was skipped.
+///
+/// They are also available as [`Event`](crate::event::Event)'s, see [`Engine::subscribe`].
+///
 /// ## `ErrorCallback`
 /// These are "special" callbacks that can do other things on-top
 /// of user-passed closures, namely, pause the audio playback.
@@ -106,10 +118,51 @@ pub struct Callbacks<Extra: ExtraData> {
 	/// The [`f32`] passed in the function is the value of [`Current::elapsed`].
 	pub elapsed: Option<(Box<dyn FnMut(f32) + Send + 'static>, Duration)>,
 
+	/// Called when a [`Source`] stops being the [`Current`] track.
+	///
+	/// The [`SourceEnd`] is whether it played until the end or was skipped.
+	pub source_end: Option<Box<dyn FnMut(Source<Extra>, SourceEnd, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when playback starts (`true`) or pauses/stops (`false`).
+	pub playing: Option<Box<dyn FnMut(bool, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when the [`Current`] track is seeked.
+	///
+	/// The [`f32`] passed in the function is the new value of [`Current::elapsed`].
+	pub seek: Option<Box<dyn FnMut(f32, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when the [`Volume`] changes.
+	pub volume: Option<Box<dyn FnMut(Volume, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when the [`Repeat`] mode changes.
+	pub repeat: Option<Box<dyn FnMut(Repeat, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when the [`AudioState::queue`] is shuffled.
+	///
+	/// This is called after [`Callbacks::queue`].
+	pub shuffle: Option<Box<dyn FnMut(Shuffle, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when the [`AudioState::queue`] is mutated, e.g. tracks
+	/// were added, removed, shuffled, or the queue was cleared.
+	pub queue: Option<Box<dyn FnMut(AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when the [`Engine`] is shutting down, with the final [`AudioStateSnapshot`].
+	///
+	/// No other callbacks are called after this one.
+	pub shutdown: Option<Box<dyn FnMut(AudioStateSnapshot<Extra>) + Send + 'static>>,
+
+	/// Called when a signal sent with [`Engine::send`] fails.
+	///
+	/// The [`AudioStateSnapshot`] is the state the signal failed against.
+	pub error_signal: Option<Box<dyn FnMut(SignalError, AudioStateSnapshot<Extra>) + Send + 'static>>,
+
 	/// The action the `Engine` will take on various [`DecodeError`]'s.
 	pub error_decode: Option<ErrorCallback<DecodeError>>,
 
 	/// The action the `Engine` will take on various [`OutputError`]'s.
+	///
+	/// [`OutputError::Glitch`]'s are passed to the function (if any),
+	/// but the action is not taken, as they are not fatal.
 	pub error_output: Option<ErrorCallback<OutputError>>,
 
 	/// The action the `Engine` will take on various [`SourceError`]'s.
@@ -130,6 +183,15 @@ impl<Extra: ExtraData> Callbacks<Extra> {
 		source_new:    None,
 		queue_end:      None,
 		elapsed:        None,
+		source_end:     None,
+		playing:        None,
+		seek:           None,
+		volume:         None,
+		repeat:         None,
+		shuffle:        None,
+		queue:          None,
+		shutdown:       None,
+		error_signal:   None,
 		error_decode:   None,
 		error_output:   None,
 		error_source:   None,
@@ -152,6 +214,15 @@ impl<Extra: ExtraData> Callbacks<Extra> {
 	///     source_new:  None,
 	///     queue_end:    None,
 	///     elapsed:      None,
+	///     source_end:   None,
+	///     playing:      None,
+	///     seek:         None,
+	///     volume:       None,
+	///     repeat:       None,
+	///     shuffle:      None,
+	///     queue:        None,
+	///     shutdown:     None,
+	///     error_signal: None,
 	///     error_decode: None,
 	///     error_output: None,
 	///     error_source: None,
@@ -162,6 +233,7 @@ impl<Extra: ExtraData> Callbacks<Extra> {
 		self.source_new.is_none()  &&
 		self.queue_end.is_none()    &&
 		self.elapsed.is_none()      &&
+		!self.events()              &&
 		self.error_decode.is_none() &&
 		self.error_output.is_none() &&
 		self.error_source.is_none()
@@ -177,6 +249,15 @@ impl<Extra: ExtraData> Callbacks<Extra> {
 	///     source_new:  Some(Box::new(|_| {})),
 	///     queue_end:    Some(Box::new(||  {})),
 	///     elapsed:      Some((Box::new(|_| {}), Duration::ZERO)),
+	///     source_end:   Some(Box::new(|_, _, _| {})),
+	///     playing:      Some(Box::new(|_, _| {})),
+	///     seek:         Some(Box::new(|_, _| {})),
+	///     volume:       Some(Box::new(|_, _| {})),
+	///     repeat:       Some(Box::new(|_, _| {})),
+	///     shuffle:      Some(Box::new(|_, _| {})),
+	///     queue:        Some(Box::new(|_| {})),
+	///     shutdown:     Some(Box::new(|_| {})),
+	///     error_signal: Some(Box::new(|_, _| {})),
 	///     error_decode: Some(ErrorCallback::Pause),
 	///     error_output: Some(ErrorCallback::Pause),
 	///     error_source: Some(ErrorCallback::Pause),
@@ -187,11 +268,37 @@ impl<Extra: ExtraData> Callbacks<Extra> {
 		self.source_new.is_some()  &&
 		self.queue_end.is_some()    &&
 		self.elapsed.is_some()      &&
+		self.source_end.is_some()   &&
+		self.playing.is_some()      &&
+		self.seek.is_some()         &&
+		self.volume.is_some()       &&
+		self.repeat.is_some()       &&
+		self.shuffle.is_some()      &&
+		self.queue.is_some()        &&
+		self.shutdown.is_some()     &&
+		self.error_signal.is_some() &&
 		self.error_decode.is_some() &&
 		self.error_output.is_some() &&
 		self.error_source.is_some()
 	}
 
+	#[must_use]
+	/// Are any of the lifecycle callbacks set?
+	///
+	/// These are driven by `Kernel`'s `Event`'s,
+	/// so `Kernel` must generate them if so.
+	pub(crate) const fn events(&self) -> bool {
+		self.source_end.is_some() ||
+		self.playing.is_some()    ||
+		self.seek.is_some()       ||
+		self.volume.is_some()     ||
+		self.repeat.is_some()     ||
+		self.shuffle.is_some()    ||
+		self.queue.is_some()      ||
+		self.shutdown.is_some()   ||
+		self.error_signal.is_some()
+	}
+
 	/// Set the behavior for when the a new [`AudioState::current`] is set.
 	///
 	/// The function has access to the new [`Source`].
@@ -262,6 +369,156 @@ impl<Extra: ExtraData> Callbacks<Extra> {
 		self
 	}
 
+	/// Set the behavior for when a [`Source`] stops being the [`Current`] track.
+	///
+	/// ```rust
+	/// # use sansan::{config::*,event::*};
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.source_end(|source, end, audio_state| {
+	///     if end == SourceEnd::Completed {
+	///         println!("finished: {source:?}, next: {:?}", audio_state.current);
+	///     }
+	/// });
+	/// ```
+	pub fn source_end<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(Source<Extra>, SourceEnd, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.source_end = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when playback starts or pauses.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.playing(|playing, _| println!("playing: {playing}"));
+	/// ```
+	pub fn playing<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(bool, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.playing = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when the [`Current`] track is seeked.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.seek(|elapsed, _| println!("seeked to: {elapsed}"));
+	/// ```
+	pub fn seek<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(f32, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.seek = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when the [`Volume`] changes.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.volume(|volume, _| println!("volume: {volume:?}"));
+	/// ```
+	pub fn volume<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(Volume, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.volume = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when the [`Repeat`] mode changes.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.repeat(|repeat, _| println!("repeat: {repeat:?}"));
+	/// ```
+	pub fn repeat<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(Repeat, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.repeat = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when the [`AudioState::queue`] is shuffled.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.shuffle(|shuffle, audio_state| {
+	///     println!("shuffle: {shuffle:?}, new queue: {:?}", audio_state.queue);
+	/// });
+	/// ```
+	pub fn shuffle<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(Shuffle, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.shuffle = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when the [`AudioState::queue`] is mutated.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.queue(|audio_state| println!("queue length: {}", audio_state.queue.len()));
+	/// ```
+	pub fn queue<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.queue = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when the [`Engine`] shuts down.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.shutdown(|audio_state| println!("final state: {audio_state:?}"));
+	/// ```
+	pub fn shutdown<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.shutdown = Some(Box::new(callback));
+		self
+	}
+
+	/// Set the behavior for when a signal sent with [`Engine::send`] fails.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// let mut callbacks = Callbacks::<()>::new();
+	///
+	/// callbacks.error_signal(|error, _| eprintln!("signal failed: {error}"));
+	/// ```
+	pub fn error_signal<F>(&mut self, callback: F) -> &mut Self
+	where
+		F: FnMut(SignalError, AudioStateSnapshot<Extra>) + Send + Sync + 'static
+	{
+		self.error_signal = Some(Box::new(callback));
+		self
+	}
+
 	/// Set the behavior for when [`DecodeError`]'s occur.
 	///
 	/// The provided [`ErrorCallback`] has access to the specific [`DecodeError`] that occurred.
@@ -360,6 +617,15 @@ impl<Extra: ExtraData> fmt::Debug for Callbacks<Extra> {
 			.field("source_new",  &self.source_new.as_ref().map(|_|      "Some(_)"))
 			.field("queue_end",    &self.queue_end.as_ref().map(|_| "Some(_)"))
 			.field("elapsed",      &self.elapsed.as_ref().map(|o|   format!("Some(_, {:?})", o.1)))
+			.field("source_end",   &self.source_end.as_ref().map(|_| "Some(_)"))
+			.field("playing",      &self.playing.as_ref().map(|_| "Some(_)"))
+			.field("seek",         &self.seek.as_ref().map(|_| "Some(_)"))
+			.field("volume",       &self.volume.as_ref().map(|_| "Some(_)"))
+			.field("repeat",       &self.repeat.as_ref().map(|_| "Some(_)"))
+			.field("shuffle",      &self.shuffle.as_ref().map(|_| "Some(_)"))
+			.field("queue",        &self.queue.as_ref().map(|_| "Some(_)"))
+			.field("shutdown",     &self.shutdown.as_ref().map(|_| "Some(_)"))
+			.field("error_signal", &self.error_signal.as_ref().map(|_| "Some(_)"))
 			.field("error_decode", &self.error_decode)
 			.field("error_source", &self.error_source)
 			.field("error_output", &self.error_output)
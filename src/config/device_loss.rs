@@ -0,0 +1,59 @@
+//! Audio output device loss/change policy.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::{InitConfig,Callbacks},
+	error::OutputError,
+};
+
+//---------------------------------------------------------------------------------------------------- DeviceLoss
+/// What to do when the audio output device disappears or changes.
+///
+/// This is the policy used when:
+/// - The device currently being used is lost, e.g. headphones are unplugged
+/// - The system's default device changes (when using the default device)
+///
+/// Set with [`InitConfig::device_loss`].
+///
+/// In all cases, a different device can
+/// manually be switched to with [`Engine::output_device`].
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DeviceLoss {
+	#[default]
+	/// Migrate the audio stream to the system's (new) default device.
+	///
+	/// If using the default device and the default changes,
+	/// playback will follow the new default device.
+	///
+	/// If migrating fails, this falls back to [`DeviceLoss::Retry`].
+	Migrate,
+
+	/// Pause playback and emit an [`OutputError`] to [`Callbacks::error_output`].
+	///
+	/// Changes in the system's default device are ignored.
+	Pause,
+
+	/// Emit an [`OutputError`] to [`Callbacks::error_output`],
+	/// then keep trying to re-open the same device
+	/// every [`InitConfig::audio_retry`].
+	///
+	/// Changes in the system's default device are ignored.
+	Retry,
+}
+
+impl DeviceLoss {
+	/// Returns [`Self::Migrate`].
+	pub const DEFAULT: Self = Self::Migrate;
+}
@@ -0,0 +1,54 @@
+//! Dither used when reducing bit depth.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	config::InitConfig,
+	output::SampleFormat,
+};
+
+//---------------------------------------------------------------------------------------------------- Dither
+/// Dither applied when converting to an integer [`SampleFormat`].
+///
+/// `sansan` processes audio as 32-bit floats, so when the output is an
+/// integer format (e.g. a DAC that only accepts 16-bit samples), the bit
+/// depth must be reduced after all processing (volume, resampling, etc).
+///
+/// Dither adds a tiny amount of noise before rounding, which turns
+/// the rounding error (audible as distortion on quiet signals)
+/// into a constant, much less noticeable noise floor.
+///
+/// Set with [`InitConfig::dither`].
+///
+/// This has no effect when the output is [`SampleFormat::F32`].
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Dither {
+	/// No dither, samples are simply rounded.
+	None,
+
+	#[default]
+	/// Triangular probability density function dither, ±1 LSB.
+	///
+	/// This completely removes the correlation between
+	/// the signal and the rounding error.
+	Tpdf,
+
+	/// [`Dither::Tpdf`], with the rounding error fed back (1st-order)
+	/// such that the noise is pushed to higher, less audible frequencies.
+	NoiseShaped,
+}
+
+impl Dither {
+	/// Returns [`Self::Tpdf`].
+	pub const DEFAULT: Self = Self::Tpdf;
+}
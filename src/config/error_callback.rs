@@ -9,6 +9,7 @@ use crate::{
 	config::{InitConfig,Callbacks},
 	source::Source,
 	state::AudioState,
+	signal::Repeat,
 	error::{DecodeError,OutputError,SourceError},
 };
 
@@ -22,6 +23,10 @@ use crate::{
 ///
 /// When these errors occur, what should the `Engine` do?
 ///
+/// For unattended playback, [`Self::Skip`] and [`Self::Remove`]
+/// allow the `Engine` to continue on with the rest of the queue
+/// instead of stopping at the first broken [`Source`].
+///
 /// These are solely used in [`Callbacks`] which solely exist in [`InitConfig`],
 /// where each particular type of error can be given a variant of [`ErrorCallback`]
 /// that determines what action the `Engine` will take in the case.
@@ -59,6 +64,68 @@ pub enum ErrorCallback<Error> {
 	/// This will solely execute the provided
 	/// function and will not pause any playback.
 	Fn(Box<dyn FnMut(Error) + Send + Sync + 'static>),
+
+	/// Skip to the next track in the queue.
+	///
+	/// This acts like [`Engine::next`] with the exception that
+	/// [`Repeat::Current`] is ignored, i.e, the erroring [`Source`]
+	/// will not be restarted, the next entry in the [`AudioState::queue`] will be.
+	///
+	/// If the queue has no more tracks (and [`Repeat::Queue`] is not set),
+	/// this will end the queue like [`Engine::next`] would.
+	///
+	/// Once every entry in the queue has been tried and errored in a row,
+	/// the queue is ended (even with [`Repeat::Queue`]) instead of
+	/// endlessly cycling through it.
+	Skip,
+
+	/// Skip to the next track in the queue and execute a function.
+	///
+	/// This is [`Self::Skip`] then running the provided function.
+	SkipAndFn(Box<dyn FnMut(Error) + Send + Sync + 'static>),
+
+	/// Remove the [`AudioState::current`] track from the queue.
+	///
+	/// This acts like [`Engine::remove`] on the current index, i.e,
+	/// the erroring [`Source`] is dropped and the track that takes its
+	/// index in the [`AudioState::queue`] (if any) will start playing.
+	Remove,
+
+	/// Remove the current track from the queue and execute a function.
+	///
+	/// This is [`Self::Remove`] then running the provided function.
+	RemoveAndFn(Box<dyn FnMut(Error) + Send + Sync + 'static>),
+}
+
+//---------------------------------------------------------------------------------------------------- ErrorAction
+/// The non-function part of an [`ErrorCallback`].
+///
+/// This is what [Kernel] actually acts upon
+/// when receiving an error, the function part
+/// (if any) is executed by [Caller].
+#[derive(Copy,Clone,Debug,PartialEq,Eq)]
+pub(crate) enum ErrorAction {
+	/// Do nothing (only a function, or no `ErrorCallback` at all).
+	Nothing,
+	/// [`ErrorCallback::Pause`] & [`ErrorCallback::PauseAndFn`].
+	Pause,
+	/// [`ErrorCallback::Skip`] & [`ErrorCallback::SkipAndFn`].
+	Skip,
+	/// [`ErrorCallback::Remove`] & [`ErrorCallback::RemoveAndFn`].
+	Remove,
+}
+
+impl ErrorAction {
+	#[must_use]
+	/// Map an optional [`ErrorCallback`] to the action [Kernel] will take.
+	pub(crate) const fn from_callback<Error>(error_callback: Option<&ErrorCallback<Error>>) -> Self {
+		match error_callback {
+			Some(ErrorCallback::Pause  | ErrorCallback::PauseAndFn(_))  => Self::Pause,
+			Some(ErrorCallback::Skip   | ErrorCallback::SkipAndFn(_))   => Self::Skip,
+			Some(ErrorCallback::Remove | ErrorCallback::RemoveAndFn(_)) => Self::Remove,
+			Some(ErrorCallback::Fn(_)) | None => Self::Nothing,
+		}
+	}
 }
 
 impl<Error> ErrorCallback<Error> {
@@ -88,6 +155,26 @@ impl<Error> ErrorCallback<Error> {
 		Self::Fn(Box::new(callback))
 	}
 
+	#[cold]
+	#[must_use]
+	/// Create a [`Self::SkipAndFn`] with the function `F`.
+	pub fn new_skip_and_fn<F>(callback: F) -> Self
+	where
+		F: FnMut(Error) + Send + Sync + 'static
+	{
+		Self::SkipAndFn(Box::new(callback))
+	}
+
+	#[cold]
+	#[must_use]
+	/// Create a [`Self::RemoveAndFn`] with the function `F`.
+	pub fn new_remove_and_fn<F>(callback: F) -> Self
+	where
+		F: FnMut(Error) + Send + Sync + 'static
+	{
+		Self::RemoveAndFn(Box::new(callback))
+	}
+
 	#[must_use]
 	/// Returns `true` if `self == ErrorCallback::Pause`
 	pub const fn is_pause(&self) -> bool {
@@ -106,11 +193,53 @@ impl<Error> ErrorCallback<Error> {
 		matches!(self, Self::Fn(_))
 	}
 
+	#[must_use]
+	/// Returns `true` if `self == ErrorCallback::Skip`
+	pub const fn is_skip(&self) -> bool {
+		matches!(self, Self::Skip)
+	}
+
+	#[must_use]
+	/// Returns `true` if `self == ErrorCallback::SkipAndFn(_)`
+	pub const fn is_skip_and_fn(&self) -> bool {
+		matches!(self, Self::SkipAndFn(_))
+	}
+
+	#[must_use]
+	/// Returns `true` if `self == ErrorCallback::Remove`
+	pub const fn is_remove(&self) -> bool {
+		matches!(self, Self::Remove)
+	}
+
+	#[must_use]
+	/// Returns `true` if `self == ErrorCallback::RemoveAndFn(_)`
+	pub const fn is_remove_and_fn(&self) -> bool {
+		matches!(self, Self::RemoveAndFn(_))
+	}
+
 	#[must_use]
 	/// Returns `true` if this [`ErrorCallback`] will involve pausing.
 	pub const fn will_pause(&self) -> bool {
 		self.is_pause() || self.is_pause_and_fn()
 	}
+
+	#[must_use]
+	/// Returns `true` if this [`ErrorCallback`] will involve skipping.
+	pub const fn will_skip(&self) -> bool {
+		self.is_skip() || self.is_skip_and_fn()
+	}
+
+	#[must_use]
+	/// Returns `true` if this [`ErrorCallback`] will involve removing.
+	pub const fn will_remove(&self) -> bool {
+		self.is_remove() || self.is_remove_and_fn()
+	}
+
+	#[must_use]
+	/// Returns `true` if this [`ErrorCallback`] contains a function.
+	pub const fn has_fn(&self) -> bool {
+		matches!(self, Self::PauseAndFn(_) | Self::Fn(_) | Self::SkipAndFn(_) | Self::RemoveAndFn(_))
+	}
 }
 
 impl<Error> Default for ErrorCallback<Error> {
@@ -127,6 +256,10 @@ impl<Error> fmt::Debug for ErrorCallback<Error> {
 				Self::Pause => format_args!("ErrorCallback::Pause"),
 				Self::PauseAndFn(_) => format_args!("ErrorCallback::PauseAndFn(_)"),
 				Self::Fn(_) => format_args!("ErrorCallback::Fn(_)"),
+				Self::Skip => format_args!("ErrorCallback::Skip"),
+				Self::SkipAndFn(_) => format_args!("ErrorCallback::SkipAndFn(_)"),
+				Self::Remove => format_args!("ErrorCallback::Remove"),
+				Self::RemoveAndFn(_) => format_args!("ErrorCallback::RemoveAndFn(_)"),
 			}
 		)
 	}
@@ -2,14 +2,19 @@
 
 //---------------------------------------------------------------------------------------------------- use
 use std::{
+	borrow::Cow,
 	marker::PhantomData,
 	time::Duration
 };
 use crate::{
-	config::{Callbacks,RuntimeConfig},
+	config::{Callbacks,RuntimeConfig,DeviceLoss,Dither,MediaControls,Realtime,SessionConfig},
 	engine::Engine,
 	state::AudioState,
 	extra_data::ExtraData,
+	output::{OutputBackend,SampleFormat,Latency,Sink},
+	render::RenderSpec,
+	signal::Repair,
+	resampler::{ResamplerQuality,ResamplerFn},
 };
 use strum::{
 	AsRefStr,
@@ -21,7 +26,13 @@ use strum::{
 };
 
 #[allow(unused_imports)] // docs
-use crate::config::ErrorCallback;
+use crate::{
+	config::ErrorCallback,
+	error::{DecodeError,OutputError},
+	output::{OutputDevice,Pull,devices},
+	resampler::Resample,
+	state::Session,
+};
 
 //---------------------------------------------------------------------------------------------------- InitConfig
 /// Initialization config for the [`Engine`].
@@ -107,12 +118,218 @@ pub struct InitConfig<Extra: ExtraData> {
 	/// A practical value would be somewhere between `0.1ms - 5s`.
 	pub audio_retry: Duration,
 
+	/// Which audio output backends should be used?
+	///
+	/// This is an ordered list of backends to try, the 1st one that
+	/// successfully opens an audio stream is used, if it fails the
+	/// next is tried, and so on.
+	///
+	/// Backends that were not compiled in are skipped.
+	///
+	/// The backend actually used can be checked with [`Engine::output_backend`].
+	///
+	/// The default is [`OutputBackend::DEFAULT`].
+	pub output_backends: Cow<'static, [OutputBackend]>,
+
+	/// Which audio output device should be used?
+	///
+	/// This is an [`OutputDevice::id`] from [`devices()`].
+	///
+	/// Device IDs are specific to each backend, if a backend in
+	/// `output_backends` does not have this device, the next one is tried.
+	///
+	/// `None` means the system's default output device will be used.
+	///
+	/// If the device cannot be found, the audio thread will report an
+	/// [`OutputError::DeviceUnavailable`] and retry every `audio_retry`.
+	///
+	/// The device can be switched later with [`Engine::output_device`].
+	pub output_device: Option<String>,
+
+	/// What should happen when the audio output device
+	/// is lost (e.g. unplugged) or the default device changes?
+	///
+	/// See [`DeviceLoss`] for more details.
+	pub device_loss: DeviceLoss,
+
+	/// A custom audio output to use instead of the built-in backends.
+	///
+	/// If this is `Some`, all audio is written to this [`Sink`], and
+	/// `output_backends`, `output_device` and `device_loss` are ignored.
+	///
+	/// [`Engine::output_backend`] will return [`OutputBackend::Custom`], and
+	/// [`Engine::output_device`] will return [`OutputError::BackendUnavailable`].
+	///
+	/// This is ignored if `pull` is set.
+	pub output_sink: Option<Box<dyn Sink + Send>>,
+
+	/// Should the host application pull audio instead?
+	///
+	/// If this is `Some`, `sansan` will not open any audio output device,
+	/// instead, the host application pulls samples in this spec with
+	/// [`Engine::render`] (or [`Pull::render`] after [`Engine::take_pull`])
+	/// from its own audio callback.
+	///
+	/// This is for embedding `sansan` inside a larger audio graph, e.g.
+	/// a game engine, a DAW plugin, or another mixer.
+	///
+	/// When this is set, `output_backends`, `output_device`,
+	/// `device_loss` and `audio_retry` are ignored.
+	///
+	/// # Panics
+	/// [`Engine::init`] panics if [`RenderSpec::sample_rate`]
+	/// or [`RenderSpec::channels`] are `0`.
+	pub pull: Option<RenderSpec>,
+
+	/// Which resampler should be used?
+	///
+	/// Audio is only resampled when the output device does
+	/// not support the audio's sample rate (or in `pull` mode,
+	/// when it differs from [`RenderSpec::sample_rate`]).
+	///
+	/// See [`ResamplerQuality`] for the trade-offs.
+	pub resampler: ResamplerQuality,
+
+	/// A custom resampler to use instead of `resampler`.
+	///
+	/// If this is `Some`, this function is called to create a new
+	/// [`Resample`] every time one is needed, and `resampler` is ignored.
+	///
+	/// Custom [`Sink`]'s are never resampled.
+	pub resampler_custom: Option<ResamplerFn>,
+
+	/// Should audio be output bit-perfect?
+	///
+	/// If `true`, the output device is opened at the audio's native
+	/// sample rate and channel layout whenever the device supports it,
+	/// so the samples are sent to the device untouched (no resampling).
+	///
+	/// The volume is locked at unity while this is enabled, i.e.
+	/// [`Engine::volume`] still updates [`AudioState::volume`] but
+	/// it is not applied, volume should be controlled by the hardware.
+	///
+	/// If the device does not support the native spec, audio is still
+	/// resampled, [`AudioState::bit_perfect`] reports whether the
+	/// current output path actually is bit-perfect, which also
+	/// requires an `f32` [`InitConfig::sample_format`] (integer
+	/// formats are quantized and possibly dithered).
+	pub bit_perfect: bool,
+
+	/// Which sample format should the output use?
+	///
+	/// `None` uses the output device's preferred format. If the
+	/// device does not support the requested format, its preferred
+	/// one is used instead, see [`Engine::output_format`].
+	///
+	/// This applies to the `cpal` and `file` [`OutputBackend`]'s,
+	/// the `cubeb` backend only supports `f32` and fails to open
+	/// if any other format is requested.
+	pub sample_format: Option<SampleFormat>,
+
+	/// The dither applied when the output uses an integer [`SampleFormat`].
+	///
+	/// This is ignored (no dither is applied, samples are only rounded)
+	/// if [`InitConfig::bit_perfect`] is enabled.
+	///
+	/// See [`Dither`] for more details.
+	pub dither: Dither,
+
+	/// How much audio should be buffered between `sansan` and the output device?
+	///
+	/// [`Latency::LOW`] is good for interactive use, [`Latency::HIGH`] is good for
+	/// battery-powered devices, see [`Latency`] for more details.
+	///
+	/// This can be changed later with [`Engine::output_latency`], and
+	/// the latency actually negotiated can be checked with [`Engine::latency`].
+	///
+	/// This is ignored if `output_sink` or `pull` is set.
+	pub latency: Latency,
+
+	/// Which of the audio threads should be promoted to real-time priority?
+	///
+	/// If the OS denies the promotion, playback continues with
+	/// normal priority, the result can be checked with [`Engine::priorities`].
+	///
+	/// See [`Realtime`] for more details.
+	pub realtime: Realtime,
+
+	//------------------------------------------ Media controls
+	//
+	/// Should the [`Engine`] integrate with the OS media controls?
+	///
+	/// If `Some`, the current track, playback status, position and
+	/// volume are published to the OS (MPRIS on Linux), and media keys
+	/// and other media control requests are mapped to [`Engine`] signals.
+	///
+	/// This requires the `media_controls` feature, it is ignored
+	/// without it, see [`MediaControls`] for more details.
+	pub media_controls: Option<MediaControls>,
+
+	//------------------------------------------ Errors
+	//
+	/// How many [`DecodeError`]'s in a row are tolerated
+	/// before the [`Callbacks::error_decode`] action is taken?
+	///
+	/// Some decode errors are transient, e.g, a few corrupted packets
+	/// in an otherwise fine track. Each error will still be forwarded
+	/// to the [`ErrorCallback`]'s function (if any), but the action
+	/// (pausing, skipping, removing) will only be taken once more than
+	/// `error_decode_retry` errors have occurred without any audio
+	/// successfully being played in-between.
+	///
+	/// `0` means the action is taken on the very first error.
+	pub error_decode_retry: usize,
+
+	/// How many corrupt packets in a row are skipped
+	/// before a [`DecodeError`] is reported?
+	///
+	/// Real-world audio files often contain a few damaged frames.
+	/// Instead of erroring, the `Engine` will skip these packets, fill
+	/// the gap they leave with silence (to preserve timing) and continue
+	/// decoding from the next valid packet.
+	///
+	/// Only once more than `error_decode_packet_threshold` packets in a
+	/// row fail is a [`DecodeError`] reported, which then goes through
+	/// `error_decode_retry` and [`Callbacks::error_decode`] as normal.
+	/// Decoding continues after reporting, what happens to the
+	/// track is decided by [`Callbacks::error_decode`].
+	///
+	/// This applies the same to corrupt audio data and a corrupt
+	/// audio container, the total amount of skipped packets can
+	/// be read with [`AudioStateReader::packets_skipped`](crate::state::AudioStateReader::packets_skipped).
+	///
+	/// `0` means every corrupt packet is reported (the default).
+	pub error_decode_packet_threshold: usize,
+
 	//------------------------------------------ Restore state/settings
 	//
 	/// TODO
 	pub audio_state: Option<AudioState<Extra>>,
+	/// How an invalid `audio_state` should be repaired when restoring it.
+	///
+	/// With [`Repair::NONE`] (the default), an `audio_state` that fails
+	/// validation is not restored at all and the [`Engine`] starts with
+	/// the default [`AudioState`], see [`RestoreError`] for what is invalid.
+	///
+	/// This does not apply to the `AudioState` loaded from a [`Session`],
+	/// which is always restored with [`Repair::ALL`].
+	///
+	/// [`RestoreError`]: crate::signal::RestoreError
+	pub audio_state_repair: Repair,
 	/// TODO
 	pub live_config: Option<RuntimeConfig>,
+
+	/// Should the [`Session`] be automatically loaded and saved?
+	///
+	/// If `Some`, the session is loaded from [`SessionConfig::path`]
+	/// and resumed paused at the position it was saved at, and saved
+	/// again on an interval and/or on [`Drop`].
+	///
+	/// `audio_state` and `live_config` take priority
+	/// over the loaded session if they are `Some`.
+	///
+	/// See [`SessionConfig`] for more details.
+	pub session: Option<SessionConfig<Extra>>,
 }
 
 //---------------------------------------------------------------------------------------------------- InitConfig Impl
@@ -120,15 +337,33 @@ impl<Extra: ExtraData> InitConfig<Extra> {
 	/// A reasonable default [`InitConfig`].
 	///
 	/// ```rust
-	/// # use sansan::config::*;
+	/// # use sansan::{config::*,output::*,resampler::*};
+	/// # use std::borrow::Cow;
 	/// InitConfig::<()> {
 	///     callbacks:             Callbacks::DEFAULT,
 	///     callback_low_priority: true,
 	///     shutdown_blocking:     true,
 	///     init_blocking:         false,
 	///     audio_retry:           std::time::Duration::from_secs(1),
+	///     output_backends:       Cow::Borrowed(OutputBackend::DEFAULT),
+	///     output_device:         None,
+	///     device_loss:           DeviceLoss::Migrate,
+	///     output_sink:           None,
+	///     pull:                  None,
+	///     resampler:             ResamplerQuality::Fft,
+	///     resampler_custom:      None,
+	///     bit_perfect:           false,
+	///     sample_format:         None,
+	///     dither:                Dither::Tpdf,
+	///     latency:               Latency::DEFAULT,
+	///     realtime:              Realtime::Audio,
+	///     media_controls:        None,
+	///     error_decode_retry:    0,
+	///     error_decode_packet_threshold: 0,
 	///     audio_state:           None,
+	///     audio_state_repair:    sansan::signal::Repair::NONE,
 	///     live_config:           None,
+	///     session:               None,
 	/// };
 	/// ```
 	pub const DEFAULT: Self = Self {
@@ -137,8 +372,25 @@ impl<Extra: ExtraData> InitConfig<Extra> {
 		shutdown_blocking:     true,
 		init_blocking:         false,
 		audio_retry:           Duration::from_secs(1),
+		output_backends:       Cow::Borrowed(OutputBackend::DEFAULT),
+		output_device:         None,
+		device_loss:           DeviceLoss::DEFAULT,
+		output_sink:           None,
+		pull:                  None,
+		resampler:             ResamplerQuality::DEFAULT,
+		resampler_custom:      None,
+		bit_perfect:           false,
+		sample_format:         None,
+		dither:                Dither::DEFAULT,
+		latency:               Latency::DEFAULT,
+		realtime:              Realtime::DEFAULT,
+		media_controls:        None,
+		error_decode_retry:    0,
+		error_decode_packet_threshold: 0,
 		audio_state:           None,
+		audio_state_repair:    Repair::NONE,
 		live_config:           None,
+		session:               None,
 	};
 }
 
@@ -0,0 +1,103 @@
+//! OS media controls configuration.
+
+//---------------------------------------------------------------------------------------------------- use
+use std::{
+	borrow::Cow,
+	time::Duration,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::InitConfig,
+	signal::Seek,
+};
+
+//---------------------------------------------------------------------------------------------------- MediaControls
+/// Built-in OS media controls
+///
+/// If [`InitConfig::media_controls`] is `Some` and the `media_controls`
+/// feature is enabled, the [`Engine`] publishes its state to, and is
+/// controlled by, the operating system's media controls:
+///
+/// | OS      | Integration |
+/// |---------|-------------|
+/// | Linux   | [MPRIS](https://specifications.freedesktop.org/mpris-spec/latest) over D-Bus
+/// | macOS   | `MPNowPlayingInfoCenter` and `MPRemoteCommandCenter`
+/// | Windows | `SystemMediaTransportControls`
+///
+/// The current track's title, artist, album and runtime (read from
+/// the audio's tags), playback status, position and volume are published.
+///
+/// Incoming requests (media keys, desktop widgets, `playerctl`, etc)
+/// are mapped to the equivalent [`Engine`] signals, e.g. "next" acts
+/// as if [`Engine::next`] was called.
+///
+/// All of this happens on a separate thread,
+/// it never blocks the `Engine` nor its audio.
+///
+/// If the media controls cannot be created (e.g. there is no
+/// D-Bus session), this is logged and the `Engine` continues
+/// without them, see [`Engine::media_controls`].
+#[derive(Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct MediaControls {
+	/// The name this player is registered under.
+	///
+	/// On Linux, this is the MPRIS D-Bus name, i.e.
+	/// `org.mpris.MediaPlayer2.{dbus_name}`.
+	///
+	/// This should be unique and must be a valid D-Bus name
+	/// element (ASCII letters, digits, and `_`).
+	pub dbus_name: Cow<'static, str>,
+
+	/// The human-readable name of this player, e.g. shown in desktop widgets.
+	pub display_name: Cow<'static, str>,
+
+	/// The raw `HWND` of the application's window.
+	///
+	/// This is required on Windows, and ignored on other platforms.
+	pub hwnd: Option<usize>,
+
+	/// How far a plain "seek forwards/backwards" request seeks.
+	///
+	/// Requests that specify an amount or
+	/// a position (e.g. MPRIS) use that instead.
+	pub seek_step: Duration,
+
+	/// How often the [`Engine`]'s state is checked for
+	/// changes, and published if anything has changed.
+	///
+	/// Changes made _through_ the media controls are published immediately.
+	pub refresh_rate: Duration,
+}
+
+impl MediaControls {
+	/// Registers as `sansan`, seeks 5 seconds, and refreshes every 500 milliseconds.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// # use std::{borrow::Cow, time::Duration};
+	/// assert_eq!(MediaControls::DEFAULT, MediaControls {
+	///     dbus_name:    Cow::Borrowed("sansan"),
+	///     display_name: Cow::Borrowed("sansan"),
+	///     hwnd:         None,
+	///     seek_step:    Duration::from_secs(5),
+	///     refresh_rate: Duration::from_millis(500),
+	/// });
+	/// ```
+	pub const DEFAULT: Self = Self {
+		dbus_name:    Cow::Borrowed("sansan"),
+		display_name: Cow::Borrowed("sansan"),
+		hwnd:         None,
+		seek_step:    Duration::from_secs(5),
+		refresh_rate: Duration::from_millis(500),
+	};
+}
+
+impl Default for MediaControls {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
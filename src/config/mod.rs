@@ -8,6 +8,22 @@ pub use callbacks::Callbacks;
 
 mod error_callback;
 pub use error_callback::ErrorCallback;
+pub(crate) use error_callback::ErrorAction;
+
+mod device_loss;
+pub use device_loss::DeviceLoss;
+
+mod dither;
+pub use dither::Dither;
+
+mod realtime;
+pub use realtime::Realtime;
+
+mod media_controls;
+pub use media_controls::MediaControls;
+
+mod session;
+pub use session::SessionConfig;
 
 mod init_config;
 pub use init_config::InitConfig;
@@ -0,0 +1,90 @@
+//! Real-time thread priority.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::InitConfig,
+	state::Priority,
+};
+
+//---------------------------------------------------------------------------------------------------- Realtime
+/// Which of the [`Engine`]'s threads should be promoted to real-time priority?
+///
+/// Real-time (or elevated) scheduling lets the audio threads
+/// run before other threads on the system, which prevents
+/// dropouts when the CPU is busy, e.g. a heavy UI thread.
+///
+/// The promotion is done with [`audio_thread_priority`](https://docs.rs/audio_thread_priority),
+/// based on the output's buffer size and sample rate:
+///
+/// | OS      | Mechanism |
+/// |---------|-----------|
+/// | Linux   | `RLIMIT_RTPRIO`, or `rtkit` over D-Bus
+/// | macOS   | Mach real-time thread time constraints
+/// | Windows | MMCSS (`Pro Audio`)
+///
+/// The OS may deny the promotion (e.g. missing permissions on Linux), in that
+/// case the thread continues with normal priority, a warning is logged, and
+/// the result can be checked with [`Engine::priorities`].
+///
+/// Set with [`InitConfig::realtime`].
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Realtime {
+	/// No thread is promoted.
+	Off,
+
+	#[default]
+	/// The thread writing to the audio output device is promoted.
+	///
+	/// This is the thread that causes audible dropouts if it misses its deadline.
+	Audio,
+
+	/// The audio thread and the thread decoding audio are promoted.
+	///
+	/// This can help if decoding itself is being starved, e.g. large
+	/// lossless files on a heavily loaded system, at the cost of giving
+	/// a more CPU-heavy thread real-time priority.
+	AudioAndDecode,
+}
+
+impl Realtime {
+	/// Returns [`Self::Audio`].
+	pub const DEFAULT: Self = Self::Audio;
+
+	#[must_use]
+	/// Should the audio thread be promoted?
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// assert!(!Realtime::Off.audio());
+	/// assert!(Realtime::Audio.audio());
+	/// assert!(Realtime::AudioAndDecode.audio());
+	/// ```
+	pub const fn audio(self) -> bool {
+		matches!(self, Self::Audio | Self::AudioAndDecode)
+	}
+
+	#[must_use]
+	/// Should the decoding thread be promoted?
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// assert!(!Realtime::Off.decode());
+	/// assert!(!Realtime::Audio.decode());
+	/// assert!(Realtime::AudioAndDecode.decode());
+	/// ```
+	pub const fn decode(self) -> bool {
+		matches!(self, Self::AudioAndDecode)
+	}
+}
@@ -0,0 +1,131 @@
+//! Session persistence configuration.
+
+//---------------------------------------------------------------------------------------------------- use
+use std::{
+	path::{Path,PathBuf},
+	time::Duration,
+};
+use crate::{
+	error::SessionError,
+	extra_data::ExtraData,
+	state::Session,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::{InitConfig,RuntimeConfig},
+	state::{AudioState,Current},
+	signal::Repair,
+};
+
+//---------------------------------------------------------------------------------------------------- SessionConfig
+/// Automatic [`Session`] loading and saving
+///
+/// If [`InitConfig::session`] is `Some`, [`Engine::init`] will load the
+/// [`Session`] at `path` (if it exists), and restore its [`AudioState`]
+/// and [`RuntimeConfig`].
+///
+/// Playback always resumes _paused_, at the exact [`Current::elapsed`]
+/// position the session was saved at, regardless of whether the
+/// [`Engine`] was playing or not when it was saved.
+///
+/// A stale session (e.g. files were moved since it was saved)
+/// is restored with [`Repair::ALL`] instead of erroring.
+///
+/// If [`InitConfig::audio_state`] or [`InitConfig::live_config`]
+/// are `Some`, they take priority over the loaded session's.
+///
+/// A missing session file is not an error (e.g. the first time
+/// the application runs), the `Engine` just starts fresh.
+/// Other errors are logged and the `Engine` also starts fresh.
+///
+/// This is created with [`SessionConfig::new`], which requires the `bincode`
+/// feature, or with [`SessionConfig::with_codec`] for any other format.
+#[derive(Clone,Debug)]
+pub struct SessionConfig<Extra: ExtraData> {
+	/// The session file to load from and save to.
+	pub path: PathBuf,
+
+	/// How often should the session be saved in the background?
+	///
+	/// `None` disables saving on an interval.
+	///
+	/// The session is saved on a separate thread, it
+	/// never blocks the `Engine` nor its audio.
+	pub autosave: Option<Duration>,
+
+	/// Should the session be saved when the [`Engine`] is dropped?
+	pub save_on_drop: bool,
+
+	/// Load a `Session` from a path.
+	pub(crate) load: fn(&Path) -> Result<Session<Extra>, SessionError>,
+
+	/// Save a `Session` to a path.
+	pub(crate) save: fn(&Path, &Session<Extra>) -> Result<(), SessionError>,
+}
+
+impl<Extra: ExtraData> SessionConfig<Extra> {
+	/// Load and save the session at `path` with custom functions.
+	///
+	/// This allows using any format, e.g. JSON with `serde_json`
+	/// (the [`Session`] implements `serde` with the `serde` feature).
+	/// A missing file should be reported as a [`SessionError::Io`]
+	/// with [`std::io::ErrorKind::NotFound`] so that it is not logged
+	/// as an error the first time the application runs.
+	///
+	/// This saves on [`Drop`], but not on an interval.
+	///
+	/// ```rust
+	/// # use sansan::{config::*,error::SessionError};
+	/// let session = SessionConfig::<()>::with_codec(
+	///     "/tmp/session.json",
+	///     |path| Err(SessionError::Io(std::io::ErrorKind::NotFound.into())),
+	///     |path, session| Ok(()),
+	/// );
+	/// assert_eq!(session.autosave, None);
+	/// assert_eq!(session.save_on_drop, true);
+	/// ```
+	pub fn with_codec(
+		path: impl Into<PathBuf>,
+		load: fn(&Path) -> Result<Session<Extra>, SessionError>,
+		save: fn(&Path, &Session<Extra>) -> Result<(), SessionError>,
+	) -> Self {
+		Self {
+			path: path.into(),
+			autosave: None,
+			save_on_drop: true,
+			load,
+			save,
+		}
+	}
+}
+
+#[cfg(feature = "bincode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+impl<Extra> SessionConfig<Extra>
+where
+	Extra: ExtraData + bincode::Encode + bincode::Decode,
+{
+	/// Load and save the session at `path`.
+	///
+	/// This saves on [`Drop`], but not on an interval.
+	///
+	/// ```rust
+	/// # use sansan::config::*;
+	/// # use std::time::Duration;
+	/// let mut session = SessionConfig::<()>::new("/tmp/session.bin");
+	/// assert_eq!(session.autosave, None);
+	/// assert_eq!(session.save_on_drop, true);
+	///
+	/// // Also save every 30 seconds.
+	/// session.autosave = Some(Duration::from_secs(30));
+	/// ```
+	pub fn new(path: impl Into<PathBuf>) -> Self {
+		Self::with_codec(
+			path,
+			|path| Session::load(path),
+			|path, session| session.save(path),
+		)
+	}
+}
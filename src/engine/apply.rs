@@ -0,0 +1,234 @@
+//! Applying `Signal`'s.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	engine::{Engine,EngineHandle},
+	extra_data::ExtraData,
+	signal::{Response,Signal,SignalError},
+	state::{AudioState,AudioStateSnapshot},
+};
+
+//---------------------------------------------------------------------------------------------------- Engine Impl
+impl<Extra: ExtraData> Engine<Extra> {
+	/// Apply a [`Signal`].
+	///
+	/// This is the same as calling the [`Engine`] method of the same
+	/// name as the [`Signal`] variant, e.g. [`Signal::Seek`] is [`Engine::seek`].
+	///
+	/// The [`Response`] owns a copy of the resulting [`AudioState`], so prefer
+	/// the methods directly if the response doesn't need to be serialized.
+	///
+	/// This forwards to [`EngineHandle::apply`], which can be
+	/// used to apply [`Signal`]'s from other threads.
+	pub fn apply(&mut self, signal: Signal<Extra>) -> Response<Extra> {
+		self.handle.apply(signal)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EngineHandle Impl
+impl<Extra: ExtraData> EngineHandle<Extra> {
+	/// Apply a [`Signal`], see [`Engine::apply`].
+	pub fn apply(&self, signal: Signal<Extra>) -> Response<Extra> {
+		match signal {
+			Signal::Toggle                    => ok(&self.toggle()),
+			Signal::Play                      => ok(&self.play()),
+			Signal::Pause                     => ok(&self.pause()),
+			Signal::Next                      => ok(&self.next()),
+			Signal::Previous                  => ok(&self.previous()),
+			Signal::Stop                      => ok(&self.stop()),
+			Signal::Clear(clear)              => ok(&self.clear(clear)),
+			Signal::Restore(restore)          => result(self.restore(restore)),
+			Signal::Repeat(repeat)            => ok(&self.repeat(repeat)),
+			Signal::Volume(volume)            => ok(&self.volume(volume)),
+			Signal::Shuffle(shuffle)          => ok(&self.shuffle(shuffle)),
+			Signal::Add(add)                  => ok(&self.add(add)),
+			Signal::AddMany(add_many)         => ok(&self.add_many(add_many)),
+			Signal::Seek(seek)                => result(self.seek(seek)),
+			Signal::Skip(skip)                => result(self.skip(skip)),
+			Signal::Back(back)                => result(self.back(back)),
+			Signal::SetIndex(set_index)       => result(self.set_index(set_index)),
+			Signal::Remove(remove)            => result(self.remove(remove)),
+			Signal::RemoveRange(remove_range) => result(self.remove_range((remove_range.start_bound, remove_range.end_bound))),
+			Signal::OutputDevice(device)      => result(self.output_device(device).map(|()| self.reader.get())),
+			Signal::OutputLatency(latency)    => result(self.output_latency(latency).map(|()| self.reader.get())),
+			Signal::ConfigUpdate(config)      => {
+				self.config_update(|c| *c = config);
+				ok(&self.reader.get())
+			},
+		}
+	}
+}
+
+/// Copy the [`AudioState`] out of a snapshot.
+fn ok<Extra: ExtraData>(snapshot: &AudioStateSnapshot<Extra>) -> Response<Extra> {
+	Response::AudioState(AudioState::clone(snapshot))
+}
+
+/// Map a signal's `Result` into a [`Response`].
+fn result<Extra, E>(result: Result<AudioStateSnapshot<Extra>, E>) -> Response<Extra>
+where
+	Extra: ExtraData,
+	E: Into<SignalError>,
+{
+	match result {
+		Ok(snapshot) => ok(&snapshot),
+		Err(error) => Response::Error(error.into()),
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use crate::{
+		config::{InitConfig,RuntimeConfig},
+		error::OutputError,
+		output::Latency,
+		render::RenderSpec,
+		signal::{
+			AddMany,AddMethod,RemoveError,RemoveRange,Repeat,
+			Response,Seek,SeekError,Signal,SignalError,Volume,
+		},
+	};
+	use pretty_assertions::assert_eq;
+
+	#[cfg(any(feature = "serde", feature = "bincode"))]
+	/// Signals of every shape.
+	fn signals() -> Vec<Signal<usize>> {
+		vec![
+			Signal::Toggle,
+			Signal::Volume(Volume::new(0.5)),
+			Signal::RemoveRange(RemoveRange::from(1..=3)),
+			Signal::AddMany(AddMany {
+				sources: crate::tests::sources(),
+				method: AddMethod::Back,
+				clear: true,
+				play: true,
+			}),
+			Signal::OutputDevice(Some("device".into())),
+			Signal::OutputDevice(None),
+			Signal::OutputLatency(Latency::new(25)),
+			Signal::ConfigUpdate(RuntimeConfig { queue_end_clear: true, ..RuntimeConfig::DEFAULT }),
+		]
+	}
+
+	#[cfg(any(feature = "serde", feature = "bincode"))]
+	/// Errors of every shape.
+	fn errors() -> Vec<Response<usize>> {
+		vec![
+			Response::Error(SignalError::Seek(SeekError::NoCurrent)),
+			Response::Error(SignalError::Output(OutputError::BackendUnavailable)),
+			Response::Error(SignalError::Output(OutputError::Unknown("unknown".into()))),
+		]
+	}
+
+	#[test]
+	fn apply() {
+		let mut engine = crate::tests::init();
+
+		// Errors are returned as `Response::Error`.
+		assert_eq!(
+			engine.apply(Signal::Seek(Seek::Absolute(1.0))),
+			Response::Error(SignalError::Seek(SeekError::NoCurrent)),
+		);
+
+		let Response::AudioState(audio_state) = engine.apply(Signal::AddMany(AddMany {
+			sources: crate::tests::sources(),
+			method: AddMethod::Back,
+			clear: false,
+			play: false,
+		})) else {
+			panic!("add_many failed");
+		};
+		assert_eq!(audio_state.queue.len(), 10);
+
+		let Response::AudioState(audio_state) = engine.apply(Signal::Volume(Volume::new(0.5))) else {
+			panic!("volume failed");
+		};
+		assert_eq!(audio_state.volume, Volume::new(0.5));
+
+		let Response::AudioState(audio_state) = engine.apply(Signal::Repeat(Repeat::Queue)) else {
+			panic!("repeat failed");
+		};
+		assert_eq!(audio_state.repeat, Repeat::Queue);
+
+		let Response::AudioState(audio_state) = engine.apply(Signal::RemoveRange(RemoveRange::from(0..4))) else {
+			panic!("remove_range failed");
+		};
+		assert_eq!(audio_state.queue.len(), 6);
+
+		// The response matches the `Engine`'s state.
+		assert_eq!(*engine.reader().get(), audio_state);
+
+		let response: Result<_, _> = engine.apply(Signal::RemoveRange(RemoveRange::from(10..20))).into();
+		assert_eq!(response, Err(SignalError::Remove(RemoveError::BadIndex)));
+
+		// The whole `RuntimeConfig` is replaced.
+		let config = RuntimeConfig { queue_end_clear: true, ..RuntimeConfig::DEFAULT };
+		assert_eq!(engine.apply(Signal::ConfigUpdate(config)), Response::AudioState(audio_state));
+		assert_eq!(engine.config(), config);
+	}
+
+	#[test]
+	fn handle() {
+		let engine = crate::tests::init();
+		let handle = engine.handle();
+
+		// `Signal`'s can be applied from other threads.
+		let response = std::thread::spawn(move || {
+			handle.apply(Signal::Volume(Volume::new(0.25)));
+			handle.apply(Signal::Repeat(Repeat::Current))
+		}).join().unwrap();
+
+		let Response::AudioState(audio_state) = response else {
+			panic!("repeat failed");
+		};
+		assert_eq!(audio_state.volume, Volume::new(0.25));
+		assert_eq!(audio_state.repeat, Repeat::Current);
+		assert_eq!(*engine.reader().get(), audio_state);
+	}
+
+	#[test]
+	fn output() {
+		// There are no output devices in pull mode.
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			pull: Some(RenderSpec { sample_rate: 48_000, channels: 2 }),
+			..InitConfig::DEFAULT
+		});
+
+		let error = Response::Error(SignalError::Output(OutputError::BackendUnavailable));
+		assert_eq!(engine.apply(Signal::OutputDevice(None)), error);
+		assert_eq!(engine.apply(Signal::OutputLatency(Latency::DEFAULT)), error);
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn serde() {
+		for signal in signals() {
+			let json = serde_json::to_string(&signal).unwrap();
+			assert_eq!(serde_json::from_str::<Signal<usize>>(&json).unwrap(), signal);
+		}
+
+		for response in errors() {
+			let json = serde_json::to_string(&response).unwrap();
+			assert_eq!(serde_json::from_str::<Response<usize>>(&json).unwrap(), response);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "bincode")]
+	fn bincode() {
+		let config = bincode::config::standard();
+
+		for signal in signals() {
+			let bytes = bincode::encode_to_vec(&signal, config).unwrap();
+			let (decoded, _) = bincode::decode_from_slice::<Signal<usize>, _>(&bytes, config).unwrap();
+			assert_eq!(decoded, signal);
+		}
+
+		for response in errors() {
+			let bytes = bincode::encode_to_vec(&response, config).unwrap();
+			let (decoded, _) = bincode::decode_from_slice::<Response<usize>, _>(&bytes, config).unwrap();
+			assert_eq!(decoded, response);
+		}
+	}
+}
@@ -0,0 +1,518 @@
+//! Async `Engine` facade.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	engine::{Engine,EngineHandle,EngineSend},
+	error::OutputError,
+	extra_data::ExtraData,
+	event::{Event,EventFilter,EventKind},
+	output::Latency,
+	signal::{
+		Add,AddMany,Back,BackError,Clear,Remove,RemoveError,Repeat,Response,Restore,
+		RestoreError,Seek,SeekError,SetIndex,SetIndexError,Shuffle,Signal,Skip,SkipError,Volume,
+	},
+	state::{AudioStateReader,AudioStateSnapshot},
+};
+use std::{
+	collections::VecDeque,
+	future::Future,
+	pin::Pin,
+	sync::{Arc,Mutex},
+	task::{Context,Poll,Waker},
+};
+
+//---------------------------------------------------------------------------------------------------- AsyncEngine
+/// An async facade over the [`Engine`]
+///
+/// Every method returns a future ([`Reply`]) that resolves to
+/// the same value the equivalent [`Engine`] method returns.
+///
+/// This does not depend on any async runtime, so it works with
+/// `tokio`, `smol`, `async-std`, or a plain `block_on()`.
+///
+/// ## How it works
+/// Each call is made on a short-lived thread with a clone of the
+/// [`EngineHandle`], which wakes the future once the `Engine` replies.
+/// No calling task ever blocks, and calls from different tasks do
+/// not wait on each other, `Kernel` applies them as they arrive.
+///
+/// The ordering guarantees are the same as [`EngineHandle`]'s, calls
+/// awaited one after another are applied in that order, while calls
+/// made without awaiting the previous [`Reply`] have no defined order.
+/// Use [`AsyncEngine::send`] to queue signals in order without waiting.
+///
+/// [`AsyncEngine`] is cheap to [`Clone`], all clones control the same
+/// [`Engine`], which is dropped once all clones and pending [`Reply`]'s
+/// have been dropped. This happens on the thread that dropped the last one,
+/// so with [`InitConfig::shutdown_blocking`](crate::config::InitConfig::shutdown_blocking)
+/// that drop waits for the shutdown to complete.
+///
+/// ```rust,no_run
+/// # use sansan::{*,signal::*,event::*};
+/// # #[cfg(feature = "async")]
+/// # async fn handler() -> Result<(), SeekError> {
+/// let engine = AsyncEngine::new(Engine::<()>::init(Default::default()));
+///
+/// // In an async web handler, no `spawn_blocking` needed.
+/// let audio_state = engine.seek(Seek::Absolute(10.0)).await?;
+///
+/// // Events are received without blocking as well.
+/// let mut events = engine.subscribe(EventKind::Seeked.into()).await;
+/// while let Some(event) = events.recv().await {
+///     println!("{event:?}");
+/// }
+/// # Ok(()) }
+/// ```
+#[derive(Clone,Debug)]
+pub struct AsyncEngine<Extra: ExtraData> {
+	/// The handle all calls are made through.
+	handle: EngineHandle<Extra>,
+	/// The `Engine` itself, only kept so that it
+	/// shuts down once the last clone is dropped.
+	_engine: Arc<Mutex<Engine<Extra>>>,
+}
+
+/// Make a call with the [`EngineHandle`] on a new thread, returning a [`Reply`] for its output.
+///
+/// The thread holds a clone of the [`AsyncEngine`], so
+/// the `Engine` stays alive until the call completes.
+///
+/// # Panics
+/// This panics if the thread could not be spawned.
+macro_rules! call {
+	($self:ident, |$handle:ident| $call:expr) => {{
+		let (reply, future) = Reply::new();
+		let this = $self.clone();
+		std::thread::Builder::new()
+			.name("AsyncEngine".into())
+			.spawn(move || {
+				let $handle = &this.handle;
+				reply.set($call);
+			})
+			.unwrap();
+		future
+	}};
+}
+
+impl<Extra: ExtraData> AsyncEngine<Extra> {
+	#[cold]
+	#[inline(never)]
+	#[must_use]
+	/// Take ownership of the [`Engine`], and control it asynchronously.
+	pub fn new(engine: Engine<Extra>) -> Self {
+		Self {
+			handle: engine.handle(),
+			_engine: Arc::new(Mutex::new(engine)),
+		}
+	}
+
+	#[must_use]
+	/// Returns the [`AudioStateReader`], see [`Engine::reader`].
+	///
+	/// Reading never waits on the `Engine`, so this is not async.
+	pub const fn reader(&self) -> &AudioStateReader<Extra> {
+		self.handle.reader()
+	}
+
+	#[must_use]
+	/// Send signals without waiting for a response, see [`EngineSend`].
+	///
+	/// Sending never waits on the `Engine`, so this is not async.
+	pub const fn send(&self) -> EngineSend<'_, Extra> {
+		self.handle.send()
+	}
+
+	/// Subscribe to [`Event`]'s, see [`Engine::subscribe`].
+	///
+	/// This resolves to an [`EventStream`] once the subscription is active.
+	pub fn subscribe(&self, filter: EventFilter) -> Reply<EventStream<Extra>> {
+		call!(self, |handle| {
+			let queue = Arc::new(Mutex::new(EventQueue {
+				events: VecDeque::new(),
+				waker: None,
+				closed: false,
+			}));
+			let sender = EventSender(Arc::clone(&queue));
+			handle.subscribe_with(filter, Box::new(move |event| sender.push(event)));
+			EventStream(queue)
+		})
+	}
+
+	/// See [`Engine::toggle`].
+	pub fn toggle(&self) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.toggle())
+	}
+
+	/// See [`Engine::play`].
+	pub fn play(&self) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.play())
+	}
+
+	/// See [`Engine::pause`].
+	pub fn pause(&self) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.pause())
+	}
+
+	/// See [`Engine::next`].
+	pub fn next(&self) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.next())
+	}
+
+	/// See [`Engine::previous`].
+	pub fn previous(&self) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.previous())
+	}
+
+	/// See [`Engine::stop`].
+	pub fn stop(&self) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.stop())
+	}
+
+	/// See [`Engine::clear`].
+	pub fn clear(&self, clear: Clear) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.clear(clear))
+	}
+
+	/// See [`Engine::restore`].
+	pub fn restore(&self, restore: impl Into<Restore<Extra>>) -> Reply<Result<AudioStateSnapshot<Extra>, RestoreError>> {
+		let restore = restore.into();
+		call!(self, |handle| handle.restore(restore))
+	}
+
+	/// See [`Engine::repeat`].
+	pub fn repeat(&self, repeat: Repeat) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.repeat(repeat))
+	}
+
+	/// See [`Engine::volume`].
+	pub fn volume(&self, volume: Volume) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.volume(volume))
+	}
+
+	/// See [`Engine::shuffle`].
+	pub fn shuffle(&self, shuffle: Shuffle) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.shuffle(shuffle))
+	}
+
+	/// See [`Engine::add`].
+	pub fn add(&self, add: Add<Extra>) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.add(add))
+	}
+
+	/// See [`Engine::add_many`].
+	pub fn add_many(&self, add_many: AddMany<Extra>) -> Reply<AudioStateSnapshot<Extra>> {
+		call!(self, |handle| handle.add_many(add_many))
+	}
+
+	/// See [`Engine::seek`].
+	pub fn seek(&self, seek: Seek) -> Reply<Result<AudioStateSnapshot<Extra>, SeekError>> {
+		call!(self, |handle| handle.seek(seek))
+	}
+
+	/// See [`Engine::skip`].
+	pub fn skip(&self, skip: Skip) -> Reply<Result<AudioStateSnapshot<Extra>, SkipError>> {
+		call!(self, |handle| handle.skip(skip))
+	}
+
+	/// See [`Engine::back`].
+	pub fn back(&self, back: Back) -> Reply<Result<AudioStateSnapshot<Extra>, BackError>> {
+		call!(self, |handle| handle.back(back))
+	}
+
+	/// See [`Engine::set_index`].
+	pub fn set_index(&self, set_index: SetIndex) -> Reply<Result<AudioStateSnapshot<Extra>, SetIndexError>> {
+		call!(self, |handle| handle.set_index(set_index))
+	}
+
+	/// See [`Engine::remove`].
+	pub fn remove(&self, remove: Remove) -> Reply<Result<AudioStateSnapshot<Extra>, RemoveError>> {
+		call!(self, |handle| handle.remove(remove))
+	}
+
+	/// See [`Engine::remove_range`].
+	pub fn remove_range(
+		&self,
+		remove_range: impl std::ops::RangeBounds<usize> + Send + 'static,
+	) -> Reply<Result<AudioStateSnapshot<Extra>, RemoveError>> {
+		call!(self, |handle| handle.remove_range(remove_range))
+	}
+
+	/// See [`Engine::output_device`].
+	pub fn output_device(&self, device: Option<String>) -> Reply<Result<(), OutputError>> {
+		call!(self, |handle| handle.output_device(device))
+	}
+
+	/// See [`Engine::output_latency`].
+	pub fn output_latency(&self, latency: Latency) -> Reply<Result<(), OutputError>> {
+		call!(self, |handle| handle.output_latency(latency))
+	}
+
+	/// See [`Engine::apply`].
+	pub fn apply(&self, signal: Signal<Extra>) -> Reply<Response<Extra>> {
+		call!(self, |handle| handle.apply(signal))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Reply
+/// The state shared between a [`Reply`] and its call thread.
+#[derive(Debug)]
+struct Oneshot<T> {
+	/// The output, once it's ready.
+	value: Option<T>,
+	/// The task waiting on the output.
+	waker: Option<Waker>,
+	/// Was the call dropped without an output (the call thread panicked)?
+	dropped: bool,
+}
+
+/// The sending half of a [`Reply`].
+struct ReplySender<T>(Arc<Mutex<Oneshot<T>>>);
+
+impl<T> ReplySender<T> {
+	/// Set the output, and wake the task.
+	fn set(self, value: T) {
+		let mut oneshot = self.0.lock().unwrap();
+		oneshot.value = Some(value);
+		if let Some(waker) = oneshot.waker.take() {
+			waker.wake();
+		}
+	}
+}
+
+impl<T> Drop for ReplySender<T> {
+	fn drop(&mut self) {
+		// Only happens if `set()` wasn't called.
+		if let Ok(mut oneshot) = self.0.lock() {
+			if oneshot.value.is_none() {
+				oneshot.dropped = true;
+				if let Some(waker) = oneshot.waker.take() {
+					waker.wake();
+				}
+			}
+		}
+	}
+}
+
+/// A future that resolves to the output of an [`AsyncEngine`] call
+///
+/// The call is sent to the `Engine` immediately, even
+/// if this is never polled (or dropped before completing).
+///
+/// # Panics
+/// This panics when polled if the call thread panicked.
+#[derive(Debug)]
+pub struct Reply<T>(Arc<Mutex<Oneshot<T>>>);
+
+impl<T> Reply<T> {
+	/// Create a [`Reply`] and its sender.
+	fn new() -> (ReplySender<T>, Self) {
+		let oneshot = Arc::new(Mutex::new(Oneshot {
+			value: None,
+			waker: None,
+			dropped: false,
+		}));
+		(ReplySender(Arc::clone(&oneshot)), Self(oneshot))
+	}
+}
+
+impl<T> Future for Reply<T> {
+	type Output = T;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+		let mut oneshot = self.0.lock().unwrap();
+
+		if let Some(value) = oneshot.value.take() {
+			return Poll::Ready(value);
+		}
+
+		assert!(!oneshot.dropped, "AsyncEngine - the call thread panicked");
+
+		oneshot.waker = Some(cx.waker().clone());
+		Poll::Pending
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EventStream
+/// The [`Event`]'s shared between an [`EventStream`] and `Caller`.
+struct EventQueue<Extra: ExtraData> {
+	/// Events not yet received.
+	events: VecDeque<Event<Extra>>,
+	/// The task waiting on the next event.
+	waker: Option<Waker>,
+	/// No more events will be pushed.
+	closed: bool,
+}
+
+/// The `Caller` side of an [`EventStream`].
+struct EventSender<Extra: ExtraData>(Arc<Mutex<EventQueue<Extra>>>);
+
+impl<Extra: ExtraData> EventSender<Extra> {
+	/// Push an event, returns `false` if the [`EventStream`] was dropped.
+	fn push(&self, event: Event<Extra>) -> bool {
+		if Arc::strong_count(&self.0) == 1 {
+			return false;
+		}
+
+		let mut queue = self.0.lock().unwrap();
+		if event.kind() == EventKind::Shutdown {
+			queue.closed = true;
+		}
+		queue.events.push_back(event);
+		if let Some(waker) = queue.waker.take() {
+			waker.wake();
+		}
+		true
+	}
+}
+
+impl<Extra: ExtraData> Drop for EventSender<Extra> {
+	fn drop(&mut self) {
+		if let Ok(mut queue) = self.0.lock() {
+			queue.closed = true;
+			if let Some(waker) = queue.waker.take() {
+				waker.wake();
+			}
+		}
+	}
+}
+
+/// An async stream of [`Event`]'s
+///
+/// This is the async version of the channel returned by [`Engine::subscribe`],
+/// see [`AsyncEngine::subscribe`]. It implements `futures_core::Stream`, and
+/// can also be used directly with [`EventStream::recv`].
+///
+/// The stream ends once the [`Engine`] shuts down, i.e. once all
+/// [`AsyncEngine`]'s have been dropped, after yielding
+/// [`Event::Shutdown`] (if it passed the filter).
+pub struct EventStream<Extra: ExtraData>(Arc<Mutex<EventQueue<Extra>>>);
+
+impl<Extra: ExtraData> EventStream<Extra> {
+	/// Receive the next [`Event`], or `None` if the stream has ended.
+	pub async fn recv(&mut self) -> Option<Event<Extra>> {
+		std::future::poll_fn(|cx| self.poll_recv(cx)).await
+	}
+
+	/// Poll for the next [`Event`].
+	fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<Event<Extra>>> {
+		let mut queue = self.0.lock().unwrap();
+
+		if let Some(event) = queue.events.pop_front() {
+			return Poll::Ready(Some(event));
+		}
+
+		if queue.closed {
+			return Poll::Ready(None);
+		}
+
+		queue.waker = Some(cx.waker().clone());
+		Poll::Pending
+	}
+}
+
+impl<Extra: ExtraData> futures_core::Stream for EventStream<Extra> {
+	type Item = Event<Extra>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event<Extra>>> {
+		self.poll_recv(cx)
+	}
+}
+
+impl<Extra: ExtraData> std::fmt::Debug for EventStream<Extra> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("EventStream").finish_non_exhaustive()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Engine Impl
+impl<Extra: ExtraData> Engine<Extra> {
+	#[must_use]
+	/// Convert this [`Engine`] into an [`AsyncEngine`].
+	///
+	/// Same as [`AsyncEngine::new`].
+	pub fn into_async(self) -> AsyncEngine<Extra> {
+		AsyncEngine::new(self)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::signal::AddMethod;
+	use pretty_assertions::assert_eq;
+	use std::{
+		task::Wake,
+		thread::Thread,
+	};
+
+	/// Wakes the thread that is blocking on a future.
+	struct ThreadWaker(Thread);
+
+	impl Wake for ThreadWaker {
+		fn wake(self: Arc<Self>) {
+			self.0.unpark();
+		}
+	}
+
+	/// A minimal executor, the `AsyncEngine` doesn't depend on any runtime.
+	fn block_on<F: Future>(future: F) -> F::Output {
+		let mut future = std::pin::pin!(future);
+		let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+		let mut cx = Context::from_waker(&waker);
+		loop {
+			match future.as_mut().poll(&mut cx) {
+				Poll::Ready(output) => return output,
+				Poll::Pending => std::thread::park(),
+			}
+		}
+	}
+
+	#[test]
+	fn async_engine() {
+		let engine = crate::tests::init().into_async();
+		let mut events = block_on(engine.subscribe(EventKind::VolumeChanged.into()));
+
+		// Clones control the same `Engine`.
+		let clone = engine.clone();
+		let audio_state = block_on(clone.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear: false,
+			play: false,
+		}));
+		assert_eq!(audio_state.queue.len(), 1);
+		assert_eq!(engine.reader().get().queue.len(), 1);
+
+		let audio_state = block_on(engine.volume(Volume::new(0.5)));
+		assert_eq!(audio_state.volume, Volume::new(0.5));
+
+		match block_on(events.recv()) {
+			Some(Event::VolumeChanged(volume)) => assert_eq!(volume, Volume::new(0.5)),
+			event => panic!("unexpected event: {event:?}"),
+		}
+
+		// Errors are returned as usual.
+		assert!(block_on(engine.set_index(SetIndex { index: 10, start_playing: false })).is_err());
+
+		// Calls don't wait on each other.
+		let replies = [engine.repeat(Repeat::Queue), clone.repeat(Repeat::Queue)];
+		for reply in replies {
+			assert_eq!(block_on(reply).repeat, Repeat::Queue);
+		}
+
+		let response = block_on(engine.apply(Signal::Volume(Volume::new(0.25))));
+		assert!(matches!(response, Response::AudioState(audio_state) if audio_state.volume == Volume::new(0.25)));
+		match block_on(events.recv()) {
+			Some(Event::VolumeChanged(volume)) => assert_eq!(volume, Volume::new(0.25)),
+			event => panic!("unexpected event: {event:?}"),
+		}
+
+		// Streams end once the `Engine` shuts down.
+		let mut shutdown = block_on(engine.subscribe(EventKind::Shutdown.into()));
+		drop((engine, clone));
+		assert!(matches!(block_on(shutdown.recv()), Some(Event::Shutdown)));
+		assert!(block_on(shutdown.recv()).is_none());
+		assert!(block_on(events.recv()).is_none());
+	}
+}
@@ -2,6 +2,7 @@
 
 //---------------------------------------------------------------------------------------------------- Use
 use crate::{
+	engine::{Autosave,EngineHandle},
 	extra_data::ExtraData,
 	macros::{recv,try_send,debug2,info2},
 	state::{
@@ -13,12 +14,11 @@ use crate::{
 	config::{
 		InitConfig,
 		Callbacks,
-		RuntimeConfig
 	},
 	actor::{
 		audio::{Audio,AUDIO_BUFFER_LEN},
 		decode::Decode,
-		kernel::Kernel,
+		kernel::{Kernel,OutputRequest},
 		gc::Gc,
 		caller::Caller,
 	},
@@ -27,7 +27,9 @@ use crate::{
 		Repeat,Seek,SetIndex,Shuffle,Skip,Volume,AddMethod,
 		SeekError,Next,PreviousError,SkipError,
 		BackError,SetIndexError,RemoveError, BackThreshold,
-	}
+	},
+	error::OutputError,
+	output::Pull,
 };
 use crossbeam::channel::{bounded,unbounded};
 use std::sync::{
@@ -55,13 +57,16 @@ use crate::resampler::ResamplerStruct;
 pub struct Engine<Extra: ExtraData> {
 	/// Data and objects.
 	pub(super) reader: AudioStateReader<Extra>,
-	pub(super) config: RuntimeConfig,
 	pub(super) atomic_state: Arc<AtomicState>,
 	pub(super) shutdown_blocking: bool,
 
-	// Internal mirrored state.
-	pub(super) repeat: Repeat,
-	pub(super) volume: Volume,
+	/// The host pulls audio through this instead of the
+	/// `Audio` actor (if `InitConfig::pull` was set).
+	pub(super) pull: Option<Pull>,
+
+	/// Saves the `Session` on an interval and/or on drop
+	/// (if `InitConfig::session` was set).
+	pub(super) autosave: Option<Autosave<Extra>>,
 
 	/// Signal to [Kernel] to tell all of our internal
 	/// actors (threads) to start shutting down.
@@ -73,42 +78,8 @@ pub struct Engine<Extra: ExtraData> {
 	/// process has been completed.
 	pub(super) shutdown_done: R<()>,
 
-	/// This channel is shared between all signals that don't
-	/// have special output, i.e, they return `AudioStateSnapshot`.
-	pub(super) recv_audio_state: R<AudioStateSnapshot<Extra>>,
-
-	/// Signals that have no input and output `AudioStateSnapshot`
-	pub(super) send_toggle:   S<()>,
-	pub(super) send_play:     S<()>,
-	pub(super) send_pause:    S<()>,
-	pub(super) send_next:     S<()>,
-	pub(super) send_previous: S<()>,
-	pub(super) send_stop:     S<()>,
-
-	/// Signals that have input and output `AudioStateSnapshot`.
-	pub(super) send_add:       S<Add<Extra>>,
-	pub(super) send_add_many:  S<AddMany<Extra>>,
-	pub(super) send_clear:     S<Clear>,
-	pub(super) send_restore:   S<AudioState<Extra>>,
-	pub(super) send_repeat:    S<Repeat>,
-	pub(super) send_volume:    S<Volume>,
-	pub(super) send_shuffle:   S<Shuffle>,
-
-	/// Signals that return `Result<T, E>`
-	/// These don't use the common `recv_audio_state_snapshot`,
-	/// as they return unique values.
-	pub(super) send_seek:         S<Seek>,
-	pub(super) recv_seek:         R<Result<AudioStateSnapshot<Extra>, SeekError>>,
-	pub(super) send_skip:         S<Skip>,
-	pub(super) recv_skip:         R<Result<AudioStateSnapshot<Extra>, SkipError>>,
-	pub(super) send_back:         S<Back>,
-	pub(super) recv_back:         R<Result<AudioStateSnapshot<Extra>, BackError>>,
-	pub(super) send_set_index:    S<SetIndex>,
-	pub(super) recv_set_index:    R<Result<AudioStateSnapshot<Extra>, SetIndexError>>,
-	pub(super) send_remove:       S<Remove>,
-	pub(super) recv_remove:       R<Result<AudioStateSnapshot<Extra>, RemoveError>>,
-	pub(super) send_remove_range: S<RemoveRange>,
-	pub(super) recv_remove_range: R<Result<AudioStateSnapshot<Extra>, RemoveError>>,
+	/// The handle all signals are sent through.
+	pub(super) handle: EngineHandle<Extra>,
 }
 
 //---------------------------------------------------------------------------------------------------- Tests
@@ -4,18 +4,21 @@
 use crate::{
 	engine::{Engine},
 	extra_data::ExtraData,
-	macros::{recv,try_send,debug2,info2},
+	macros::{debug2,info2},
 	state::{
 		AudioStateSnapshot,
 		AudioStateReader,
 		AudioState,
 	},
 	config::RuntimeConfig,
+	error::OutputError,
+	output::{OutputBackend,Pull,SampleFormat,Latency},
 	signal::{
 		Add,AddMany,Back,Clear,Previous,RemoveRange,Remove,
 		Repeat,Seek,SetIndex,Shuffle,Skip,Volume,AddMethod,
 		SeekError,Next,PreviousError,SkipError,
 		BackError,SetIndexError,RemoveError, BackThreshold,
+		Restore,RestoreError,
 	}
 };
 
@@ -30,31 +33,25 @@ impl<Extra: ExtraData> Engine<Extra> {
 	}
 
 	//---------------------------------------------------------------------------------------------------- Config
-	#[inline]
 	#[must_use]
-	/// TODO
-	pub const fn config(&self) -> &RuntimeConfig {
-		&self.config
+	/// Returns the current [`RuntimeConfig`].
+	pub fn config(&self) -> RuntimeConfig {
+		self.handle.config()
 	}
 
-	/// TODO
-	pub fn config_update<F>(&mut self, mut f: F)
+	/// Update the [`RuntimeConfig`] with `f`.
+	///
+	/// See [`EngineHandle::config_update`](crate::EngineHandle::config_update).
+	pub fn config_update<F>(&mut self, f: F)
 	where
 		F: FnMut(&mut RuntimeConfig)
 	{
-		// Update the config.
-		f(&mut self.config);
-		// Update the atomic version so the other actors see it.
-		self.atomic_state.update_from_config(&self.config);
+		self.handle.config_update(f);
 	}
 
 	//---------------------------------------------------------------------------------------------------- Signals
-	// INVARIANT: The `Engine`'s channel <-> return system
-	// relies on the fact that only 1 thread is `.recv()`'ing
-	// at any given moment, `&mut self` ensures this mutual exclusion.
-	//
-	// There is no "routing" so-to-speak so we must
-	// ensure the caller also `.recv()`'s the return value.
+	// These all forward to the `EngineHandle`, each signal
+	// carries its own response channel, see `EngineHandle`.
 	//
 	// SAFETY: The [Kernel] should always be listening.
 	// it is a logic error for [send()] or [recv()] to panic,
@@ -78,71 +75,67 @@ impl<Extra: ExtraData> Engine<Extra> {
 
 	/// TODO
 	pub fn toggle(&mut self) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_toggle, ());
-		recv!(self.recv_audio_state)
+		self.handle.toggle()
 	}
 
 	/// TODO
 	pub fn play(&mut self) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_play, ());
-		recv!(self.recv_audio_state)
+		self.handle.play()
 	}
 
 	/// TODO
 	pub fn pause(&mut self) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_pause, ());
-		recv!(self.recv_audio_state)
+		self.handle.pause()
 	}
 
 	#[allow(clippy::should_implement_trait)]
 	/// TODO
 	pub fn next(&mut self) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_next, ());
-		recv!(self.recv_audio_state)
+		self.handle.next()
 	}
 
 	/// TODO
 	pub fn previous(&mut self) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_previous, ());
-		recv!(self.recv_audio_state)
+		self.handle.previous()
 	}
 
 	/// TODO
 	pub fn stop(&mut self) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_stop, ());
-		recv!(self.recv_audio_state)
+		self.handle.stop()
 	}
 
 	/// TODO
 	pub fn clear(&mut self, clear: Clear) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_clear, clear);
-		recv!(self.recv_audio_state)
+		self.handle.clear(clear)
 	}
 
-	/// TODO
-	pub fn restore(&mut self, audio_state: AudioState<Extra>) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_restore, audio_state);
-		recv!(self.recv_audio_state)
+	/// Restore an [`AudioState`].
+	///
+	/// This accepts a plain [`AudioState`] (which will not be repaired),
+	/// or a [`Restore`] to select which [`Repair`](crate::signal::Repair)'s to apply.
+	///
+	/// This blocks while the `AudioState` is validated, see [`Restore`].
+	///
+	/// # Errors
+	/// If the `AudioState` is invalid and was not repaired, a
+	/// [`RestoreError`] is returned and nothing is restored.
+	pub fn restore(&mut self, restore: impl Into<Restore<Extra>>) -> Result<AudioStateSnapshot<Extra>, RestoreError> {
+		self.handle.restore(restore)
 	}
 
 	/// TODO
 	pub fn repeat(&mut self, repeat: Repeat) -> AudioStateSnapshot<Extra> {
-		self.repeat = repeat;
-		try_send!(self.send_repeat, repeat);
-		recv!(self.recv_audio_state)
+		self.handle.repeat(repeat)
 	}
 
 	/// TODO
 	pub fn volume(&mut self, volume: Volume) -> AudioStateSnapshot<Extra> {
-		self.volume = volume;
-		try_send!(self.send_volume, volume);
-		recv!(self.recv_audio_state)
+		self.handle.volume(volume)
 	}
 
 	/// TODO
 	pub fn shuffle(&mut self, shuffle: Shuffle) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_shuffle, shuffle);
-		recv!(self.recv_audio_state)
+		self.handle.shuffle(shuffle)
 	}
 
 	/// TODO
@@ -150,8 +143,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn add(&mut self, add: Add<Extra>) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_add, add);
-		recv!(self.recv_audio_state)
+		self.handle.add(add)
 	}
 
 	/// TODO
@@ -159,8 +151,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn add_many(&mut self, add_many: AddMany<Extra>) -> AudioStateSnapshot<Extra> {
-		try_send!(self.send_add_many, add_many);
-		recv!(self.recv_audio_state)
+		self.handle.add_many(add_many)
 	}
 
 	/// TODO
@@ -168,8 +159,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn seek(&mut self, seek: Seek) -> Result<AudioStateSnapshot<Extra>, SeekError> {
-		try_send!(self.send_seek, seek);
-		recv!(self.recv_seek)
+		self.handle.seek(seek)
 	}
 
 	/// TODO
@@ -177,8 +167,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn skip(&mut self, skip: Skip) -> Result<AudioStateSnapshot<Extra>, SkipError> {
-		try_send!(self.send_skip, skip);
-		recv!(self.recv_skip)
+		self.handle.skip(skip)
 	}
 
 	/// TODO
@@ -186,8 +175,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn back(&mut self, back: Back) -> Result<AudioStateSnapshot<Extra>, BackError> {
-		try_send!(self.send_back, back);
-		recv!(self.recv_back)
+		self.handle.back(back)
 	}
 
 	/// TODO
@@ -195,8 +183,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn set_index(&mut self, set_index: SetIndex) -> Result<AudioStateSnapshot<Extra>, SetIndexError> {
-		try_send!(self.send_set_index, set_index);
-		recv!(self.recv_set_index)
+		self.handle.set_index(set_index)
 	}
 
 	/// TODO
@@ -204,8 +191,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn remove(&mut self, remove: Remove) -> Result<AudioStateSnapshot<Extra>, RemoveError> {
-		try_send!(self.send_remove, remove);
-		recv!(self.recv_remove)
+		self.handle.remove(remove)
 	}
 
 	/// TODO
@@ -213,11 +199,311 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// # Errors
 	/// TODO
 	pub fn remove_range(&mut self, remove_range: impl std::ops::RangeBounds<usize>) -> Result<AudioStateSnapshot<Extra>, RemoveError> {
-		try_send!(self.send_remove_range, remove_range.into());
-		recv!(self.recv_remove_range)
+		self.handle.remove_range(remove_range)
+	}
+
+	//---------------------------------------------------------------------------------------------------- Output
+	#[must_use]
+	/// Which audio output backend is currently being used?
+	///
+	/// This returns `None` if no audio output has been opened yet, see
+	/// [`InitConfig::output_backends`](crate::config::InitConfig::output_backends).
+	pub fn output_backend(&self) -> Option<OutputBackend> {
+		self.atomic_state.output_backend.load()
+	}
+
+	#[must_use]
+	/// Which sample format is the audio output currently using?
+	///
+	/// This returns `None` if no audio output has been opened yet, see
+	/// [`InitConfig::sample_format`](crate::config::InitConfig::sample_format).
+	pub fn output_format(&self) -> Option<SampleFormat> {
+		self.atomic_state.output_format.load()
+	}
+
+	#[must_use]
+	/// The latency actually negotiated with the audio output device.
+	///
+	/// This is how long audio takes from being written by `sansan`
+	/// to being played by the device, which may differ from the
+	/// [`Latency`] requested, depending on what the device allows.
+	///
+	/// This returns `None` if no audio output has been opened yet,
+	/// or if the output cannot report its latency (e.g. a custom
+	/// [`Sink`](crate::output::Sink) or [`InitConfig::pull`](crate::config::InitConfig::pull)).
+	pub fn latency(&self) -> Option<std::time::Duration> {
+		self.atomic_state
+			.output_latency
+			.load()
+			.map(|micros| std::time::Duration::from_micros(u64::from(micros)))
+	}
+
+	#[must_use]
+	/// The scheduling priority of the audio threads.
+	///
+	/// This reports whether the real-time promotion requested with
+	/// [`InitConfig::realtime`](crate::config::InitConfig::realtime) succeeded.
+	///
+	/// The audio thread promotes itself while initializing, so without
+	/// [`InitConfig::init_blocking`](crate::config::InitConfig::init_blocking)
+	/// this may still be [`Priority::Normal`](crate::state::Priority::Normal)
+	/// for a short moment after [`Engine::init`].
+	///
+	/// The decoding thread is promoted once the first [`Source`](crate::source::Source)
+	/// is set, as that is when the sample rate is known, until then it is
+	/// [`Priority::Normal`](crate::state::Priority::Normal).
+	///
+	/// Both threads are re-promoted when the sample rate or latency
+	/// they were promoted with changes, so this may change over time.
+	pub fn priorities(&self) -> crate::state::Priorities {
+		crate::state::Priorities {
+			audio: self.atomic_state.priority_audio.load(),
+			decode: self.atomic_state.priority_decode.load(),
+		}
+	}
+
+	#[must_use]
+	#[cfg(feature = "stats")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+	/// Returns a snapshot of the `Engine`'s internal metrics.
+	///
+	/// This includes buffer fill levels, decode throughput,
+	/// signal latency, message counts, etc, see [`Stats`](crate::state::Stats).
+	///
+	/// This is cheap (a handful of atomic loads) and
+	/// can be called as often as needed, e.g. every frame
+	/// of a diagnostics overlay, or periodically for logging.
+	///
+	/// Without the `stats` feature, none of these metrics are
+	/// collected and this function does not exist.
+	pub fn stats(&self) -> crate::state::Stats {
+		self.atomic_state.stats.snapshot()
+	}
+
+	#[must_use]
+	#[cfg(feature = "media_controls")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "media_controls")))]
+	/// Are the OS media controls active?
+	///
+	/// This is `false` if [`InitConfig::media_controls`](crate::config::InitConfig::media_controls)
+	/// was `None`, or if the media controls could not be created (e.g. there
+	/// is no D-Bus session bus on Linux), see [`MediaControls`](crate::config::MediaControls).
+	///
+	/// Without [`InitConfig::init_blocking`](crate::config::InitConfig::init_blocking),
+	/// this may be `false` for a short moment after [`Engine::init`].
+	pub fn media_controls(&self) -> bool {
+		self.atomic_state.media_controls.load(std::sync::atomic::Ordering::Acquire)
+	}
+
+	/// Switch the audio output device.
+	///
+	/// `device` is an [`OutputDevice::id`](crate::output::OutputDevice::id)
+	/// from [`devices()`](crate::output::devices), `None` switches to
+	/// the system's default output device.
+	///
+	/// Any audio already buffered for the old device is discarded,
+	/// playback (if any) continues on the new device.
+	///
+	/// # Errors
+	/// If the new device could not be opened, the old device
+	/// continues to be used and the error is returned.
+	///
+	/// [`OutputError::BackendUnavailable`] is always returned if
+	/// [`InitConfig::pull`](crate::config::InitConfig::pull) was set.
+	pub fn output_device(&mut self, device: Option<String>) -> Result<(), OutputError> {
+		self.handle.output_device(device)
+	}
+
+	/// Change the audio output latency.
+	///
+	/// This re-opens the current output device with `latency`, the
+	/// latency actually negotiated can be checked with [`Engine::latency`].
+	///
+	/// Any audio already buffered is discarded,
+	/// playback (if any) continues after re-opening.
+	///
+	/// # Errors
+	/// If the device could not be re-opened with `latency`, the
+	/// old output continues to be used and the error is returned.
+	///
+	/// [`OutputError::BackendUnavailable`] is always returned if
+	/// [`InitConfig::pull`](crate::config::InitConfig::pull) was set
+	/// or a custom [`Sink`](crate::output::Sink) is being used.
+	pub fn output_latency(&mut self, latency: Latency) -> Result<(), OutputError> {
+		self.handle.output_latency(latency)
+	}
+
+	//---------------------------------------------------------------------------------------------------- Pull
+	/// Pull audio into `out`, see [`Pull::render`].
+	///
+	/// This is only for when [`InitConfig::pull`](crate::config::InitConfig::pull)
+	/// is set, else, `out` is filled with silence and `0` is returned.
+	///
+	/// This also returns `0` (silence) if the [`Pull`]
+	/// was already taken out with [`Engine::take_pull`].
+	pub fn render(&mut self, out: &mut [f32], frames: usize) -> usize {
+		if let Some(pull) = self.pull.as_mut() {
+			pull.render(out, frames)
+		} else {
+			out.fill(0.0);
+			0
+		}
+	}
+
+	/// Take the [`Pull`] out of the `Engine`.
+	///
+	/// This allows moving it into the host's audio callback,
+	/// while the `Engine` is used elsewhere for control.
+	///
+	/// This returns `None` if [`InitConfig::pull`](crate::config::InitConfig::pull)
+	/// was not set, or if it was already taken.
+	pub fn take_pull(&mut self) -> Option<Pull> {
+		self.pull.take()
 	}
 }
 
 //---------------------------------------------------------------------------------------------------- Tests
 #[cfg(test)]
-mod tests {}
\ No newline at end of file
+mod tests {
+	use super::*;
+	use crate::{
+		config::InitConfig,
+		render::RenderSpec,
+		signal::{AddMethod,Add},
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	// Threads must only be promoted if requested, and always report a result if so.
+	fn priorities() {
+		use crate::{config::Realtime, state::{Priority,Priorities}};
+
+		let engine = crate::tests::init_with_config(InitConfig {
+			realtime: Realtime::Off,
+			init_blocking: true,
+			..InitConfig::DEFAULT
+		});
+		assert_eq!(engine.priorities(), Priorities::default());
+		drop(engine);
+
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			realtime: Realtime::AudioAndDecode,
+			init_blocking: true,
+			..InitConfig::DEFAULT
+		});
+		assert_ne!(engine.priorities().audio, Priority::Normal);
+		// `Decode` waits for the sample rate of the first source.
+		assert_eq!(engine.priorities().decode, Priority::Normal);
+
+		engine.add(crate::signal::Add {
+			source: crate::tests::source(0),
+			method: crate::signal::AddMethod::Back,
+			clear: false,
+			play: false,
+		});
+		let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+		while engine.priorities().decode == Priority::Normal {
+			assert!(std::time::Instant::now() < deadline, "decode was never promoted");
+			std::thread::yield_now();
+		}
+	}
+
+	#[test]
+	// `InitConfig::resampler_custom` is used, even with the dummy output.
+	fn resampler_custom() {
+		use crate::resampler::{Resample,ResamplerSpec};
+		use std::sync::atomic::{AtomicUsize,Ordering};
+
+		static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+		/// Outputs nothing, counts calls.
+		struct Count;
+		impl Resample for Count {
+			fn resample(&mut self, _: &[&[f32]], _: &mut Vec<f32>) {
+				CALLS.fetch_add(1, Ordering::Relaxed);
+			}
+		}
+		fn count(spec: ResamplerSpec) -> Box<dyn Resample> {
+			assert_eq!(spec.sample_rate_target, 96_000);
+			Box::new(Count)
+		}
+
+		// The test audio is not 96kHz, so it must be resampled.
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			pull: Some(RenderSpec { sample_rate: 96_000, channels: 2 }),
+			resampler_custom: Some(count),
+			..InitConfig::DEFAULT
+		});
+		engine.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear:  false,
+			play:   true,
+		});
+
+		let mut out = vec![0.0; 1024 * 2];
+		for _ in 0..1000 {
+			engine.render(&mut out, 1024);
+			if CALLS.load(Ordering::Relaxed) > 0 {
+				break;
+			}
+			std::thread::sleep(std::time::Duration::from_millis(1));
+		}
+		assert!(CALLS.load(Ordering::Relaxed) > 0);
+	}
+
+	#[test]
+	// `InitConfig::pull` should let the host drive playback.
+	fn pull() {
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			pull: Some(RenderSpec { sample_rate: 48_000, channels: 2 }),
+			..InitConfig::DEFAULT
+		});
+
+		let mut out = vec![1.0; 1024 * 2];
+
+		// Nothing is playing, silence.
+		assert_eq!(engine.render(&mut out, 1024), 0);
+		assert!(out.iter().all(|s| *s == 0.0));
+
+		engine.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear:  false,
+			play:   true,
+		});
+
+		// Keep pulling until `Decode` catches up, and
+		// make sure the elapsed time is driven by it.
+		let mut frames = 0;
+		for _ in 0..1000 {
+			frames += engine.render(&mut out, 1024);
+			if frames >= 48_000 {
+				break;
+			}
+			std::thread::sleep(std::time::Duration::from_millis(1));
+		}
+		assert!(frames >= 48_000);
+
+		// No output devices exist in pull mode.
+		assert!(matches!(engine.output_device(None), Err(OutputError::BackendUnavailable)));
+
+		// The pull can be taken out (once).
+		assert!(engine.take_pull().is_some());
+		assert!(engine.take_pull().is_none());
+		assert_eq!(engine.render(&mut out, 1024), 0);
+
+		// The `Pull` was dropped, signals that `Kernel`
+		// forwards to it must not panic any actors.
+		engine.pause();
+		engine.play();
+		engine.stop();
+		engine.add(Add {
+			source: crate::tests::source(1),
+			method: AddMethod::Back,
+			clear:  true,
+			play:   true,
+		});
+		assert!(engine.reader().get().playing);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,409 @@
+//! Cloneable `Engine` control handle.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	actor::{
+		caller::{Subscribe,Subscriber},
+		kernel::{OutputRequest,Request},
+	},
+	engine::Engine,
+	extra_data::ExtraData,
+	event::{Event,EventFilter},
+	macros::{recv,send,stats},
+	state::{
+		AtomicState,
+		AudioState,
+		AudioStateReader,
+		AudioStateSnapshot,
+	},
+	config::RuntimeConfig,
+	error::OutputError,
+	output::Latency,
+	signal::{
+		Add,AddMany,Back,Clear,RemoveRange,Remove,
+		Repeat,Seek,SetIndex,Shuffle,Skip,Volume,
+		SeekError,SkipError,BackError,SetIndexError,RemoveError,
+		Restore,RestoreError,SendSignal,
+	},
+};
+use crossbeam::channel::{bounded,unbounded,Receiver};
+use std::sync::Arc;
+
+// Prevent collision with [S] generic.
+use crossbeam::channel::Sender as S;
+
+//---------------------------------------------------------------------------------------------------- Macros
+/// Send a signal to `Kernel` along with a fresh
+/// response channel, and wait for its response.
+///
+/// With the `stats` feature, this also records
+/// the round-trip time in `Stats::signal_latency`.
+macro_rules! signal {
+	($self:ident, $send:ident, $msg:expr) => {{
+		#[cfg(feature = "stats")]
+		let start = std::time::Instant::now();
+		let (to_engine, response) = bounded(1);
+		// Other handles may be sending at the same
+		// time, so this must block, not `try_send`.
+		send!($self.$send, ($msg, to_engine));
+		let response = recv!(response);
+		stats! {
+			crate::state::AtomicStats::store_duration(&$self.atomic_state.stats.signal_micros, start.elapsed());
+		}
+		response
+	}};
+}
+
+//---------------------------------------------------------------------------------------------------- EngineHandle
+/// A cloneable, thread-safe handle to control the [`Engine`]
+///
+/// This is created with [`Engine::handle`], and has the same signal methods as the
+/// [`Engine`], although they take `&self`, and the handle is `Clone + Send + Sync`.
+///
+/// This allows many threads (e.g. a GUI, media key handler, and network server)
+/// to control the same [`Engine`] at the same time, without an external mutex.
+///
+/// ## Ordering
+/// Each signal carries its own response channel, so a handle only ever
+/// receives the response to its own signal. The guarantees are:
+///
+/// - `Kernel` handles signals one at a time, so each signal is atomic,
+///   no other signal is applied "in the middle" of another one
+/// - The [`AudioStateSnapshot`] returned is the state right after _that_ signal
+///   was applied, and before any signal that `Kernel` handles afterwards
+/// - Signals sent from the same thread are applied in the order
+///   they were sent, as each method waits for its response
+/// - Signals sent concurrently from different threads have no defined order
+///   relative to each other, the response's [`AudioStateSnapshot`] is the
+///   only way to know what state the signal was applied to
+/// - Signals sent with [`EngineHandle::send`] are applied before any
+///   waiting signal sent afterwards, see [`EngineSend`](crate::EngineSend)
+/// - [`Event`]'s are emitted in the order `Kernel` applied the signals
+#[derive(Clone,Debug)]
+pub struct EngineHandle<Extra: ExtraData> {
+	/// The `Engine`'s audio state.
+	pub(super) reader: AudioStateReader<Extra>,
+	/// Shared atomic state.
+	pub(super) atomic_state: Arc<AtomicState>,
+	/// Was `InitConfig::pull` set?
+	pub(super) pull_mode: bool,
+
+	/// New `Event` subscribers, sent to `Caller`.
+	pub(super) send_subscribe: S<Subscribe<Extra>>,
+
+	/// Signals that have no input and output `AudioStateSnapshot`
+	pub(super) send_toggle:   S<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(super) send_play:     S<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(super) send_pause:    S<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(super) send_next:     S<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(super) send_previous: S<Request<(), AudioStateSnapshot<Extra>>>,
+	pub(super) send_stop:     S<Request<(), AudioStateSnapshot<Extra>>>,
+
+	/// Signals that have input and output `AudioStateSnapshot`.
+	pub(super) send_add:      S<Request<Add<Extra>, AudioStateSnapshot<Extra>>>,
+	pub(super) send_add_many: S<Request<AddMany<Extra>, AudioStateSnapshot<Extra>>>,
+	pub(super) send_clear:    S<Request<Clear, AudioStateSnapshot<Extra>>>,
+	pub(super) send_restore:  S<Request<AudioState<Extra>, AudioStateSnapshot<Extra>>>,
+	pub(super) send_repeat:   S<Request<Repeat, AudioStateSnapshot<Extra>>>,
+	pub(super) send_volume:   S<Request<Volume, AudioStateSnapshot<Extra>>>,
+	pub(super) send_shuffle:  S<Request<Shuffle, AudioStateSnapshot<Extra>>>,
+
+	/// Signals that return `Result<T, E>`.
+	pub(super) send_seek:          S<Request<Seek, Result<AudioStateSnapshot<Extra>, SeekError>>>,
+	pub(super) send_skip:          S<Request<Skip, Result<AudioStateSnapshot<Extra>, SkipError>>>,
+	pub(super) send_back:          S<Request<Back, Result<AudioStateSnapshot<Extra>, BackError>>>,
+	pub(super) send_set_index:     S<Request<SetIndex, Result<AudioStateSnapshot<Extra>, SetIndexError>>>,
+	pub(super) send_remove:        S<Request<Remove, Result<AudioStateSnapshot<Extra>, RemoveError>>>,
+	pub(super) send_remove_range:  S<Request<RemoveRange, Result<AudioStateSnapshot<Extra>, RemoveError>>>,
+	pub(super) send_output_device: S<Request<OutputRequest, Result<(), OutputError>>>,
+
+	/// Signals sent with `EngineSend`, these all go through 1 channel.
+	pub(super) send_signal: S<SendSignal<Extra>>,
+}
+
+//---------------------------------------------------------------------------------------------------- EngineHandle Impl
+impl<Extra: ExtraData> EngineHandle<Extra> {
+	#[inline]
+	#[must_use]
+	/// Returns the [`AudioStateReader`], see [`Engine::reader`].
+	pub const fn reader(&self) -> &AudioStateReader<Extra> {
+		&self.reader
+	}
+
+	#[must_use]
+	/// Returns the current [`RuntimeConfig`], see [`Engine::config`].
+	pub fn config(&self) -> RuntimeConfig {
+		self.atomic_state.config()
+	}
+
+	/// Update the [`RuntimeConfig`] with `f`.
+	///
+	/// This takes effect immediately, and is shared by the
+	/// [`Engine`] and all handles. Updates from different
+	/// handles are applied one at a time, so none are lost.
+	///
+	/// If [`InitConfig::session`](crate::config::InitConfig::session)
+	/// is set, the new config is saved along with the [`Session`](crate::state::Session).
+	pub fn config_update<F>(&self, f: F)
+	where
+		F: FnOnce(&mut RuntimeConfig)
+	{
+		self.atomic_state.config_update(f);
+	}
+
+	#[must_use]
+	/// Subscribe to [`Event`]'s, see [`Engine::subscribe`].
+	pub fn subscribe(&self, filter: EventFilter) -> Receiver<Event<Extra>> {
+		let (send, recv) = unbounded();
+		self.subscribe_with(filter, Box::new(move |event| send.send(event).is_ok()));
+		recv
+	}
+
+	/// Register a [`Subscriber`], returning once it is active.
+	pub(crate) fn subscribe_with(&self, filter: EventFilter, subscriber: Subscriber<Extra>) {
+		let (ack_send, ack_recv) = bounded(1);
+
+		// `Caller` only exits on shutdown, so these never fail.
+		send!(self.send_subscribe, (filter, subscriber, ack_send));
+		recv!(ack_recv);
+	}
+
+	/// See [`Engine::toggle`].
+	pub fn toggle(&self) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_toggle, ())
+	}
+
+	/// See [`Engine::play`].
+	pub fn play(&self) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_play, ())
+	}
+
+	/// See [`Engine::pause`].
+	pub fn pause(&self) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_pause, ())
+	}
+
+	#[allow(clippy::should_implement_trait)]
+	/// See [`Engine::next`].
+	pub fn next(&self) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_next, ())
+	}
+
+	/// See [`Engine::previous`].
+	pub fn previous(&self) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_previous, ())
+	}
+
+	/// See [`Engine::stop`].
+	pub fn stop(&self) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_stop, ())
+	}
+
+	/// See [`Engine::clear`].
+	pub fn clear(&self, clear: Clear) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_clear, clear)
+	}
+
+	/// See [`Engine::restore`].
+	///
+	/// # Errors
+	/// See [`Engine::restore`].
+	pub fn restore(&self, restore: impl Into<Restore<Extra>>) -> Result<AudioStateSnapshot<Extra>, RestoreError> {
+		let audio_state = restore.into().validate()?;
+		Ok(signal!(self, send_restore, audio_state))
+	}
+
+	/// See [`Engine::repeat`].
+	pub fn repeat(&self, repeat: Repeat) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_repeat, repeat)
+	}
+
+	/// See [`Engine::volume`].
+	pub fn volume(&self, volume: Volume) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_volume, volume)
+	}
+
+	/// See [`Engine::shuffle`].
+	pub fn shuffle(&self, shuffle: Shuffle) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_shuffle, shuffle)
+	}
+
+	/// See [`Engine::add`].
+	pub fn add(&self, add: Add<Extra>) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_add, add)
+	}
+
+	/// See [`Engine::add_many`].
+	pub fn add_many(&self, add_many: AddMany<Extra>) -> AudioStateSnapshot<Extra> {
+		signal!(self, send_add_many, add_many)
+	}
+
+	/// See [`Engine::seek`].
+	///
+	/// # Errors
+	/// See [`Engine::seek`].
+	pub fn seek(&self, seek: Seek) -> Result<AudioStateSnapshot<Extra>, SeekError> {
+		signal!(self, send_seek, seek)
+	}
+
+	/// See [`Engine::skip`].
+	///
+	/// # Errors
+	/// See [`Engine::skip`].
+	pub fn skip(&self, skip: Skip) -> Result<AudioStateSnapshot<Extra>, SkipError> {
+		signal!(self, send_skip, skip)
+	}
+
+	/// See [`Engine::back`].
+	///
+	/// # Errors
+	/// See [`Engine::back`].
+	pub fn back(&self, back: Back) -> Result<AudioStateSnapshot<Extra>, BackError> {
+		signal!(self, send_back, back)
+	}
+
+	/// See [`Engine::set_index`].
+	///
+	/// # Errors
+	/// See [`Engine::set_index`].
+	pub fn set_index(&self, set_index: SetIndex) -> Result<AudioStateSnapshot<Extra>, SetIndexError> {
+		signal!(self, send_set_index, set_index)
+	}
+
+	/// See [`Engine::remove`].
+	///
+	/// # Errors
+	/// See [`Engine::remove`].
+	pub fn remove(&self, remove: Remove) -> Result<AudioStateSnapshot<Extra>, RemoveError> {
+		signal!(self, send_remove, remove)
+	}
+
+	/// See [`Engine::remove_range`].
+	///
+	/// # Errors
+	/// See [`Engine::remove_range`].
+	pub fn remove_range(&self, remove_range: impl std::ops::RangeBounds<usize>) -> Result<AudioStateSnapshot<Extra>, RemoveError> {
+		signal!(self, send_remove_range, remove_range.into())
+	}
+
+	/// See [`Engine::output_device`].
+	///
+	/// # Errors
+	/// See [`Engine::output_device`].
+	pub fn output_device(&self, device: Option<String>) -> Result<(), OutputError> {
+		// The host owns the device, and `Pull` may not
+		// be pulled until we return, so don't wait on it.
+		if self.pull_mode {
+			return Err(OutputError::BackendUnavailable);
+		}
+
+		signal!(self, send_output_device, OutputRequest::Device(device))
+	}
+
+	/// See [`Engine::output_latency`].
+	///
+	/// # Errors
+	/// See [`Engine::output_latency`].
+	pub fn output_latency(&self, latency: Latency) -> Result<(), OutputError> {
+		if self.pull_mode {
+			return Err(OutputError::BackendUnavailable);
+		}
+
+		signal!(self, send_output_device, OutputRequest::Latency(latency))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Engine Impl
+impl<Extra: ExtraData> Engine<Extra> {
+	#[must_use]
+	/// Returns a cloneable, thread-safe [`EngineHandle`] to control this [`Engine`].
+	///
+	/// ```rust,ignore
+	/// # use sansan::*;
+	/// let mut engine = Engine::<()>::init(Default::default());
+	///
+	/// let handle = engine.handle();
+	/// std::thread::spawn(move || {
+	///     // Media keys.
+	///     handle.toggle();
+	/// });
+	///
+	/// // GUI.
+	/// engine.next();
+	/// ```
+	pub fn handle(&self) -> EngineHandle<Extra> {
+		self.handle.clone()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use crate::signal::{AddMethod,Volume};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn concurrent() {
+		let mut engine = crate::tests::init();
+		let handle = engine.handle();
+
+		// Many threads sending signals at the same time
+		// must only ever receive their own responses.
+		let threads: Vec<_> = (0..8).map(|i| {
+			let handle = handle.clone();
+			std::thread::spawn(move || {
+				for _ in 0..25 {
+					let state = handle.add(crate::signal::Add {
+						source: crate::tests::source(i),
+						method: AddMethod::Back,
+						clear: false,
+						play: false,
+					});
+					// The response is the state right after _our_ signal.
+					assert_eq!(*state.queue.back().unwrap().extra(), i);
+
+					assert!(!handle.volume(Volume::new(0.5)).queue.is_empty());
+				}
+			})
+		}).collect();
+
+		// The `Engine` keeps working alongside handles.
+		for _ in 0..25 {
+			engine.toggle();
+		}
+
+		for thread in threads {
+			thread.join().unwrap();
+		}
+
+		assert_eq!(engine.reader().get().queue.len(), 8 * 25);
+	}
+
+	#[test]
+	fn config_update() {
+		let mut engine = crate::tests::init();
+		let handle = engine.handle();
+
+		// Updates from a handle are seen by the `Engine`, and
+		// concurrent updates of different fields are all kept.
+		let threads: Vec<_> = (0..2).map(|i| {
+			let handle = handle.clone();
+			std::thread::spawn(move || if i == 0 {
+				handle.config_update(|c| c.queue_end_clear = false);
+			} else {
+				handle.config_update(|c| c.back_threshold = std::time::Duration::from_secs(10));
+			})
+		}).collect();
+		for thread in threads {
+			thread.join().unwrap();
+		}
+
+		let config = engine.config();
+		assert!(!config.queue_end_clear);
+		assert_eq!(config.back_threshold, std::time::Duration::from_secs(10));
+		assert_eq!(handle.config(), config);
+
+		engine.config_update(|c| c.queue_end_clear = true);
+		assert!(handle.config().queue_end_clear);
+	}
+}
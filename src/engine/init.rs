@@ -3,9 +3,11 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::{
 	actor::actor::spawn_actor,
-	engine::Engine,
+	engine::{Engine,EngineHandle,Autosave},
 	extra_data::ExtraData,
-	macros::{try_send,debug2,info2},
+	error::SessionError,
+	signal::{Seek,Restore,Repair},
+	macros::{debug2,info2,warn2},
 	state::{
 		AudioStateReader,
 		AudioState,
@@ -15,7 +17,7 @@ use crate::{
 		InitConfig,
 		Callbacks,
 		RuntimeConfig,
-		ErrorCallback,
+		ErrorAction,
 	},
 	actor::{
 		audio::{Audio,AUDIO_BUFFER_LEN},
@@ -25,18 +27,18 @@ use crate::{
 		caller::Caller,
 	},
 };
-use crossbeam::channel::{bounded,unbounded};
+use crossbeam::channel::{bounded,unbounded,never};
 use std::sync::{
 	Arc,
 	Barrier,
-	atomic::AtomicBool,
+	atomic::{AtomicBool,Ordering},
 };
 
 // Audio I/O backend.
-use crate::output::{AudioOutputStruct, AUDIO_OUTPUT_BACKEND};
+use crate::output::{AudioOutputStruct,OpenConfig,AUDIO_OUTPUT_BACKEND};
 
 // Resampler backend.
-use crate::resampler::{ResamplerStruct, RESAMPLER_BACKEND};
+use crate::resampler::{ResamplerStruct,ResamplerConfig};
 
 //---------------------------------------------------------------------------------------------------- Constants
 /// Total count of all the "actors" in our system.
@@ -47,6 +49,8 @@ use crate::resampler::{ResamplerStruct, RESAMPLER_BACKEND};
 /// [3] Caller
 /// [4] Gc (Garbage Collector)
 ///
+/// [Media] is optional and not included.
+///
 /// TODO: finalize all actors
 const ACTOR_COUNT: usize = 5;
 
@@ -67,9 +71,59 @@ impl<Extra: ExtraData> Engine<Extra> {
 	/// TODO
 	pub fn init(mut config: InitConfig<Extra>) -> Self {
 		info2!("Engine - initializing...");
-		info2!("Engine - audio output backend: [{AUDIO_OUTPUT_BACKEND}], resampler backend: [{RESAMPLER_BACKEND}]");
+		info2!(
+			"Engine - audio output backends: {:?} (default: [{AUDIO_OUTPUT_BACKEND}]), resampler: [{}]",
+			config.output_backends,
+			if config.resampler_custom.is_some() { "custom" } else { config.resampler.as_ref() },
+		);
 		debug2!("Engine - init config:\n{config:#?}");
 
+		// Which resampler to use, if needed.
+		let resampler = ResamplerConfig {
+			quality: config.resampler,
+			custom: config.resampler_custom,
+		};
+		// Settings used when [Audio] opens the output.
+		let open_config = OpenConfig {
+			resampler,
+			bit_perfect: config.bit_perfect,
+			sample_format: config.sample_format,
+			dither: config.dither,
+			latency: config.latency,
+		};
+
+		// Load the previous `Session`, if any.
+		//
+		// `InitConfig::audio_state` and `InitConfig::live_config`
+		// take priority over the session's, if they're set.
+		//
+		// The `AudioState` is always resumed paused, at the saved
+		// `Current::elapsed`, which requires a seek after restoring.
+		let session_config = config.session.take();
+		let mut resume_session = false;
+		if let Some(session_config) = session_config.as_ref() {
+			match (session_config.load)(&session_config.path) {
+				Ok(session) => {
+					info2!("Engine - loaded session: {}", session_config.path.display());
+					if config.live_config.is_none() {
+						config.live_config = Some(session.config);
+					}
+					if config.audio_state.is_none() {
+						let mut audio_state = session.audio_state;
+						audio_state.playing = false;
+						resume_session = true;
+						config.audio_state = Some(audio_state);
+					}
+				},
+				Err(SessionError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+					info2!("Engine - no session found: {}", session_config.path.display());
+				},
+				Err(e) => {
+					warn2!("Engine - failed to load session {}: {e}", session_config.path.display());
+				},
+			}
+		}
+
 		// Set a default `RuntimeConfig` if it doesn't exist.
 		let live_config = config.live_config.unwrap_or(RuntimeConfig::DEFAULT);
 
@@ -77,6 +131,10 @@ impl<Extra: ExtraData> Engine<Extra> {
 		// These may or may not have been already checked
 		// by other constructors, but we will check again here.
 		{
+			if let Some(spec) = config.pull {
+				assert_ne!(spec.sample_rate, 0, "InitConfig::pull sample rate was 0");
+				assert_ne!(spec.channels, 0, "InitConfig::pull channel count was 0");
+			}
 		}
 
 		// Initialize the "Barrier".
@@ -88,7 +146,19 @@ impl<Extra: ExtraData> Engine<Extra> {
 		// causing a panic.
 		//
 		// This is also re-used for the init barrier.
-		let barrier = Arc::new(Barrier::new(ACTOR_COUNT));
+		//
+		// In pull mode, there is no [Audio] actor.
+		//
+		// [Media] is only spawned if requested (and compiled in).
+		let media_controls = config.media_controls.take().filter(|_| cfg!(feature = "media_controls"));
+		let mut actor_count = ACTOR_COUNT;
+		if config.pull.is_some() {
+			actor_count -= 1;
+		}
+		if media_controls.is_some() {
+			actor_count += 1;
+		}
+		let barrier = Arc::new(Barrier::new(actor_count));
 
 		debug2!("Engine - init config audio state:\n{:#?}", config.audio_state);
 
@@ -126,14 +196,19 @@ impl<Extra: ExtraData> Engine<Extra> {
 		let (k_to_caller_error_decode, error_decode) = unbounded();
 		let (k_to_caller_error_source, error_source) = unbounded();
 		let (k_to_caller_error_output, error_output) = unbounded();
+		let (k_to_caller_event,        event)        = unbounded();
+		let (send_subscribe,           subscribe)    = unbounded();
 
 		// The channels _other_ actors use to tell
 		// [Caller] that some event has gone off
 		// and that it should [call()] the callback.
 		let a_to_caller_elapsed = callbacks.elapsed.as_ref().map(|(_, dur)| (a_to_caller_elapsed, dur.as_secs_f32()));
-		let caller_error_decode_pause = callbacks.error_decode.as_ref().is_some_and(ErrorCallback::will_pause);
-		let caller_error_source_pause = callbacks.error_source.as_ref().is_some_and(ErrorCallback::will_pause);
-		let caller_error_output_pause = callbacks.error_output.as_ref().is_some_and(ErrorCallback::will_pause);
+		let caller_error_decode_action = ErrorAction::from_callback(callbacks.error_decode.as_ref());
+		let caller_error_source_action = ErrorAction::from_callback(callbacks.error_source.as_ref());
+		let caller_error_output_action = ErrorAction::from_callback(callbacks.error_output.as_ref());
+
+		// The lifecycle callbacks are driven by `Kernel`'s `Event`'s.
+		atomic_state.events.store(callbacks.events(), Ordering::Release);
 
 		// INVARIANT:
 		//
@@ -155,6 +230,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 			crate::actor::caller::InitArgs {
 				barrier: Arc::clone(&barrier),
 				callbacks,
+				atomic_state:  Arc::clone(&atomic_state),
 				low_priority:  config.callback_low_priority,
 				shutdown,
 				source_new,
@@ -163,6 +239,8 @@ impl<Extra: ExtraData> Engine<Extra> {
 				error_decode,
 				error_source,
 				error_output,
+				event,
+				subscribe,
 			},
 		);
 
@@ -181,24 +259,49 @@ impl<Extra: ExtraData> Engine<Extra> {
 		let audio_ready_to_recv = Arc::new(AtomicBool::new(true));
 
 		let (a_to_gc, gc_from_a)   = unbounded();
-		spawn_actor!(
-			"Audio",
-			crate::actor::audio::InitArgs {
-				init_blocking:     config.init_blocking,
-				shutdown_blocking: config.shutdown_blocking,
-				barrier:           Arc::clone(&barrier),
-				atomic_state:      Arc::clone(&atomic_state),
-				ready_to_recv:     Arc::clone(&audio_ready_to_recv),
-				audio_retry:       config.audio_retry,
-				to_gc:             a_to_gc,
-				to_caller_elapsed: a_to_caller_elapsed,
-				from_decode:       a_from_d,
-				to_kernel:         a_to_k,
-				from_kernel:       a_from_k,
-				to_kernel_error:   err_a_to_k,
-			},
-			Audio::<AudioOutputStruct<ResamplerStruct>>::init
-		);
+		let pull = if let Some(spec) = config.pull {
+			// The host application pulls audio, so there is
+			// no [Audio] actor, [Pull] takes over its channels.
+			info2!("Engine - pull mode, not spawning [Audio], spec: {spec:?}");
+			Some(crate::output::Pull::new(crate::output::PullInitArgs {
+				spec,
+				resampler,
+				bit_perfect:             config.bit_perfect,
+				atomic_state:            Arc::clone(&atomic_state),
+				ready_to_recv:           Arc::clone(&audio_ready_to_recv),
+				to_gc:                   a_to_gc,
+				to_caller_elapsed:       a_to_caller_elapsed,
+				from_decode:             a_from_d,
+				to_kernel:               a_to_k,
+				from_kernel:             a_from_k,
+				to_kernel_error:         err_a_to_k,
+			}))
+		} else {
+			spawn_actor!(
+				Audio<AudioOutputStruct<ResamplerStruct>>,
+				config.init_blocking,
+				config.shutdown_blocking,
+				crate::actor::audio::InitArgs {
+					barrier:           Arc::clone(&barrier),
+					atomic_state:      Arc::clone(&atomic_state),
+					ready_to_recv:     Arc::clone(&audio_ready_to_recv),
+					audio_retry:       config.audio_retry,
+					output_backends:   std::mem::take(&mut config.output_backends),
+					output_device:     config.output_device.take(),
+					device_loss:       config.device_loss,
+					output_sink:       config.output_sink.take(),
+					open_config,
+					realtime:          config.realtime.audio(),
+					to_gc:             a_to_gc,
+					to_caller_elapsed: a_to_caller_elapsed,
+					from_decode:       a_from_d,
+					to_kernel:         a_to_k,
+					from_kernel:       a_from_k,
+					to_kernel_error:   err_a_to_k,
+				},
+			);
+			None
+		};
 
 		//-------------------------------------------------------------- Spawn [Decode]
 		let (d_to_k_seek,     k_from_d_seek)     = bounded(1);
@@ -215,6 +318,9 @@ impl<Extra: ExtraData> Engine<Extra> {
 			config.shutdown_blocking,
 			crate::actor::decode::InitArgs {
 				barrier:                Arc::clone(&barrier),
+				atomic_state:           Arc::clone(&atomic_state),
+				packet_error_threshold: config.error_decode_packet_threshold,
+				realtime:               config.realtime.decode().then_some(config.latency),
 				audio_ready_to_recv:    Arc::clone(&audio_ready_to_recv),
 				to_gc:                  d_to_gc,
 				to_audio:               d_to_a,
@@ -235,6 +341,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 			config.shutdown_blocking,
 			Gc {
 				barrier: Arc::clone(&barrier),
+				atomic_state: Arc::clone(&atomic_state),
 				shutdown,
 				from_audio:  gc_from_a,
 				from_decode: gc_from_d,
@@ -242,62 +349,84 @@ impl<Extra: ExtraData> Engine<Extra> {
 			},
 		}
 
-		//-------------------------------------------------------------- Initialize [Kernel] <-> [Engine] channels
-		// Variables are prefix/suffixed accordingly:
-		// - [Engine] == [e]
-		// - [Kernel] == [k]
-		//
-		// Since most of the channels are "full-duplex", as in:
-		//
-		//     [Engine] ---request---> [Kernel] ---response---> [Engine]
+		//-------------------------------------------------------------- Spawn [Media]
+		// [Kernel] handles [Media]'s requests exactly like the [Engine]'s.
 		//
-		// there must be 2 pairs of send/recv channel set up.
+		// If [Media] is not spawned, [Kernel] listens
+		// on a channel that never receives anything.
+		#[cfg_attr(not(feature = "media_controls"), allow(unused_mut))]
+		let mut shutdown_actor = vec![gc_shutdown, c_shutdown];
+		let (k_to_m,      m_from_k)      = bounded(1);
+		let (k_to_m_seek, m_from_k_seek) = bounded(1);
+		#[cfg(feature = "media_controls")]
+		let k_from_m = if let Some(media_controls) = media_controls {
+			let (m_shutdown, shutdown) = bounded(1);
+			let (m_to_k,     k_from_m) = bounded(1);
+			shutdown_actor.push(m_shutdown);
+			spawn_actor!(
+				crate::actor::media::Media<Extra>,
+				config.init_blocking,
+				config.shutdown_blocking,
+				crate::actor::media::InitArgs {
+					barrier:          Arc::clone(&barrier),
+					atomic_state:     Arc::clone(&atomic_state),
+					reader:           audio_state_reader.clone(),
+					config:           media_controls,
+					shutdown,
+					to_kernel:        m_to_k,
+					from_kernel:      m_from_k,
+					from_kernel_seek: m_from_k_seek,
+				},
+			);
+			k_from_m
+		} else {
+			drop((m_from_k, m_from_k_seek));
+			never()
+		};
+		#[cfg(not(feature = "media_controls"))]
+		let k_from_m = {
+			drop((m_from_k, m_from_k_seek, media_controls));
+			never()
+		};
+
+		//-------------------------------------------------------------- Initialize [Kernel] <-> [Engine] channels
+		// All signals are "full-duplex", as in:
 		//
-		// The variables are prefixed with [k_*] or [e_*] depending
-		// on if they are for [Kernel] or [Engine].
+		//     [EngineHandle] ---request---> [Kernel] ---response---> [EngineHandle]
 		//
-		// In the case where we don't need or a response, or rather
-		// the return value is [()], e.g [toggle()], then there
-		// is no need for a [Kernel] ---response---> [Engine] channel,
-		// meaning Engine only owns a S, and Kernel only owns a R.
+		// but there can be many [EngineHandle]'s sending at the same time,
+		// so instead of a shared response channel, each request carries
+		// its own response [Sender], see [Request].
 		//
-		// These are those "no-response-needed" channels.
-		// They are [unbounded()] to allow for immediate return.
-		//  |
-		//  v
-		let (send_toggle,   recv_toggle)               = bounded(1);
-		let (send_play,     recv_play)                 = bounded(1);
-		let (send_pause,    recv_pause)                = bounded(1);
-		let (send_stop,     recv_stop)                 = bounded(1);
-		let (send_clear,    recv_clear)                = bounded(1);
-		let (send_restore,  recv_restore)              = bounded(1);
-		let (send_repeat,   recv_repeat)               = bounded(1);
-		let (send_shuffle,  recv_shuffle)              = bounded(1);
-		let (send_volume,   recv_volume)               = bounded(1);
-		let (send_next,     recv_next)                 = bounded(1);
-		let (send_previous, recv_previous)             = bounded(1);
-		// These must be labeled.
 		// Although semantically [bounded(0)] makes sense since [Kernel]
 		// and [Signal] must meet up, [bounded(1)] is faster.
 		//  |
 		//  v
-		let (e_send_add,          k_recv_add)          = bounded(1);
-		let (e_send_add_many,     k_recv_add_many)     = bounded(1);
-		let (e_send_seek,         k_recv_seek)         = bounded(1);
-		let (k_send_seek,         e_recv_seek)         = bounded(1);
-		let (e_send_skip,         k_recv_skip)         = bounded(1);
-		let (k_send_skip,         e_recv_skip)         = bounded(1);
-		let (e_send_back,         k_recv_back)         = bounded(1);
-		let (k_send_back,         e_recv_back)         = bounded(1);
-		let (e_send_set_index,    k_recv_set_index)    = bounded(1);
-		let (k_send_set_index,    e_recv_set_index)    = bounded(1);
-		let (e_send_remove,       k_recv_remove)       = bounded(1);
-		let (k_send_remove,       e_recv_remove)       = bounded(1);
-		let (e_send_remove_range, k_recv_remove_range) = bounded(1);
-		let (k_send_remove_range, e_recv_remove_range) = bounded(1);
-
-		// The special common "AudioStateSnapshot" return channel.
-		let (send_audio_state, recv_audio_state) = bounded(1);
+		let (send_toggle,        recv_toggle)        = bounded(1);
+		let (send_play,          recv_play)          = bounded(1);
+		let (send_pause,         recv_pause)         = bounded(1);
+		let (send_stop,          recv_stop)          = bounded(1);
+		let (send_clear,         recv_clear)         = bounded(1);
+		let (send_restore,       recv_restore)       = bounded(1);
+		let (send_repeat,        recv_repeat)        = bounded(1);
+		let (send_shuffle,       recv_shuffle)       = bounded(1);
+		let (send_volume,        recv_volume)        = bounded(1);
+		let (send_next,          recv_next)          = bounded(1);
+		let (send_previous,      recv_previous)      = bounded(1);
+		let (send_add,           recv_add)           = bounded(1);
+		let (send_add_many,      recv_add_many)      = bounded(1);
+		let (send_seek,          recv_seek)          = bounded(1);
+		let (send_skip,          recv_skip)          = bounded(1);
+		let (send_back,          recv_back)          = bounded(1);
+		let (send_set_index,     recv_set_index)     = bounded(1);
+		let (send_remove,        recv_remove)        = bounded(1);
+		let (send_remove_range,  recv_remove_range)  = bounded(1);
+		let (send_output_device, recv_output_device) = bounded(1);
+
+		// Signals sent with [Engine::send] all go through this 1 channel,
+		// so they are applied in order. It is [unbounded()] so they
+		// return immediately, even if [Kernel] is busy.
+		let (send_signal, recv_signal) = unbounded();
 
 		//-------------------------------------------------------------- Spawn [Kernel]
 		let (shutdown, k_shutdown)           = bounded(1);
@@ -305,7 +434,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 		let channels = crate::actor::kernel::Channels {
 			shutdown: k_shutdown,
 			shutdown_done: k_shutdown_done,
-			shutdown_actor: [gc_shutdown, c_shutdown],
+			shutdown_actor,
 			recv_toggle,
 			recv_play,
 			recv_pause,
@@ -315,6 +444,7 @@ impl<Extra: ExtraData> Engine<Extra> {
 			to_audio:                 k_to_a,
 			from_audio:               k_from_a,
 			from_audio_error:         err_k_from_a,
+			_pull:                    pull.as_ref().map(crate::output::Pull::keep_alive),
 			to_decode:                k_to_d,
 			from_decode_seek:         k_from_d_seek,
 			from_decode_source:       k_from_d_source,
@@ -322,30 +452,29 @@ impl<Extra: ExtraData> Engine<Extra> {
 			from_decode_error_source: err_source_k_from_d,
 			to_caller_source_new:     k_to_caller_source_new,
 			to_caller_queue_end:      k_to_caller_queue_end,
-			to_caller_error_decode:   (k_to_caller_error_decode, caller_error_decode_pause),
-			to_caller_error_source:   (k_to_caller_error_source, caller_error_source_pause),
-			to_caller_error_output:   (k_to_caller_error_output, caller_error_output_pause),
+			to_caller_error_decode:   (k_to_caller_error_decode, caller_error_decode_action),
+			to_caller_error_source:   (k_to_caller_error_source, caller_error_source_action),
+			to_caller_error_output:   (k_to_caller_error_output, caller_error_output_action),
+			to_caller_event:          k_to_caller_event,
 			to_gc:                    k_to_gc,
-			send_audio_state,
 			recv_clear,
 			recv_repeat,
 			recv_shuffle,
 			recv_volume,
 			recv_restore,
-			recv_add:          k_recv_add,
-			recv_add_many:     k_recv_add_many,
-			send_seek:         k_send_seek,
-			recv_seek:         k_recv_seek,
-			send_skip:         k_send_skip,
-			recv_skip:         k_recv_skip,
-			send_back:         k_send_back,
-			recv_back:         k_recv_back,
-			send_set_index:    k_send_set_index,
-			recv_set_index:    k_recv_set_index,
-			send_remove:       k_send_remove,
-			recv_remove:       k_recv_remove,
-			send_remove_range: k_send_remove_range,
-			recv_remove_range: k_recv_remove_range,
+			recv_add,
+			recv_add_many,
+			recv_seek,
+			recv_skip,
+			recv_back,
+			recv_set_index,
+			recv_remove,
+			recv_remove_range,
+			recv_output_device,
+			recv_signal,
+			from_media:    k_from_m,
+			to_media:      k_to_m,
+			to_media_seek: k_to_m_seek,
 		};
 		spawn_actor! {
 			"Kernel",
@@ -354,66 +483,130 @@ impl<Extra: ExtraData> Engine<Extra> {
 				shutdown_blocking: config.shutdown_blocking,
 				barrier: Arc::clone(&barrier),
 				atomic_state:  Arc::clone(&atomic_state),
+				error_decode_retry: config.error_decode_retry,
 				w: audio_state_writer,
 				channels,
 			},
 			Kernel::<Extra>::init
 		}
 
-		// TODO: hand until ready before returning.
-		//
-		// If we had `AudioState` to restore, load it first
-		// so `Kernel` immediately restores it upon spawn.
-		if let Some(audio_state) = config.audio_state.take() {
-			try_send!(send_restore, audio_state);
-		}
-
 		//-------------------------------------------------------------- Return
-		let repeat = atomic_state.repeat.load();
-		let volume = atomic_state.volume.load();
-		info2!("Engine - initialization complete");
-		Self {
-			reader: audio_state_reader,
-			config: live_config,
-			shutdown_blocking: config.shutdown_blocking,
-			atomic_state,
-
-			repeat,
-			volume,
-
-			shutdown,
-			shutdown_done,
-
-			recv_audio_state,
+		let handle = EngineHandle {
+			reader: audio_state_reader.clone(),
+			atomic_state: Arc::clone(&atomic_state),
+			pull_mode: pull.is_some(),
+			send_subscribe,
 			send_toggle,
 			send_play,
 			send_pause,
+			send_next,
+			send_previous,
 			send_stop,
+			send_add,
+			send_add_many,
 			send_clear,
 			send_restore,
 			send_repeat,
-			send_shuffle,
 			send_volume,
-			send_next,
-			send_previous,
-			send_add:          e_send_add,
-			send_add_many:     e_send_add_many,
-			send_seek:         e_send_seek,
-			recv_seek:         e_recv_seek,
-			send_skip:         e_send_skip,
-			recv_skip:         e_recv_skip,
-			send_back:         e_send_back,
-			recv_back:         e_recv_back,
-			send_set_index:    e_send_set_index,
-			recv_set_index:    e_recv_set_index,
-			send_remove:       e_send_remove,
-			recv_remove:       e_recv_remove,
-			send_remove_range: e_send_remove_range,
-			recv_remove_range: e_recv_remove_range,
+			send_shuffle,
+			send_seek,
+			send_skip,
+			send_back,
+			send_set_index,
+			send_remove,
+			send_remove_range,
+			send_output_device,
+			send_signal,
+		};
+		let mut engine = Self {
+			reader: audio_state_reader,
+			shutdown_blocking: config.shutdown_blocking,
+			atomic_state,
+			pull,
+
+			shutdown,
+			shutdown_done,
+
+			handle,
+			autosave: None,
+		};
+
+		// TODO: hand until ready before returning.
+		//
+		// If we had `AudioState` to restore, restore it before returning.
+		//
+		// A loaded `Session` may be stale so it is always repaired,
+		// a caller-supplied `AudioState` is only repaired as configured,
+		// if it is invalid it is not restored and we start fresh.
+		if let Some(audio_state) = config.audio_state.take() {
+			let repair = if resume_session { Repair::ALL } else { config.audio_state_repair };
+			let restore = Restore { audio_state, repair };
+			let elapsed = match engine.restore(restore) {
+				Ok(snapshot) => snapshot.current.as_ref().map(|c| c.elapsed),
+				Err(e) => {
+					warn2!("Engine - failed to restore audio state: {e}");
+					None
+				},
+			};
+
+			// Resume at the exact position the `Session` was saved at.
+			if let Some(elapsed) = elapsed.filter(|e| resume_session && *e > 0.0) {
+				if let Err(e) = engine.seek(Seek::Absolute(elapsed)) {
+					warn2!("Engine - failed to resume session at {elapsed}s: {e:?}");
+				}
+			}
 		}
+
+		// Start saving the `Session` (if enabled), only now
+		// that any previous session has been restored.
+		engine.autosave = session_config.map(|s| Autosave::new(s, engine.reader.clone(), Arc::clone(&engine.atomic_state)));
+
+		info2!("Engine - initialization complete");
+		engine
 	}
 }
 
 //---------------------------------------------------------------------------------------------------- Tests
 #[cfg(test)]
-mod tests {}
\ No newline at end of file
+mod tests {
+	use crate::{
+		config::InitConfig,
+		signal::Repair,
+		state::{AudioState,Current},
+	};
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn audio_state_repair() {
+		let sources = crate::tests::sources();
+		let queue: std::collections::VecDeque<_> = sources.iter().map(Clone::clone).collect();
+		// An invalid `Current::index`.
+		let audio_state = AudioState {
+			current: Some(Current {
+				source: queue[0].clone(),
+				index: usize::MAX,
+				elapsed: 0.0,
+			}),
+			queue,
+			..AudioState::DEFAULT
+		};
+
+		// Not repaired by default, nothing is restored.
+		let engine = crate::tests::init_with_config(InitConfig {
+			audio_state: Some(audio_state.clone()),
+			..InitConfig::DEFAULT
+		});
+		assert_eq!(*engine.reader().get(), AudioState::DEFAULT);
+		drop(engine);
+
+		// Repaired, the bad `Current` is reset.
+		let engine = crate::tests::init_with_config(InitConfig {
+			audio_state: Some(audio_state.clone()),
+			audio_state_repair: Repair { reset_current: true, ..Repair::NONE },
+			..InitConfig::DEFAULT
+		});
+		let state = engine.reader().get();
+		assert_eq!(state.queue, audio_state.queue);
+		assert_eq!(state.current, None);
+	}
+}
\ No newline at end of file
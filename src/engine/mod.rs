@@ -3,9 +3,26 @@
 mod engine;
 pub use engine::Engine;
 
+mod handle;
+pub use handle::EngineHandle;
+
+mod send;
+pub use send::EngineSend;
+
 // mod error;
+mod apply;
 mod init;
 mod functions;
+mod session;
+mod shutdown;
+pub(crate) use session::Autosave;
+mod subscribe;
+
+#[cfg(feature = "async")]
+mod async_engine;
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use async_engine::{AsyncEngine,Reply,EventStream};
 
 #[cfg(test)]
 mod test;
\ No newline at end of file
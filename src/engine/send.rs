@@ -0,0 +1,293 @@
+//! Signals that are not waited on.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	engine::{Engine,EngineHandle},
+	extra_data::ExtraData,
+	macros::send,
+	output::Latency,
+	signal::{
+		Add,AddMany,Back,Clear,Remove,Repeat,Seek,
+		SetIndex,Shuffle,SendSignal,Skip,Volume,Restore,
+	},
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	config::Callbacks,
+	event::Event,
+	signal::SignalError,
+	state::AudioStateSnapshot,
+};
+
+//---------------------------------------------------------------------------------------------------- EngineSend
+/// Send signals without waiting for a response
+///
+/// This is created with [`Engine::send`] or [`EngineHandle::send`].
+///
+/// The signal methods are the same as the [`Engine`]'s, although they
+/// return immediately after the signal is queued, instead of waiting
+/// for `sansan` to apply it and return an [`AudioStateSnapshot`].
+///
+/// This is useful when the response isn't needed and the calling
+/// thread shouldn't stall, e.g. media keys or rapid seeking.
+///
+/// ## Errors
+/// If a signal fails, the error is delivered as an [`Event::ErrorSignal`]
+/// to subscribers and to [`Callbacks::error_signal`], see [`SignalError`].
+///
+/// ## Ordering
+/// - Signals sent with [`EngineSend`] are applied in the order they were sent
+/// - They are applied before any waiting signal (e.g. [`Engine::next`])
+///   sent afterwards, so a waiting signal observes all previous [`EngineSend`] signals
+///
+/// ```rust,ignore
+/// # use sansan::{*,signal::*};
+/// let engine = Engine::<()>::init(Default::default());
+///
+/// // These return immediately.
+/// engine.send().seek(Seek::Forward(5.0));
+/// engine.send().seek(Seek::Forward(5.0));
+/// engine.send().next();
+/// ```
+#[derive(Debug)]
+pub struct EngineSend<'a, Extra: ExtraData>(&'a EngineHandle<Extra>);
+
+impl<Extra: ExtraData> EngineSend<'_, Extra> {
+	/// Queue a [`SendSignal`] for `Kernel`.
+	fn signal(&self, signal: SendSignal<Extra>) {
+		// `Kernel` only exits on shutdown, so this never fails.
+		send!(self.0.send_signal, signal);
+	}
+
+	/// See [`Engine::toggle`].
+	pub fn toggle(&self) {
+		self.signal(SendSignal::Toggle);
+	}
+
+	/// See [`Engine::play`].
+	pub fn play(&self) {
+		self.signal(SendSignal::Play);
+	}
+
+	/// See [`Engine::pause`].
+	pub fn pause(&self) {
+		self.signal(SendSignal::Pause);
+	}
+
+	#[allow(clippy::should_implement_trait)]
+	/// See [`Engine::next`].
+	pub fn next(&self) {
+		self.signal(SendSignal::Next);
+	}
+
+	/// See [`Engine::previous`].
+	pub fn previous(&self) {
+		self.signal(SendSignal::Previous);
+	}
+
+	/// See [`Engine::stop`].
+	pub fn stop(&self) {
+		self.signal(SendSignal::Stop);
+	}
+
+	/// See [`Engine::clear`].
+	pub fn clear(&self, clear: Clear) {
+		self.signal(SendSignal::Clear(clear));
+	}
+
+	/// See [`Engine::repeat`].
+	pub fn repeat(&self, repeat: Repeat) {
+		self.signal(SendSignal::Repeat(repeat));
+	}
+
+	/// See [`Engine::volume`].
+	pub fn volume(&self, volume: Volume) {
+		self.signal(SendSignal::Volume(volume));
+	}
+
+	/// See [`Engine::shuffle`].
+	pub fn shuffle(&self, shuffle: Shuffle) {
+		self.signal(SendSignal::Shuffle(shuffle));
+	}
+
+	/// See [`Engine::add`].
+	pub fn add(&self, add: Add<Extra>) {
+		self.signal(SendSignal::Add(add));
+	}
+
+	/// See [`Engine::add_many`].
+	pub fn add_many(&self, add_many: AddMany<Extra>) {
+		self.signal(SendSignal::AddMany(add_many));
+	}
+
+	/// See [`Engine::seek`].
+	///
+	/// Errors are sent as [`SignalError::Seek`].
+	pub fn seek(&self, seek: Seek) {
+		self.signal(SendSignal::Seek(seek));
+	}
+
+	/// See [`Engine::skip`].
+	///
+	/// Errors are sent as [`SignalError::Skip`].
+	pub fn skip(&self, skip: Skip) {
+		self.signal(SendSignal::Skip(skip));
+	}
+
+	/// See [`Engine::back`].
+	///
+	/// Errors are sent as [`SignalError::Back`].
+	pub fn back(&self, back: Back) {
+		self.signal(SendSignal::Back(back));
+	}
+
+	/// See [`Engine::set_index`].
+	///
+	/// Errors are sent as [`SignalError::SetIndex`].
+	pub fn set_index(&self, set_index: SetIndex) {
+		self.signal(SendSignal::SetIndex(set_index));
+	}
+
+	/// See [`Engine::remove`].
+	///
+	/// Errors are sent as [`SignalError::Remove`].
+	pub fn remove(&self, remove: Remove) {
+		self.signal(SendSignal::Remove(remove));
+	}
+
+	/// See [`Engine::remove_range`].
+	///
+	/// Errors are sent as [`SignalError::Remove`].
+	pub fn remove_range(&self, remove_range: impl std::ops::RangeBounds<usize>) {
+		self.signal(SendSignal::RemoveRange(remove_range.into()));
+	}
+
+	/// See [`Engine::restore`].
+	///
+	/// Like [`Engine::restore`], the `AudioState` is validated
+	/// on this thread, so this blocks until that is done.
+	///
+	/// Errors are sent as [`SignalError::Restore`].
+	pub fn restore(&self, restore: impl Into<Restore<Extra>>) {
+		self.signal(SendSignal::Restore(restore.into().validate()));
+	}
+
+	/// See [`Engine::output_device`].
+	///
+	/// Errors are sent as [`SignalError::Output`].
+	pub fn output_device(&self, device: Option<String>) {
+		self.signal(SendSignal::OutputDevice(device));
+	}
+
+	/// See [`Engine::output_latency`].
+	///
+	/// Errors are sent as [`SignalError::Output`].
+	pub fn output_latency(&self, latency: Latency) {
+		self.signal(SendSignal::OutputLatency(latency));
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EngineHandle Impl
+impl<Extra: ExtraData> EngineHandle<Extra> {
+	#[must_use]
+	/// Send signals without waiting for a response, see [`EngineSend`].
+	pub const fn send(&self) -> EngineSend<'_, Extra> {
+		EngineSend(self)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Engine Impl
+impl<Extra: ExtraData> Engine<Extra> {
+	#[must_use]
+	/// Send signals without waiting for a response, see [`EngineSend`].
+	pub const fn send(&self) -> EngineSend<'_, Extra> {
+		self.handle.send()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use crate::{
+		config::{Callbacks,InitConfig},
+		error::OutputError,
+		event::{Event,EventKind},
+		signal::{AddMany,AddMethod,RestoreError,Seek,SeekError,SignalError,Volume},
+		state::{AudioState,Current},
+	};
+	use std::time::Duration;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn send() {
+		let (send, recv) = crossbeam::channel::unbounded();
+		let mut callbacks = Callbacks::new();
+		callbacks.error_signal(move |error, _| send.send(error).unwrap());
+
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			callbacks,
+			..InitConfig::DEFAULT
+		});
+		let errors = engine.subscribe(EventKind::ErrorSignal.into());
+
+		// Nothing is playing, so this fails.
+		engine.send().seek(Seek::Absolute(1.0));
+		assert_eq!(recv.recv_timeout(Duration::from_secs(5)).unwrap(), SignalError::Seek(SeekError::NoCurrent));
+		match errors.recv_timeout(Duration::from_secs(5)).unwrap() {
+			Event::ErrorSignal(error) => assert_eq!(error, SignalError::Seek(SeekError::NoCurrent)),
+			e => panic!("unexpected event: {e:?}"),
+		}
+
+		// These are applied in order, and before
+		// the waiting signal sent afterwards.
+		engine.send().add_many(AddMany {
+			sources: crate::tests::sources(),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		engine.send().next();
+		engine.send().next();
+		engine.send().volume(Volume::new(0.25));
+		let state = engine.volume(Volume::new(0.5));
+
+		assert_eq!(state.queue.len(), 10);
+		assert_eq!(state.current.as_ref().unwrap().index, 2);
+		assert_eq!(state.volume, Volume::new(0.5));
+		assert!(recv.try_recv().is_err());
+	}
+
+	#[test]
+	fn restore_output() {
+		let _lock = crate::tests::dummy_devices_lock();
+		let engine = crate::tests::init();
+		let errors = engine.subscribe(EventKind::ErrorSignal.into());
+		let error = || match errors.recv_timeout(Duration::from_secs(5)).unwrap() {
+			Event::ErrorSignal(error) => error,
+			e => panic!("unexpected event: {e:?}"),
+		};
+
+		// An invalid `AudioState` is not restored.
+		let audio_state = AudioState {
+			current: Some(Current {
+				source: crate::tests::source(0),
+				index: 1,
+				elapsed: 0.0,
+			}),
+			..AudioState::DEFAULT
+		};
+		engine.send().restore(audio_state);
+		assert_eq!(error(), SignalError::Restore(RestoreError::CurrentIndex { index: 1, len: 0 }));
+
+		// `Audio` responds after `Kernel` has moved on.
+		engine.send().output_device(Some("this device does not exist".into()));
+		assert_eq!(error(), SignalError::Output(OutputError::DeviceUnavailable));
+
+		// Successes send nothing.
+		engine.send().output_device(None);
+		engine.send().restore(AudioState::DEFAULT);
+		assert!(engine.stop().queue.is_empty());
+		assert!(errors.recv_timeout(Duration::from_millis(100)).is_err());
+	}
+}
@@ -0,0 +1,295 @@
+//! Session saving.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	engine::Engine,
+	extra_data::ExtraData,
+	macros::{debug2,warn2},
+	state::{AtomicState,AudioStateReader,Session},
+	config::SessionConfig,
+};
+use crossbeam::channel::{unbounded,Sender,RecvTimeoutError};
+use std::{
+	sync::Arc,
+	thread::JoinHandle,
+	time::Instant,
+};
+
+#[cfg(feature = "bincode")]
+use crate::error::SessionError;
+
+//---------------------------------------------------------------------------------------------------- Engine Impl
+impl<Extra: ExtraData> Engine<Extra> {
+	#[must_use]
+	/// Returns the current [`Session`].
+	///
+	/// This is the latest [`AudioState`](crate::state::AudioState)
+	/// and the current [`RuntimeConfig`].
+	///
+	/// This can be serialized in any format and later restored with
+	/// [`InitConfig::audio_state`](crate::config::InitConfig::audio_state) and
+	/// [`InitConfig::live_config`](crate::config::InitConfig::live_config).
+	pub fn session(&self) -> Session<Extra> {
+		Session {
+			audio_state: self.reader.get().as_ref().clone(),
+			config: self.config(),
+		}
+	}
+}
+
+#[cfg(feature = "bincode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+impl<Extra> Engine<Extra>
+where
+	Extra: ExtraData + bincode::Encode + bincode::Decode,
+{
+	/// Save the current [`Session`] to a file.
+	///
+	/// This can be loaded with [`Session::load`], or automatically
+	/// with [`InitConfig::session`](crate::config::InitConfig::session).
+	///
+	/// # Errors
+	/// See [`Session::save`].
+	pub fn save_session(&self, path: impl AsRef<std::path::Path>) -> Result<(), SessionError> {
+		self.session().save(path)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Autosave
+/// Saves the [`Session`] on an interval and on [`Drop`].
+///
+/// This is owned by the [`Engine`], so it
+/// is dropped (and saves) along with it.
+#[derive(Debug)]
+pub(crate) struct Autosave<Extra: ExtraData> {
+	/// Where and how to save.
+	session: SessionConfig<Extra>,
+	/// The `Engine`'s audio state.
+	reader: AudioStateReader<Extra>,
+	/// Holds the `Engine`'s latest `RuntimeConfig`.
+	atomic_state: Arc<AtomicState>,
+	/// The interval thread (if any), and the channel to tell it to exit.
+	thread: Option<(Sender<()>, JoinHandle<()>)>,
+}
+
+impl<Extra: ExtraData> Autosave<Extra> {
+	/// Start autosaving, spawning the interval thread if needed.
+	pub(crate) fn new(
+		session: SessionConfig<Extra>,
+		reader: AudioStateReader<Extra>,
+		atomic_state: Arc<AtomicState>,
+	) -> Self {
+		let thread = session.autosave.and_then(|interval| {
+			let (send, recv) = unbounded();
+			let session = session.clone();
+			let reader = reader.clone();
+			let atomic_state = Arc::clone(&atomic_state);
+
+			let spawn = std::thread::Builder::new()
+				.name("Autosave".into())
+				.spawn(move || {
+					let mut deadline = Instant::now() + interval;
+
+					loop {
+						match recv.recv_deadline(deadline) {
+							// The `Engine` is being dropped.
+							Ok(()) | Err(RecvTimeoutError::Disconnected) => return,
+							Err(RecvTimeoutError::Timeout) => {
+								Self::save(&session, &reader, &atomic_state);
+								deadline = Instant::now() + interval;
+							},
+						}
+					}
+				});
+
+			match spawn {
+				Ok(handle) => Some((send, handle)),
+				Err(e) => {
+					warn2!("Autosave - failed to spawn thread: {e}");
+					None
+				},
+			}
+		});
+
+		Self {
+			session,
+			reader,
+			atomic_state,
+			thread,
+		}
+	}
+
+	/// Save the latest `Session`, logging any errors.
+	fn save(
+		session: &SessionConfig<Extra>,
+		reader: &AudioStateReader<Extra>,
+		atomic_state: &AtomicState,
+	) {
+		let s = Session {
+			audio_state: reader.get().as_ref().clone(),
+			config: atomic_state.config(),
+		};
+
+		match (session.save)(&session.path, &s) {
+			Ok(()) => debug2!("Autosave - saved session: {}", session.path.display()),
+			Err(e) => warn2!("Autosave - failed to save session {}: {e}", session.path.display()),
+		}
+	}
+}
+
+impl<Extra: ExtraData> Drop for Autosave<Extra> {
+	fn drop(&mut self) {
+		// Stop the interval thread first,
+		// so it doesn't save at the same time.
+		if let Some((send, handle)) = self.thread.take() {
+			// The thread may have already exited (e.g. it panicked).
+			drop(send.send(()));
+			if handle.join().is_err() {
+				warn2!("Autosave - thread panicked");
+			}
+		}
+
+		if self.session.save_on_drop {
+			Self::save(&self.session, &self.reader, &self.atomic_state);
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+	use super::*;
+	use crate::{
+		config::{InitConfig,RuntimeConfig},
+		error::SessionError,
+		signal::{Repeat,Volume},
+		state::{AudioState,Current},
+	};
+	use pretty_assertions::assert_eq;
+	use std::{path::{Path,PathBuf}, time::Duration};
+
+	/// Load a JSON `Session`, autosaving doesn't depend on the format.
+	fn load(path: &Path) -> Result<Session<usize>, SessionError> {
+		let bytes = std::fs::read(path)?;
+		serde_json::from_slice(&bytes).map_err(|e| SessionError::Decode(e.to_string()))
+	}
+
+	/// Save a JSON `Session`, atomically like `Session::save`
+	/// so the interval test never reads a half-written file.
+	fn save(path: &Path, session: &Session<usize>) -> Result<(), SessionError> {
+		let bytes = serde_json::to_vec(session).map_err(|e| SessionError::Encode(e.to_string()))?;
+		let tmp = path.with_extension("tmp");
+		std::fs::write(&tmp, bytes)?;
+		Ok(std::fs::rename(tmp, path)?)
+	}
+
+	/// A JSON `SessionConfig` at `path`.
+	fn json(path: &Path) -> SessionConfig<usize> {
+		SessionConfig::with_codec(path, load, save)
+	}
+
+	/// A unique session path for each test.
+	fn path(name: &str) -> PathBuf {
+		let dir = std::env::temp_dir().join(format!("sansan_autosave_{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let path = dir.join(name);
+		if path.exists() {
+			std::fs::remove_file(&path).unwrap();
+		}
+		path
+	}
+
+	#[test]
+	fn save_and_resume() {
+		let path = path("resume.json");
+
+		// Save a session that was playing with a modified config.
+		let mut audio_state = AudioState::DEFAULT;
+		for i in 0..3 {
+			audio_state.queue.push_back(crate::tests::source(i));
+		}
+		audio_state.current = Some(Current {
+			source: audio_state.queue[1].clone(),
+			index: 1,
+			elapsed: 100.0,
+		});
+		audio_state.playing = true;
+		audio_state.repeat = Repeat::Current;
+		audio_state.volume = Volume::new(0.5);
+
+		let mut config = RuntimeConfig::DEFAULT;
+		config.queue_end_clear = false;
+
+		save(&path, &Session { audio_state, config }).unwrap();
+
+		// Resume it.
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			session: Some(json(&path)),
+			..InitConfig::DEFAULT
+		});
+
+		assert_eq!(engine.config(), config);
+		let reader = engine.reader().get();
+		assert!(!reader.playing);
+		assert_eq!(reader.repeat, Repeat::Current);
+		assert_eq!(reader.volume, Volume::new(0.5));
+		assert_eq!(reader.queue.len(), 3);
+		let current = reader.current.as_ref().unwrap();
+		assert_eq!(current.index, 1);
+		// Resumed at the exact saved position.
+		assert_eq!(current.elapsed, 100.0);
+		drop(reader);
+
+		// Modify state, the session should be saved on drop.
+		engine.config_update(|c| c.queue_end_clear = true);
+		engine.repeat(Repeat::Off);
+		let session = engine.session();
+		drop(engine);
+
+		let saved = load(&path).unwrap();
+		assert!(saved.config.queue_end_clear);
+		assert_eq!(saved.audio_state.repeat, Repeat::Off);
+		assert_eq!(saved, session);
+	}
+
+	#[test]
+	fn missing() {
+		let path = path("missing.json");
+
+		// A missing session starts fresh.
+		let engine = crate::tests::init_with_config(InitConfig {
+			session: Some(SessionConfig {
+				save_on_drop: false,
+				..json(&path)
+			}),
+			..InitConfig::DEFAULT
+		});
+		assert_eq!(engine.session(), Session {
+			audio_state: AudioState::DEFAULT,
+			config: RuntimeConfig::DEFAULT,
+		});
+
+		drop(engine);
+		assert!(!path.exists());
+	}
+
+	#[test]
+	fn interval() {
+		let path = path("interval.json");
+
+		let engine = crate::tests::init_with_config(InitConfig {
+			session: Some(SessionConfig {
+				autosave: Some(Duration::from_millis(10)),
+				save_on_drop: false,
+				..json(&path)
+			}),
+			..InitConfig::DEFAULT
+		});
+
+		while !path.exists() {
+			std::thread::sleep(Duration::from_millis(10));
+		}
+		assert_eq!(load(&path).unwrap(), engine.session());
+	}
+}
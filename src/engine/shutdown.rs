@@ -1,4 +1,6 @@
-//---------------------------------------------------------------------------------------------------- Drop
+//! `Engine` shutdown on `Drop`.
+
+//---------------------------------------------------------------------------------------------------- Use
 use crate::{
 	extra_data::ExtraData,
 	engine::Engine,
@@ -11,23 +13,59 @@ impl<Extra: ExtraData> Drop for Engine<Extra> {
 	#[inline(never)]
 	#[allow(clippy::branches_sharing_code)]
 	fn drop(&mut self) {
-		match shutdown {
-		}
 		if self.shutdown_blocking {
 			info2!("Engine - waiting on shutdown ...");
 
 			// Tell [Kernel] to shutdown,
 			// and to tell us when it's done.
-			try_send!(self.shutdown.try_send(true));
+			try_send!(self.shutdown, true);
 
 			// Hang until [Kernel] responds.
-			recv!(self.shutdown_done.recv());
+			recv!(self.shutdown_done);
 			info2!("Engine - waiting on shutdown ... OK");
 		} else {
 			// Tell [Kernel] to shutdown,
 			// and to not notify us.
-			try_send!(self.shutdown.try_send(false));
+			try_send!(self.shutdown, false);
 			info2!("Engine - async shutdown ... OK");
 		}
 	}
-}
\ No newline at end of file
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use crate::{
+		config::{Callbacks,InitConfig},
+		event::{Event,EventKind},
+		signal::Volume,
+	};
+	use std::time::Duration;
+
+	#[test]
+	fn drop_engine() {
+		let (send, recv) = crossbeam::channel::unbounded();
+		let mut callbacks = Callbacks::new();
+		callbacks.shutdown(move |state| send.send(state.volume).unwrap());
+
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			callbacks,
+			..InitConfig::DEFAULT
+		});
+		let events = engine.subscribe(EventKind::Shutdown.into());
+		engine.volume(Volume::new(0.5));
+
+		// `InitConfig::shutdown_blocking` is set, so
+		// everything has been handled once this returns.
+		drop(engine);
+
+		// The callback sees the final state.
+		assert_eq!(recv.try_recv().unwrap(), Volume::new(0.5));
+		assert!(matches!(
+			events.recv_timeout(Duration::from_secs(5)).unwrap(),
+			Event::Shutdown,
+		));
+		// `Caller` exited, so the subscriber is disconnected.
+		assert!(events.recv_timeout(Duration::from_secs(5)).is_err());
+	}
+}
@@ -0,0 +1,66 @@
+//! Event subscriptions.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	actor::caller::Subscriber,
+	engine::Engine,
+	extra_data::ExtraData,
+	event::{Event,EventFilter},
+};
+use crossbeam::channel::Receiver;
+
+#[allow(unused_imports)] // docs
+use crate::{
+	config::Callbacks,
+	event::EventKind,
+};
+
+//---------------------------------------------------------------------------------------------------- Engine Impl
+impl<Extra: ExtraData> Engine<Extra> {
+	#[must_use]
+	/// Subscribe to [`Event`]'s.
+	///
+	/// This returns a channel that receives all [`Event`]'s
+	/// that pass the `filter`, in the order they occurred.
+	///
+	/// Any amount of subscribers can exist at the same time, each with their
+	/// own filter, and they are independent of the [`Callbacks`] (which still work).
+	///
+	/// ## Ordering
+	/// - This returns once the subscription is active, so all [`Event`]'s
+	///   caused by signals sent after this function returns will be received
+	/// - [`Event`]'s that occurred before subscribing are not received
+	/// - [`Event::QueueEnd`] and errors are ordered relative to each other,
+	///   but not necessarily relative to the other [`Event`]'s
+	///
+	/// The channel is unbounded, so [`Event`]'s are never dropped if the
+	/// receiver is slow. Consider filtering [`EventKind::Elapsed`] if it isn't needed.
+	///
+	/// Dropping the [`Receiver`] unsubscribes. If there are no subscribers,
+	/// the [`Engine`] does not spend any time creating [`Event`]'s.
+	///
+	/// ```rust,ignore
+	/// # use sansan::{*,event::*};
+	/// let engine = Engine::<()>::init(Default::default());
+	///
+	/// // Only receive playback state changes.
+	/// let filter = EventFilter::NONE
+	///     .with(EventKind::Playing)
+	///     .with(EventKind::Paused);
+	/// let events = engine.subscribe(filter);
+	///
+	/// std::thread::spawn(move || {
+	///     while let Ok(event) = events.recv() {
+	///         println!("{event:?}");
+	///     }
+	/// });
+	/// ```
+	pub fn subscribe(&self, filter: EventFilter) -> Receiver<Event<Extra>> {
+		self.handle.subscribe(filter)
+	}
+
+	/// Register a [`Subscriber`], returning once it is active.
+	pub(crate) fn subscribe_with(&self, filter: EventFilter, subscriber: Subscriber<Extra>) {
+		self.handle.subscribe_with(filter, subscriber);
+	}
+}
@@ -43,4 +43,18 @@ impl From<symphonia::core::errors::Error> for DecodeError {
 			E::SeekError(_) | E::ResetRequired => Self::Unknown,
 		}
 	}
+}
+
+impl Clone for DecodeError {
+	/// [`std::io::Error`] is not [`Clone`], so [`DecodeError::Io`]
+	/// is cloned with the same [`std::io::ErrorKind`] and message.
+	fn clone(&self) -> Self {
+		match self {
+			Self::Decode(s)      => Self::Decode(s),
+			Self::Unsupported(s) => Self::Unsupported(s),
+			Self::Limit(s)       => Self::Limit(s),
+			Self::Io(e)          => Self::Io(std::io::Error::new(e.kind(), e.to_string())),
+			Self::Unknown        => Self::Unknown,
+		}
+	}
 }
\ No newline at end of file
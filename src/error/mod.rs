@@ -26,4 +26,10 @@ mod output;
 pub use output::OutputError;
 
 mod source;
-pub use source::SourceError;
\ No newline at end of file
+pub use source::SourceError;
+
+mod render;
+pub use render::RenderError;
+
+mod session;
+pub use session::SessionError;
\ No newline at end of file
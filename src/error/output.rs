@@ -1,5 +1,8 @@
 //! TODO
 
+//----------------------------------------------------------------------------------------------- use
+use crate::output::Glitch;
+
 //----------------------------------------------------------------------------------------------- AudioOutput Errors
 /// Error that occurs when attempting to
 /// write an audio buffer to the hardware/server.
@@ -8,7 +11,9 @@
 /// - Audio device was unplugged
 /// - Audio server disconnected/killed
 /// - Audio buffer spec is mismatched with the audio hardware/server
-#[derive(thiserror::Error, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode))]
+#[derive(thiserror::Error, Clone, Debug, PartialEq)]
 pub enum OutputError {
 	#[error("audio stream was closed")]
 	/// The audio stream was closed.
@@ -18,6 +23,11 @@ pub enum OutputError {
 	/// The audio hardware/server is unavailable.
 	DeviceUnavailable,
 
+	#[error("no audio output backend is available")]
+	/// None of the selected audio output backends
+	/// were compiled in, or none were selected at all.
+	BackendUnavailable,
+
 	#[error("audio format is invalid or unsupported")]
 	/// The audio format is invalid or unsupported.
 	InvalidFormat,
@@ -45,9 +55,44 @@ pub enum OutputError {
 	/// e.g, a duration of `0`.
 	InvalidSpec,
 
+	#[error("audio output glitch: {0}")]
+	/// An audible glitch occurred, e.g. an underrun.
+	///
+	/// Unlike the other errors, this is not fatal, playback
+	/// continues and the [`ErrorAction`](crate::config::ErrorAction)
+	/// is _not_ acted upon, see [`Glitch`] for more details.
+	Glitch(Glitch),
+
 	#[error("unknown error: {0}")]
 	/// An unknown or very specific error occurred.
 	///
 	/// The `str` will contain more information.
 	Unknown(std::borrow::Cow<'static, str>),
-}
\ No newline at end of file
+}
+
+// `Unknown` can't be borrow-decoded as `'static`, so this is
+// written manually, it matches the layout of the derived `Encode`.
+#[cfg(feature = "bincode")]
+impl bincode::Decode for OutputError {
+	fn decode<D: bincode::de::Decoder>(decoder: &mut D) -> Result<Self, bincode::error::DecodeError> {
+		Ok(match <u32 as bincode::Decode>::decode(decoder)? {
+			0 => Self::StreamClosed,
+			1 => Self::DeviceUnavailable,
+			2 => Self::BackendUnavailable,
+			3 => Self::InvalidFormat,
+			4 => Self::Write,
+			5 => Self::InvalidChannels,
+			6 => Self::InvalidSampleRate,
+			7 => Self::InvalidSpec,
+			8 => Self::Glitch(bincode::Decode::decode(decoder)?),
+			9 => Self::Unknown(std::borrow::Cow::Owned(bincode::Decode::decode(decoder)?)),
+			found => return Err(bincode::error::DecodeError::UnexpectedVariant {
+				type_name: "OutputError",
+				allowed: &bincode::error::AllowedEnumVariants::Range { min: 0, max: 9 },
+				found,
+			}),
+		})
+	}
+}
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(OutputError);
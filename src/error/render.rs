@@ -0,0 +1,32 @@
+//! TODO
+
+//---------------------------------------------------------------------------------------------------- Render Errors
+#[allow(unused_imports)] // docs
+use crate::render::Render;
+use crate::error::{SourceError,DecodeError};
+
+#[derive(thiserror::Error, Debug)]
+/// Errors when rendering with [`Render`]
+///
+/// Unlike playback through the `Engine`, rendering
+/// has no one to report errors to "later", so
+/// any unrecoverable error stops the render.
+///
+/// Corrupt packets are still skipped, the same way the `Engine` does.
+pub enum RenderError {
+	#[error("there were no sources to render")]
+	/// [`Render::sources`] was empty, or the starting index was out of bounds
+	Empty,
+
+	#[error("failed to load source: {0}")]
+	/// A [`crate::source::Source`] failed to load
+	Source(#[from] SourceError),
+
+	#[error("failed to decode source: {0}")]
+	/// A [`crate::source::Source`] failed to decode
+	Decode(#[from] DecodeError),
+
+	#[error("output file io error: {0}")]
+	/// Error occurred while writing the output file
+	Io(#[from] std::io::Error),
+}
@@ -0,0 +1,36 @@
+//! Session errors.
+
+//---------------------------------------------------------------------------------------------------- Use
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	state::Session,
+};
+
+//---------------------------------------------------------------------------------------------------- SessionError
+#[derive(thiserror::Error, Debug)]
+/// Errors when saving or loading a [`Session`]
+///
+/// This is returned by [`Engine::save_session`], [`Session::save`] and [`Session::load`].
+pub enum SessionError {
+	#[error("session file IO error: {0}")]
+	/// Error occurred while reading/writing the session file
+	Io(#[from] std::io::Error),
+
+	#[error("not a sansan session file")]
+	/// The data did not start with [`Session::MAGIC`]
+	Header,
+
+	#[error("unsupported session version: {0}")]
+	/// The session was written by a newer (or unknown)
+	/// version of the format than [`Session::VERSION`]
+	Version(u16),
+
+	#[error("failed to encode session: {0}")]
+	/// The session could not be encoded
+	Encode(String),
+
+	#[error("failed to decode session: {0}")]
+	/// The session data was malformed
+	Decode(String),
+}
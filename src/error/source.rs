@@ -43,4 +43,34 @@ pub enum SourceError {
 	#[error("failed to find codec n_frames")]
     /// The audio codec did not specify the number of frames
 	Frames,
+}
+
+impl Clone for SourceError {
+	/// [`std::io::Error`] and [`symphonia`]'s errors are not [`Clone`],
+	/// so they are cloned with the same kind and message.
+	fn clone(&self) -> Self {
+		use symphonia::core::errors::{Error as E, SeekErrorKind as K};
+
+		match self {
+			Self::File(e) => Self::File(std::io::Error::new(e.kind(), e.to_string())),
+			Self::Probe(e) => Self::Probe(match e {
+				E::IoError(e)       => E::IoError(std::io::Error::new(e.kind(), e.to_string())),
+				E::DecodeError(s)   => E::DecodeError(s),
+				E::Unsupported(s)   => E::Unsupported(s),
+				E::LimitError(s)    => E::LimitError(s),
+				E::ResetRequired    => E::ResetRequired,
+				E::SeekError(kind)  => E::SeekError(match kind {
+					K::Unseekable   => K::Unseekable,
+					K::ForwardOnly  => K::ForwardOnly,
+					K::OutOfRange   => K::OutOfRange,
+					K::InvalidTrack => K::InvalidTrack,
+				}),
+			}),
+			Self::Decoder(e)  => Self::Decoder(e.clone()),
+			Self::Current     => Self::Current,
+			Self::SampleRate  => Self::SampleRate,
+			Self::TimeBase    => Self::TimeBase,
+			Self::Frames      => Self::Frames,
+		}
+	}
 }
\ No newline at end of file
@@ -0,0 +1,169 @@
+//! Events.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+use crate::{
+	extra_data::ExtraData,
+	error::{DecodeError,OutputError,SourceError},
+	signal::{Repeat,Shuffle,SignalError,Volume},
+	source::Source,
+	state::Current,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::{Callbacks,RuntimeConfig},
+	state::AudioState,
+};
+
+//---------------------------------------------------------------------------------------------------- Event
+/// An event that occurred in the [`Engine`]
+///
+/// These are received from [`Engine::subscribe`].
+///
+/// Events describe _what_ changed, the full
+/// [`AudioState`] can be read as usual.
+#[derive(Clone,Debug)]
+pub enum Event<Extra: ExtraData> {
+	/// The [`Current`] was set to a new track (or restarted),
+	/// or `None` if there is no longer a track set.
+	SourceChanged(Option<Current<Extra>>),
+
+	/// This [`Source`] stopped being the [`Current`] track, and why.
+	///
+	/// This is emitted before the corresponding [`Event::SourceChanged`].
+	SourceEnded(Source<Extra>, SourceEnd),
+
+	/// Playback started or resumed.
+	Playing,
+
+	/// Playback was paused or stopped.
+	Paused,
+
+	/// The [`Current`] track was seeked to this [`Current::elapsed`].
+	Seeked(f32),
+
+	/// The [`AudioState::queue`] was mutated, e.g. tracks
+	/// were added, removed, shuffled, or the queue was cleared.
+	QueueChanged,
+
+	/// The [`AudioState::queue`] was shuffled with this [`Shuffle`].
+	///
+	/// This is emitted after the corresponding [`Event::QueueChanged`].
+	Shuffled(Shuffle),
+
+	/// The volume was changed.
+	VolumeChanged(Volume),
+
+	/// The repeat mode was changed.
+	RepeatChanged(Repeat),
+
+	/// The last track in the queue ended.
+	///
+	/// This is emitted at the same time as [`Callbacks::queue_end`].
+	QueueEnd,
+
+	/// Playback progressed to this [`Current::elapsed`].
+	///
+	/// This is emitted every [`RuntimeConfig::elapsed_refresh_rate`].
+	Elapsed(f32),
+
+	/// A [`DecodeError`] occurred, see [`Callbacks::error_decode`].
+	ErrorDecode(DecodeError),
+
+	/// A [`SourceError`] occurred, see [`Callbacks::error_source`].
+	ErrorSource(SourceError),
+
+	/// An [`OutputError`] occurred, see [`Callbacks::error_output`].
+	ErrorOutput(OutputError),
+
+	/// A signal sent with [`Engine::send`] failed, see [`Callbacks::error_signal`].
+	ErrorSignal(SignalError),
+
+	/// The [`Engine`] is shutting down, no more events will be received.
+	Shutdown,
+}
+
+impl<Extra: ExtraData> Event<Extra> {
+	#[must_use]
+	/// Returns the [`EventKind`] of this [`Event`].
+	///
+	/// ```rust
+	/// # use sansan::event::*;
+	/// assert_eq!(Event::<()>::Playing.kind(), EventKind::Playing);
+	/// assert_eq!(Event::<()>::Seeked(1.0).kind(), EventKind::Seeked);
+	/// ```
+	pub const fn kind(&self) -> EventKind {
+		match self {
+			Self::SourceChanged(_) => EventKind::SourceChanged,
+			Self::SourceEnded(..)  => EventKind::SourceEnded,
+			Self::Playing          => EventKind::Playing,
+			Self::Paused           => EventKind::Paused,
+			Self::Seeked(_)        => EventKind::Seeked,
+			Self::QueueChanged     => EventKind::QueueChanged,
+			Self::Shuffled(_)      => EventKind::Shuffled,
+			Self::VolumeChanged(_) => EventKind::VolumeChanged,
+			Self::RepeatChanged(_) => EventKind::RepeatChanged,
+			Self::QueueEnd         => EventKind::QueueEnd,
+			Self::Elapsed(_)       => EventKind::Elapsed,
+			Self::ErrorDecode(_)   => EventKind::ErrorDecode,
+			Self::ErrorSource(_)   => EventKind::ErrorSource,
+			Self::ErrorOutput(_)   => EventKind::ErrorOutput,
+			Self::ErrorSignal(_)   => EventKind::ErrorSignal,
+			Self::Shutdown         => EventKind::Shutdown,
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- EventKind
+/// The kind of an [`Event`], without its data
+///
+/// This is used to filter which events are
+/// received, see [`EventFilter`](crate::event::EventFilter).
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(missing_docs)] // See `Event`.
+pub enum EventKind {
+	SourceChanged,
+	SourceEnded,
+	Playing,
+	Paused,
+	Seeked,
+	QueueChanged,
+	Shuffled,
+	VolumeChanged,
+	RepeatChanged,
+	QueueEnd,
+	Elapsed,
+	ErrorDecode,
+	ErrorSource,
+	ErrorOutput,
+	ErrorSignal,
+	Shutdown,
+}
+
+//---------------------------------------------------------------------------------------------------- SourceEnd
+/// Why a [`Source`] stopped being the [`Current`] track
+///
+/// See [`Event::SourceEnded`] and [`Callbacks::source_end`].
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SourceEnd {
+	/// The track played until its end.
+	Completed,
+	/// The track was stopped before its end, e.g. by
+	/// [`Engine::next`], [`Engine::clear`], or an error.
+	Skipped,
+}
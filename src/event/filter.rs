@@ -0,0 +1,97 @@
+//! Event filters.
+
+//---------------------------------------------------------------------------------------------------- use
+use crate::event::EventKind;
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	event::Event,
+};
+
+//---------------------------------------------------------------------------------------------------- EventFilter
+/// Which [`Event`]'s a subscriber receives
+///
+/// This is a set of [`EventKind`]'s, passed to [`Engine::subscribe`].
+///
+/// ```rust
+/// # use sansan::event::*;
+/// // Only receive playback state changes.
+/// let filter = EventFilter::NONE
+///     .with(EventKind::Playing)
+///     .with(EventKind::Paused);
+///
+/// assert!(filter.contains(EventKind::Playing));
+/// assert!(!filter.contains(EventKind::Elapsed));
+///
+/// // Or, everything except the frequent `Elapsed`.
+/// let filter = EventFilter::ALL.without(EventKind::Elapsed);
+/// assert!(filter.contains(EventKind::QueueEnd));
+/// assert!(!filter.contains(EventKind::Elapsed));
+///
+/// // Collecting from `EventKind`'s also works.
+/// let filter: EventFilter = [EventKind::Seeked, EventKind::QueueEnd].into_iter().collect();
+/// assert!(filter.contains(EventKind::Seeked));
+/// ```
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct EventFilter(u16);
+
+impl EventFilter {
+	/// Receive all events.
+	pub const ALL: Self = Self(u16::MAX);
+
+	/// Receive no events.
+	pub const NONE: Self = Self(0);
+
+	/// The bit representing an [`EventKind`].
+	const fn bit(kind: EventKind) -> u16 {
+		1 << kind as u16
+	}
+
+	#[must_use]
+	/// Also receive this [`EventKind`].
+	pub const fn with(self, kind: EventKind) -> Self {
+		Self(self.0 | Self::bit(kind))
+	}
+
+	#[must_use]
+	/// Do not receive this [`EventKind`].
+	pub const fn without(self, kind: EventKind) -> Self {
+		Self(self.0 & !Self::bit(kind))
+	}
+
+	#[must_use]
+	/// Does this filter receive this [`EventKind`]?
+	pub const fn contains(self, kind: EventKind) -> bool {
+		self.0 & Self::bit(kind) != 0
+	}
+}
+
+impl Default for EventFilter {
+	/// Same as [`EventFilter::ALL`].
+	fn default() -> Self {
+		Self::ALL
+	}
+}
+
+impl From<EventKind> for EventFilter {
+	fn from(kind: EventKind) -> Self {
+		Self::NONE.with(kind)
+	}
+}
+
+impl FromIterator<EventKind> for EventFilter {
+	fn from_iter<T: IntoIterator<Item = EventKind>>(iter: T) -> Self {
+		iter.into_iter().fold(Self::NONE, Self::with)
+	}
+}
+
+/// Static assertion to make sure every `EventKind` fits in the filter.
+const _: () = {
+	assert!(
+		<EventKind as strum::EnumCount>::COUNT <= u16::BITS as usize,
+		"EventFilter cannot represent every EventKind",
+	);
+};
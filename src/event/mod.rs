@@ -0,0 +1,16 @@
+//! Typed events emitted by the [`Engine`].
+//!
+//! This is an alternative to [`Callbacks`] that fits event-loop
+//! based applications (GUIs, TUIs, etc) better, see [`Engine::subscribe`].
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::Callbacks,
+};
+
+mod event;
+pub use event::{Event,EventKind,SourceEnd};
+
+mod filter;
+pub use filter::EventFilter;
@@ -3,7 +3,10 @@
 //---------------------------------------------------------------------------------------------------- Use
 use std::sync::{Arc,Barrier};
 use std::num::NonZeroUsize;
-use crate::macros::debug2;
+use crate::{
+	macros::{debug2,info2,warn2},
+	state::Priority,
+};
 
 //---------------------------------------------------------------------------------------------------- Shutdown
 /// The method called when `actor/`'s `init()`.
@@ -46,6 +49,101 @@ pub(crate) fn shutdown(
     debug2!("{actor_name} - shutdown ... OK");
 }
 
+//---------------------------------------------------------------------------------------------------- Priority
+/// Promote the current thread to real-time priority.
+///
+/// `frames` is how many frames the thread processes at a time
+/// at `sample_rate` (only used on macOS to set its time constraints).
+///
+/// This never fails, if the OS denies the promotion, the thread
+/// continues as normal and `RealtimeHandle::priority()` is `Priority::Denied`.
+///
+/// The returned handle must be kept for the thread's lifetime,
+/// dropping it demotes the thread back to normal priority.
+#[cold]
+#[inline(never)]
+pub(crate) fn realtime(
+    actor_name: &'static str,
+    frames: usize,
+    sample_rate: u32,
+) -> RealtimeHandle {
+    let frames = u32::try_from(frames).unwrap_or(u32::MAX);
+
+    let handle = match audio_thread_priority::promote_current_thread_to_real_time(frames, sample_rate) {
+        Ok(handle) => {
+            info2!("{actor_name} - promoted to real-time priority ({frames} frames, {sample_rate}hz)");
+            Some(handle)
+        },
+        Err(error) => {
+            warn2!("{actor_name} - real-time priority denied, continuing with normal priority: {error}");
+            None
+        },
+    };
+
+    RealtimeHandle {
+        actor_name,
+        params: (frames, sample_rate),
+        handle,
+    }
+}
+
+/// The current thread's real-time promotion, from `realtime()`.
+///
+/// This must not be sent to another thread,
+/// it (de)promotes the thread it is used on.
+pub(crate) struct RealtimeHandle {
+    /// The name of the actor promoted, for logging.
+    actor_name: &'static str,
+    /// The `frames` and `sample_rate` we were promoted with.
+    params: (u32, u32),
+    /// `None` if the OS denied the promotion.
+    handle: Option<audio_thread_priority::RtPriorityHandle>,
+}
+
+impl RealtimeHandle {
+    /// Did the OS allow the promotion?
+    pub(crate) const fn priority(&self) -> Priority {
+        if self.handle.is_some() {
+            Priority::Realtime
+        } else {
+            Priority::Denied
+        }
+    }
+
+    #[cold]
+    #[inline(never)]
+    /// Re-promote the thread if its deadlines (`frames` or `sample_rate`)
+    /// changed since it was last promoted, returning the new `Priority`.
+    ///
+    /// Returns `None` if nothing changed.
+    pub(crate) fn update(&mut self, frames: usize, sample_rate: u32) -> Option<Priority> {
+        let params = (u32::try_from(frames).unwrap_or(u32::MAX), sample_rate);
+        if params == self.params {
+            return None;
+        }
+
+        debug2!("{} - re-promoting, {:?} -> {params:?}", self.actor_name, self.params);
+        self.demote();
+        *self = realtime(self.actor_name, frames, sample_rate);
+        Some(self.priority())
+    }
+
+    /// Demote the thread back to normal priority, if it was promoted.
+    fn demote(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            if let Err(error) = audio_thread_priority::demote_current_thread_from_real_time(handle) {
+                warn2!("{} - couldn't demote from real-time priority: {error}", self.actor_name);
+            }
+        }
+    }
+}
+
+impl Drop for RealtimeHandle {
+    fn drop(&mut self) {
+        self.demote();
+    }
+}
+
 //---------------------------------------------------------------------------------------------------- Threads
 /// Get the total amount of CPU threads.
 /// Returns at least 1.
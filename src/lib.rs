@@ -162,7 +162,9 @@ const _: () = {
 
 //---------------------------------------------------------------------------------------------------- Public API
 mod engine;
-pub use engine::Engine;
+pub use engine::{Engine,EngineHandle,EngineSend};
+#[cfg(feature = "async")]
+pub use engine::{AsyncEngine,Reply,EventStream};
 
 mod extra_data;
 pub use extra_data::ExtraData;
@@ -172,6 +174,9 @@ pub mod source;
 pub mod config;
 pub mod signal;
 pub mod error;
+pub mod output;
+pub mod render;
+pub mod event;
 
 // SOMEDAY:
 // This module is getting pretty big, and it's mostly
@@ -187,8 +192,7 @@ pub mod error;
 //---------------------------------------------------------------------------------------------------- Private Usage
 mod actor;
 mod macros;
-mod output;
-mod resampler;
+pub mod resampler;
 mod free;
 
 //---------------------------------------------------------------------------------------------------- Test Init Helpers
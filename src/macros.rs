@@ -110,4 +110,19 @@ macro_rules! trace2 {
         // ::std::println!("TRACE | {}", ::std::format_args!($($arg)+));
     }};
 }
-pub(crate) use trace2;
\ No newline at end of file
+pub(crate) use trace2;
+
+//---------------------------------------------------------------------------------------------------- Stats
+/// Update `AtomicStats`, but only if the `stats` feature is enabled.
+///
+/// The body is not compiled at all if the feature is disabled,
+/// so it may (and must) only be used in statement position.
+macro_rules! stats {
+    ($($body:tt)+) => {
+        #[cfg(feature = "stats")]
+        {
+            $($body)+
+        }
+    };
+}
+pub(crate) use stats;
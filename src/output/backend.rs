@@ -0,0 +1,433 @@
+//! Audio output backend selection.
+//!
+//! This file defines the public `OutputBackend` enum used to select
+//! a backend at runtime, and the internal `AudioOutputBackend` enum
+//! which wraps all compiled-in backends and implements `AudioOutput`
+//! by dispatching to whichever backend was successfully opened
+//! (or the user's `Sink`).
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	output::{AudioOutput,AudioOutputDummy,AudioOutputFile,AudioOutputCustom,OutputDevice,OpenConfig,SampleFormat,Sink},
+	render::RenderSpec,
+	output::constants::AUDIO_SAMPLE_BUFFER_LEN,
+	error::OutputError,
+	resampler::Resampler,
+	signal::Volume,
+	macros::{info2,warn2},
+};
+use symphonia::core::audio::{AudioBuffer,Channels,SignalSpec,SampleBuffer};
+use crossbeam::channel::Sender;
+use std::sync::{Arc,atomic::AtomicBool};
+use std::time::Duration;
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[cfg(feature = "cpal")]
+use crate::output::cpal::Cpal;
+#[cfg(feature = "cubeb")]
+use crate::output::cubeb::Cubeb;
+
+#[allow(unused_imports)] // docs
+use crate::config::InitConfig;
+
+//----------------------------------------------------------------------------------------------- OutputBackend
+/// An audio output backend.
+///
+/// This is used to select which audio backend(s)
+/// `sansan` will use, see [`InitConfig::output_backends`].
+///
+/// All variants always exist, but only the backends
+/// that were compiled in can actually be used, see
+/// [`OutputBackend::compiled`] and [`OutputBackend::available`].
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OutputBackend {
+	/// [`cubeb`](https://github.com/mozilla/cubeb-rs), enabled with the `cubeb` feature.
+	Cubeb,
+	/// [`cpal`](https://github.com/RustAudio/cpal), enabled with the `cpal` feature.
+	Cpal,
+	/// A fake backend that plays audio to nowhere (in real-time).
+	///
+	/// This is always available.
+	Dummy,
+	/// A backend that writes audio to a file (in real-time).
+	///
+	/// The "device" for this backend is the path of the file,
+	/// e.g. [`InitConfig::output_device`] set to `/tmp/sansan.wav`.
+	///
+	/// The exact stream that would be sent to an audio device
+	/// (post-volume, post-resample) is written as a 32-bit float
+	/// WAV file, or if the file extension is `pcm`, `raw` or `f32`,
+	/// raw interleaved 32-bit little-endian float PCM.
+	///
+	/// The file is created upon the first sample written. If a file
+	/// already exists at the path, if the audio specification changes
+	/// (e.g. a track with a different sample rate), or if a WAV file
+	/// reaches its 4 GiB limit, a new file is rolled to with the
+	/// path `{stem}_{n}.{extension}`, where `n` starts at `1`.
+	///
+	/// This backend has no devices to list, and
+	/// has no default device, a path must be given.
+	///
+	/// This is always available.
+	File,
+	/// A user supplied [`Sink`], see [`InitConfig::output_sink`].
+	///
+	/// This cannot be opened from [`InitConfig::output_backends`],
+	/// it is used (only) when [`InitConfig::output_sink`] is set.
+	Custom,
+}
+
+impl OutputBackend {
+	/// All the backends, in the default order of preference.
+	pub const ALL: [Self; 5] = [Self::Cubeb, Self::Cpal, Self::Dummy, Self::File, Self::Custom];
+
+	/// The default (ordered) list of backends to try.
+	///
+	/// The 1st backend is the same one `sansan` used before backends
+	/// could be selected at runtime, the other real backend is the fallback:
+	///
+	/// | Enabled features         | Order            |
+	/// |--------------------------|------------------|
+	/// | `dummy`                  | `dummy`          |
+	/// | `cpal`                   | `cpal`, `cubeb`  |
+	/// | neither (`cubeb`)        | `cubeb`, `cpal`  |
+	///
+	/// Backends that were not compiled in are skipped when opening.
+	pub const DEFAULT: &'static [Self] = {
+		cfg_if::cfg_if! {
+			if #[cfg(any(test, feature = "dummy"))] {
+				&[Self::Dummy]
+			} else if #[cfg(feature = "cpal")] {
+				&[Self::Cpal, Self::Cubeb]
+			} else {
+				&[Self::Cubeb, Self::Cpal]
+			}
+		}
+	};
+
+	#[must_use]
+	/// Was this backend compiled into `sansan`?
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert!(OutputBackend::Dummy.compiled());
+	/// ```
+	pub const fn compiled(self) -> bool {
+		match self {
+			Self::Cubeb => cfg!(feature = "cubeb"),
+			Self::Cpal  => cfg!(feature = "cpal"),
+			Self::Dummy | Self::File | Self::Custom => true,
+		}
+	}
+
+	#[must_use]
+	/// Returns all the backends that were compiled into `sansan`.
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert!(OutputBackend::available().contains(&OutputBackend::Dummy));
+	/// ```
+	pub fn available() -> Vec<Self> {
+		Self::ALL.into_iter().filter(|b| b.compiled()).collect()
+	}
+}
+
+//----------------------------------------------------------------------------------------------- AudioOutputBackend
+/// All the compiled-in `AudioOutput` backends.
+///
+/// This is what `Audio` actually uses, it implements `AudioOutput`
+/// by dispatching to whichever backend was successfully opened.
+pub(crate) enum AudioOutputBackend<R: Resampler> {
+	#[cfg(feature = "cubeb")]
+	/// `cubeb` backend.
+	Cubeb(Cubeb<R>),
+	#[cfg(feature = "cpal")]
+	/// `cpal` backend.
+	Cpal(Cpal<R>),
+	/// `dummy` backend.
+	Dummy(AudioOutputDummy<R>),
+	/// `file` backend.
+	File(AudioOutputFile<R>),
+	/// User supplied `Sink`.
+	Custom(AudioOutputCustom<R>),
+}
+
+/// Call `$fn` on the inner backend.
+macro_rules! dispatch {
+	($self:expr, $x:ident => $fn:expr) => {
+		match $self {
+			#[cfg(feature = "cubeb")]
+			Self::Cubeb($x) => $fn,
+			#[cfg(feature = "cpal")]
+			Self::Cpal($x) => $fn,
+			Self::Dummy($x) => $fn,
+			Self::File($x) => $fn,
+			Self::Custom($x) => $fn,
+		}
+	};
+}
+
+impl<R: Resampler> AudioOutputBackend<R> {
+	/// List all the output devices of `backend`.
+	pub(crate) fn devices_backend(backend: OutputBackend) -> Result<Vec<OutputDevice>, OutputError> {
+		match backend {
+			#[cfg(feature = "cubeb")]
+			OutputBackend::Cubeb => Cubeb::<R>::devices(),
+			#[cfg(feature = "cpal")]
+			OutputBackend::Cpal => Cpal::<R>::devices(),
+			OutputBackend::Dummy => AudioOutputDummy::<R>::devices(),
+			OutputBackend::File => AudioOutputFile::<R>::devices(),
+			OutputBackend::Custom => AudioOutputCustom::<R>::devices(),
+			#[allow(unreachable_patterns)]
+			_ => Err(OutputError::BackendUnavailable),
+		}
+	}
+
+	/// The default output device of `backend`, see [`AudioOutput::default_device`].
+	pub(crate) fn default_device_backend(backend: OutputBackend) -> Option<String> {
+		match backend {
+			#[cfg(feature = "cubeb")]
+			OutputBackend::Cubeb => Cubeb::<R>::default_device(),
+			#[cfg(feature = "cpal")]
+			OutputBackend::Cpal => Cpal::<R>::default_device(),
+			OutputBackend::Dummy => AudioOutputDummy::<R>::default_device(),
+			OutputBackend::File => AudioOutputFile::<R>::default_device(),
+			OutputBackend::Custom => AudioOutputCustom::<R>::default_device(),
+			#[allow(unreachable_patterns)]
+			_ => None,
+		}
+	}
+
+	#[cold]
+	#[inline(never)]
+	/// Wrap a user's `Sink`, see `AudioOutputCustom::new()`.
+	///
+	/// This uses the same initial spec as `AudioOutput::dummy()`,
+	/// the real spec is set upon the first audio buffer.
+	pub(crate) fn custom(sink: Box<dyn Sink + Send>) -> (Self, Result<(), OutputError>) {
+		let spec = SignalSpec {
+			rate: 44_100,
+			channels: Channels::FRONT_LEFT | Channels::FRONT_RIGHT,
+		};
+		let (output, result) = AudioOutputCustom::new(sink, spec, 4096);
+		(Self::Custom(output), result)
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `AudioOutput` Impl
+impl<R: Resampler> AudioOutput for AudioOutputBackend<R> {
+	type R = R;
+
+	fn signal_spec(&self) -> SignalSpec {
+		dispatch!(self, x => x.signal_spec())
+	}
+
+	fn duration(&self) -> u64 {
+		dispatch!(self, x => x.duration())
+	}
+
+	fn backend(&self) -> OutputBackend {
+		dispatch!(self, x => x.backend())
+	}
+
+	fn into_inner(self) -> (
+		SampleBuffer<f32>,
+		Vec<f32>,
+		Arc<AtomicBool>,
+		Option<R>,
+	) {
+		dispatch!(self, x => x.into_inner())
+	}
+
+	fn devices() -> Result<Vec<OutputDevice>, OutputError> {
+		// Use the first compiled-in default backend.
+		let backend = OutputBackend::DEFAULT
+			.iter()
+			.copied()
+			.find(|b| b.compiled())
+			.unwrap_or(OutputBackend::Dummy);
+
+		Self::devices_backend(backend)
+	}
+
+	#[cold]
+	#[inline(never)]
+	fn try_open_backends(
+		backends: &[OutputBackend],
+		device: Option<&str>,
+		signal_spec: SignalSpec,
+		duration: symphonia::core::units::Duration,
+		disable_device_switch: bool,
+		open_config: OpenConfig,
+		sample_buf: SampleBuffer<f32>,
+		samples: Vec<f32>,
+		discarding: Arc<AtomicBool>,
+		resampler: Option<R>,
+	) -> Result<Self, OutputError> {
+		// The buffers are moved into the 1st attempt,
+		// if that fails, fresh ones are allocated.
+		let mut sample_buf = Some(sample_buf);
+		let mut samples    = Some(samples);
+		let mut resampler  = resampler;
+
+		let mut error = OutputError::BackendUnavailable;
+
+		for backend in backends.iter().copied().filter(|b| b.compiled()) {
+			let sample_buf = sample_buf.take().unwrap_or_else(|| SampleBuffer::new(duration, signal_spec));
+			let samples    = samples.take().unwrap_or_else(|| Vec::with_capacity(AUDIO_SAMPLE_BUFFER_LEN));
+			let discarding = Arc::clone(&discarding);
+			let resampler  = resampler.take();
+
+			macro_rules! open {
+				($backend:ident, $variant:ident) => {
+					$backend::try_open(
+						device,
+						signal_spec,
+						duration,
+						disable_device_switch,
+						open_config,
+						sample_buf,
+						samples,
+						discarding,
+						resampler,
+					).map(Self::$variant)
+				};
+			}
+
+			let result = match backend {
+				#[cfg(feature = "cubeb")]
+				OutputBackend::Cubeb => open!(Cubeb, Cubeb),
+				#[cfg(feature = "cpal")]
+				OutputBackend::Cpal => open!(Cpal, Cpal),
+				OutputBackend::Dummy => open!(AudioOutputDummy, Dummy),
+				OutputBackend::File => open!(AudioOutputFile, File),
+				#[allow(unreachable_patterns)]
+				_ => continue,
+			};
+
+			match result {
+				Ok(output) => {
+					info2!("AudioOutput - audio output backend: [{backend}]");
+					return Ok(output);
+				},
+				Err(e) => {
+					warn2!("AudioOutput - backend [{backend}] failed: {e}");
+					error = e;
+				},
+			}
+		}
+
+		Err(error)
+	}
+
+	fn try_open(
+		device: Option<&str>,
+		signal_spec: SignalSpec,
+		duration: symphonia::core::units::Duration,
+		disable_device_switch: bool,
+		open_config: OpenConfig,
+		sample_buf: SampleBuffer<f32>,
+		samples: Vec<f32>,
+		discarding: Arc<AtomicBool>,
+		resampler: Option<R>,
+	) -> Result<Self, OutputError> {
+		Self::try_open_backends(
+			OutputBackend::DEFAULT,
+			device,
+			signal_spec,
+			duration,
+			disable_device_switch,
+			open_config,
+			sample_buf,
+			samples,
+			discarding,
+			resampler,
+		)
+	}
+
+	fn write_audio(
+		&mut self,
+		audio: AudioBuffer<f32>,
+		volume: Volume,
+		to_gc: &Sender<AudioBuffer<f32>>,
+	) -> Result<(), OutputError> {
+		dispatch!(self, x => x.write_audio(audio, volume, to_gc))
+	}
+
+	fn respec(&mut self, spec: SignalSpec, duration: u64) -> Option<Result<(), OutputError>> {
+		dispatch!(self, x => x.respec(spec, duration))
+	}
+
+	fn sample_format(&self) -> SampleFormat {
+		dispatch!(self, x => x.sample_format())
+	}
+
+	fn latency(&self) -> Option<Duration> {
+		dispatch!(self, x => x.latency())
+	}
+
+	fn underruns(&self) -> usize {
+		dispatch!(self, x => x.underruns())
+	}
+
+	fn resampling(&mut self) -> bool {
+		dispatch!(self, x => x.resampling())
+	}
+
+	fn bit_perfect(&self) -> bool {
+		dispatch!(self, x => x.bit_perfect())
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `Sink` Impl
+impl<R: Resampler> Sink for AudioOutputBackend<R> {
+	fn spec(&self) -> RenderSpec {
+		dispatch!(self, x => Sink::spec(x))
+	}
+
+	fn set_spec(&mut self, spec: RenderSpec) -> Result<(), OutputError> {
+		dispatch!(self, x => x.set_spec(spec))
+	}
+
+	fn write(&mut self, samples: &[f32]) -> Result<(), OutputError> {
+		dispatch!(self, x => Sink::write(x, samples))
+	}
+
+	fn play(&mut self) -> Result<(), OutputError> {
+		dispatch!(self, x => x.play())
+	}
+
+	fn pause(&mut self) -> Result<(), OutputError> {
+		dispatch!(self, x => x.pause())
+	}
+
+	fn flush(&mut self) {
+		dispatch!(self, x => x.flush());
+	}
+
+	fn discard(&mut self) {
+		dispatch!(self, x => x.discard());
+	}
+}
+
+//----------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::output::AUDIO_OUTPUT_BACKEND;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn default() {
+		// The default backend is tried first.
+		assert_eq!(OutputBackend::DEFAULT[0].as_ref(), AUDIO_OUTPUT_BACKEND);
+	}
+}
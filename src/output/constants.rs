@@ -3,11 +3,16 @@
 //----------------------------------------------------------------------------------------------- Constants
 /// The most common sample rate to fallback to if we cannot
 /// poll the audio devices "preferred" audio sample rate.
-pub(super) const SAMPLE_RATE_FALLBACK: u32 = 44_100;
+pub(crate) const SAMPLE_RATE_FALLBACK: u32 = 44_100;
 
-/// The amount of milliseconds our audio buffer is between us and the `AudioOutput`
-/// backend's callback function (if the user does not provide a value).
-pub(super) const AUDIO_MILLISECOND_BUFFER_FALLBACK: usize = 50;
+/// Common sample rates.
+///
+/// Some backends only report a _range_ of supported sample rates,
+/// so this is used to report which of these are within that range.
+pub(super) const SAMPLE_RATES_COMMON: [u32; 13] = [
+	8_000, 11_025, 16_000, 22_050, 32_000, 44_100, 48_000,
+	88_200, 96_000, 176_400, 192_000, 352_800, 384_000,
+];
 
 /// The amount of raw [f32] samples held in our [Vec<f32>] sample buffer.
 ///
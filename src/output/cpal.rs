@@ -10,7 +10,7 @@
 //----------------------------------------------------------------------------------------------- use
 use crate::{
 	signal::Volume,
-	output::AudioOutput,
+	output::{AudioOutput,AudioWrite,Sink,OutputDevice,OutputBackend,OpenConfig,SampleFormat,Quantizer,impl_sink,impl_write,frames_to_duration},
 	resampler::Resampler,
 	error::OutputError,
 };
@@ -18,15 +18,16 @@ use symphonia::core::audio::{AudioBuffer,SignalSpec, SampleBuffer,Signal};
 use crossbeam::channel::{Sender,Receiver};
 use std::num::NonZeroUsize;
 use std::borrow::Cow;
+use std::time::Duration;
 use std::sync::{
 	Arc,
-	atomic::{AtomicBool,Ordering},
+	atomic::{AtomicBool,AtomicUsize,Ordering},
 };
 use crate::macros::{recv,send,try_send,try_recv,trace2,debug2,error2};
 use crate::output::constants::{
-	AUDIO_MILLISECOND_BUFFER_FALLBACK,
 	SAMPLE_RATE_FALLBACK,
 	AUDIO_SAMPLE_BUFFER_LEN,
+	SAMPLE_RATES_COMMON,
 };
 use cpal::traits::{DeviceTrait,StreamTrait,HostTrait};
 
@@ -60,6 +61,14 @@ pub(crate) struct Cpal<R: Resampler> {
 	spec: SignalSpec,
 	/// Duration this output was opened with.
 	duration: u64,
+	/// The sample format the stream was opened with.
+	format: SampleFormat,
+	/// The negotiated latency (our channel + the device's buffer).
+	latency: Duration,
+	/// How many times the audio callback underran.
+	underruns: Arc<AtomicUsize>,
+	/// Are samples sent to the device untouched?
+	bit_perfect: bool,
 
 	/// The resampler.
 	resampler: Option<R>,
@@ -73,12 +82,34 @@ pub(crate) struct Cpal<R: Resampler> {
 	discard: Arc<AtomicBool>,
 }
 
+//----------------------------------------------------------------------------------------------- `AudioWrite` Impl
+impl<R: Resampler> AudioWrite for Cpal<R> {
+	type E = cpal::StreamError;
+
+	fn write_pre(&mut self) -> (
+		&mut Option<Self::R>,   // Our resampler (none == no resampling needed)
+		&mut SampleBuffer<f32>, // A local buffer used for sample processing
+		&mut Vec<f32>,          // A local buffer of the _end result_ samples (potentially after resampling)
+		&Sender<f32>,           // Channel to send sample to audio backend
+		&Receiver<Self::E>,     // Channel to potentially receive an error, after writing the sample
+	) {
+		(
+			&mut self.resampler,
+			&mut self.sample_buf,
+			&mut self.samples,
+			&self.sender,
+			&self.error,
+		)
+	}
+}
+
 //----------------------------------------------------------------------------------------------- `AudioOutput` Impl
 impl<R: Resampler> AudioOutput for Cpal<R> {
-	type E = cpal::StreamError;
 	type R = R;
 
-	fn spec(&self) -> SignalSpec {
+	impl_write!();
+
+	fn signal_spec(&self) -> SignalSpec {
 		self.spec
 	}
 
@@ -86,6 +117,10 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 		self.duration
 	}
 
+	fn backend(&self) -> OutputBackend {
+		OutputBackend::Cpal
+	}
+
 	fn into_inner(self) -> (
 		SampleBuffer<f32>,
 		Vec<f32>,
@@ -100,58 +135,96 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 		)
 	}
 
-	fn write_pre(&mut self) -> (
-		&mut Option<Self::R>,   // Our resampler (none == no resampling needed)
-		&mut SampleBuffer<f32>, // A local buffer used for sample processing
-		&mut Vec<f32>,          // A local buffer of the _end result_ samples (potentially after resampling)
-		&Sender<f32>,           // Channel to send sample to audio backend
-		&Receiver<Self::E>,     // Channel to potentially receive an error, after writing the sample
-	) {
-		(
-			&mut self.resampler,
-			&mut self.sample_buf,
-			&mut self.samples,
-			&self.sender,
-			&self.error,
-		)
+	fn sample_format(&self) -> SampleFormat {
+		self.format
 	}
 
-	fn flush(&mut self) {
-		debug2!("AudioOutput - flush()");
+	fn bit_perfect(&self) -> bool {
+		self.bit_perfect
+	}
 
-		while !self.sender.is_empty() {
-			std::thread::yield_now();
-		}
+	fn latency(&self) -> Option<Duration> {
+		Some(self.latency)
 	}
 
-	fn discard(&mut self) {
-		debug2!("AudioOutput - discard()");
+	fn underruns(&self) -> usize {
+		self.underruns.load(Ordering::Relaxed)
+	}
 
-		self.discard.store(true, Ordering::Release);
+	#[cold]
+	#[inline(never)]
+	fn devices() -> Result<Vec<OutputDevice>, OutputError> {
+		debug2!("AudioOutput - devices()");
 
-		while !self.sender.is_empty() {
-			std::thread::yield_now();
+		let host = cpal::default_host();
+
+		// `cpal` has no concept of a device ID
+		// separate from the name, so use the name.
+		let default = host
+			.default_output_device()
+			.and_then(|d| d.name().ok());
+
+		let mut vec = vec![];
+		for device in host.output_devices()? {
+			let Ok(name) = device.name() else {
+				continue;
+			};
+
+			let mut sample_rates = vec![];
+			let mut channels = vec![];
+
+			// `cpal` reports sample rates as a range, so
+			// report the common sample rates within that range.
+			if let Ok(configs) = device.supported_output_configs() {
+				for config in configs {
+					if sample_format(config.sample_format()).is_none() {
+						continue;
+					}
+
+					let range = config.min_sample_rate().0..=config.max_sample_rate().0;
+					for rate in SAMPLE_RATES_COMMON {
+						if range.contains(&rate) {
+							sample_rates.push(rate);
+						}
+					}
+					channels.push(config.channels());
+				}
+			}
+
+			sample_rates.sort_unstable();
+			sample_rates.dedup();
+			channels.sort_unstable();
+			channels.dedup();
+
+			vec.push(OutputDevice {
+				default: default.as_ref() == Some(&name),
+				id: name.clone(),
+				name,
+				sample_rates,
+				channels,
+			});
 		}
 
-		// INVARIANT The callback thread sets `discard` back to `false`.
+		debug2!("AudioOutput - devices: {vec:#?}");
+		Ok(vec)
 	}
 
 	#[cold]
 	#[inline(never)]
 	#[allow(clippy::unwrap_in_result)]
 	fn try_open(
-		name: String,
+		device: Option<&str>,
 		signal_spec: SignalSpec,
 		duration: symphonia::core::units::Duration,
 		disable_device_switch: bool,
-		buffer_milliseconds: Option<u8>,
+		open_config: OpenConfig,
 		sample_buf: SampleBuffer<f32>,
 		samples: Vec<f32>,
 		discard: Arc<AtomicBool>,
 		resampler: Option<R>,
 	) -> Result<Self, OutputError> {
 		debug2!("AudioOutput - try_open()");
-		debug2!("AudioOutput - signal_spec: {signal_spec:?}, duration: {duration}, disable_device_switch: {disable_device_switch}, buffer_milliseconds: {buffer_milliseconds:?}");
+		debug2!("AudioOutput - device: {device:?}, signal_spec: {signal_spec:?}, duration: {duration}, disable_device_switch: {disable_device_switch}, latency: {:?}", open_config.latency);
 
 		let channels = std::cmp::max(signal_spec.channels.count(), 2);
 		// For the resampler.
@@ -187,8 +260,12 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 		// Get default host.
 		let host = cpal::default_host();
 
-		// Get the default audio output device.
-		let Some(device) = host.default_output_device() else {
+		// Get the selected audio output device, or the default one.
+		let device = match device {
+			Some(id) => host.output_devices()?.find(|d| d.name().is_ok_and(|name| name == id)),
+			None => host.default_output_device(),
+		};
+		let Some(device) = device else {
 			return Err(OutputError::DeviceUnavailable);
 		};
 
@@ -199,70 +276,92 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 		};
 		debug2!("AudioOutput - device_config:\n{config:#?}");
 
-		// SOMEDAY: support non-f32.
-		if config.sample_format() != cpal::SampleFormat::F32 {
-			return Err(OutputError::InvalidFormat);
-		}
+		// Use the requested sample format if the device
+		// supports it, else, whatever the device prefers.
+		let requested = open_config.sample_format.filter(|format| {
+			device.supported_output_configs().is_ok_and(|mut configs| {
+				configs.any(|c| sample_format(c.sample_format()) == Some(*format))
+			})
+		});
+		let format = match requested.or_else(|| sample_format(config.sample_format())) {
+			Some(format) => format,
+			None => return Err(OutputError::InvalidFormat),
+		};
+		debug2!("AudioOutput - sample_format: {format}, dither: {}", open_config.dither());
+
+		// If we want bit-perfect output, check if the
+		// device can be opened at the audio's native spec.
+		let native = open_config.bit_perfect && device.supported_output_configs().is_ok_and(|mut configs| {
+			configs.any(|c| {
+				sample_format(c.sample_format()) == Some(format) &&
+				usize::from(c.channels()) == channel_count.get() &&
+				(c.min_sample_rate().0..=c.max_sample_rate().0).contains(&sample_rate)
+			})
+		});
+		debug2!("AudioOutput - bit_perfect: {}, native spec supported: {native}", open_config.bit_perfect);
+
+		// Ask the device for a buffer as close to our latency as it allows,
+		// if it doesn't report a range, we can only use its default.
+		let device_frames = match config.buffer_size() {
+			cpal::SupportedBufferSize::Range { min, max } => {
+				let frames = u32::try_from(open_config.latency.frames(config.sample_rate().0)).unwrap_or(u32::MAX);
+				Some(frames.clamp(*min, *max))
+			},
+			cpal::SupportedBufferSize::Unknown => None,
+		};
+		let buffer_size = match device_frames {
+			Some(frames) => cpal::BufferSize::Fixed(frames),
+			None => cpal::BufferSize::Default,
+		};
 
 		// Output audio stream config.
-		let config = if cfg!(windows) {
-			config.config()
+		let config = if cfg!(windows) && !native {
+			cpal::StreamConfig {
+				buffer_size,
+				..config.config()
+			}
 		} else {
 			cpal::StreamConfig {
 				channels: channel_count.get() as cpal::ChannelCount,
 				sample_rate: cpal::SampleRate(sample_rate),
-				buffer_size: cpal::BufferSize::Default, // TODO: add our own buffer size
+				buffer_size,
 			}
 		};
 		debug2!("AudioOutput - config:\n{config:#?}");
 
-		// The `cubeb` <-> AudioOutput channel will hold up to 50ms of audio data by default.
-		let buffer_milliseconds = match buffer_milliseconds {
-			Some(u) => u as usize,
-			None => AUDIO_MILLISECOND_BUFFER_FALLBACK,
-		};
-		let channel_len = ((buffer_milliseconds * sample_rate as usize) / 1000) * 2;
-		debug2!("AudioOutput - buffer_milliseconds: {buffer_milliseconds}, channel_len: {channel_len}");
+		// Even if the native spec was supported, only
+		// report bit-perfect if the samples are untouched.
+		let bit_perfect = open_config.bit_perfect(&signal_spec, config.sample_rate.0, usize::from(config.channels), format);
+		debug2!("AudioOutput - bit_perfect: {bit_perfect}");
+
+		// The `cpal` <-> AudioOutput channel holds the requested latency,
+		// the device's buffer (if known) adds onto that.
+		let frames = open_config.latency.frames(config.sample_rate.0);
+		let channel_len = frames * usize::from(config.channels);
+		let latency = frames_to_duration(frames + device_frames.unwrap_or(0) as usize, config.sample_rate.0);
+		debug2!("AudioOutput - channel_len: {channel_len}, device_frames: {device_frames:?}, latency: {latency:?}");
 
 		let (sender, receiver)       = crossbeam::channel::bounded(channel_len);
 		let (error_send, error_recv) = crossbeam::channel::unbounded();
 
-		// The actual callback `cpal` will call when polling for audio data.
-		let receiver_clone = receiver.clone();
-		let discard_clone = Arc::clone(&discard);
-		let data_callback = move |output: &mut [f32], _: &cpal::OutputCallbackInfo| {
-			trace2!("AudioOutput - data callback, output.len(): {}", output.len());
-
-			// We received a "discard" signal, discard and return ASAP.
-			if discard_clone.load(Ordering::Acquire) {
-				while receiver.try_recv().is_ok() {}
-				// INVARIANT: we are responsible for setting this to `false`.
-				discard_clone.store(false, Ordering::Release);
-				return;
-			}
-
-			// Fill output buffer while there are messages in the channel.
-			for o in output.iter_mut() {
-				if let Ok(audio) = receiver.try_recv() {
-					*o = audio;
-				} else {
-					break;
-				}
-			}
-
-			// TODO: test if we need this.
-			// Mute any remaining samples.
-			// let written = output.len();
-			// output[written..].fill(0.0);
-			// trace2!("AudioOutput - data callback, written: {written}");
-		};
 		// The callback `cpal` will call when errors occur.
 		let error_callback = move |error: cpal::StreamError| {
 			drop(error_send.try_send(error));
 		};
 
-		// Build the audio stream.
-		let stream = match device.build_output_stream(&config, data_callback, error_callback, None) {
+		// Build the audio stream, integer formats
+		// are quantized within the audio callback.
+		let mut quantizer = Quantizer::new(format, open_config.dither(), channel_count.get());
+		let underruns = Arc::new(AtomicUsize::new(0));
+		let shared = (receiver, Arc::clone(&discard), Arc::clone(&underruns));
+		let stream = match format {
+			SampleFormat::F32 => build_stream(&device, &config, shared, error_callback, |s| s),
+			SampleFormat::I16 => build_stream(&device, &config, shared, error_callback, move |s| quantizer.quantize_i16(s)),
+			SampleFormat::I32 => build_stream(&device, &config, shared, error_callback, move |s| quantizer.quantize(s)),
+			// `cpal` has no packed 24-bit format.
+			SampleFormat::I24 => return Err(OutputError::InvalidFormat),
+		};
+		let stream = match stream {
 			Ok(s) => s,
 			Err(err) => return Err(err.into()),
 		};
@@ -287,6 +386,7 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 		} else {
 			debug2!("AudioOutput - creating resampler, {sample_rate_input} -> {sample_rate_target}");
 			Some(R::new(
+				open_config.resampler,
 				sample_rate_input,
 				sample_rate_target,
 				duration_non_zero,
@@ -301,12 +401,105 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 			resampler,
 			spec: signal_spec,
 			duration,
+			format,
+			latency,
+			underruns,
+			bit_perfect,
 			sample_buf,
 			samples,
 			discard,
 		})
 	}
 
+	fn default_device() -> Option<String> {
+		// `cpal` does not follow the default device, so it must be polled.
+		cpal::default_host()
+			.default_output_device()
+			.and_then(|d| d.name().ok())
+	}
+}
+
+//----------------------------------------------------------------------------------------------- Stream
+/// Map a `cpal` sample format to ours, `None` if unsupported.
+const fn sample_format(format: cpal::SampleFormat) -> Option<SampleFormat> {
+	match format {
+		cpal::SampleFormat::F32 => Some(SampleFormat::F32),
+		cpal::SampleFormat::I16 => Some(SampleFormat::I16),
+		cpal::SampleFormat::I32 => Some(SampleFormat::I32),
+		_ => None,
+	}
+}
+
+#[cold]
+#[inline(never)]
+/// Build an output stream of samples `T`.
+///
+/// The `f32` samples received from `Audio` are converted with `convert`.
+///
+/// `shared` is the channel to receive samples from, the discard
+/// signal, and the underrun counter shared with `Cpal`.
+fn build_stream<T, F>(
+	device: &cpal::Device,
+	config: &cpal::StreamConfig,
+	(receiver, discard, underruns): (Receiver<f32>, Arc<AtomicBool>, Arc<AtomicUsize>),
+	error_callback: impl FnMut(cpal::StreamError) + Send + 'static,
+	mut convert: F,
+) -> Result<cpal::Stream, cpal::BuildStreamError>
+where
+	T: cpal::SizedSample,
+	F: FnMut(f32) -> T + Send + 'static,
+{
+	// Was the last callback completely filled?
+	//
+	// Only running out of audio _after_ having enough is counted as an
+	// underrun, the channel is expected to be empty before the first
+	// write after `play()`, and after pausing or the queue ending.
+	let mut filled = false;
+
+	// The actual callback `cpal` will call when polling for audio data.
+	let data_callback = move |output: &mut [T], _: &cpal::OutputCallbackInfo| {
+		trace2!("AudioOutput - data callback, output.len(): {}", output.len());
+
+		// We received a "discard" signal, discard and return ASAP.
+		if discard.load(Ordering::Acquire) {
+			while receiver.try_recv().is_ok() {}
+			// INVARIANT: we are responsible for setting this to `false`.
+			discard.store(false, Ordering::Release);
+			return;
+		}
+
+		// Fill output buffer while there are messages in the channel.
+		let mut written = 0;
+		for o in output.iter_mut() {
+			if let Ok(audio) = receiver.try_recv() {
+				*o = convert(audio);
+				written += 1;
+			} else {
+				break;
+			}
+		}
+
+		// We ran out of audio, play silence
+		// instead of whatever was in the buffer.
+		if written < output.len() {
+			output[written..].fill(T::EQUILIBRIUM);
+			if filled {
+				trace2!("AudioOutput - data callback, underrun: {written}/{}", output.len());
+				underruns.fetch_add(1, Ordering::Relaxed);
+			}
+			filled = false;
+		} else {
+			filled = true;
+		}
+	};
+
+	device.build_output_stream(config, data_callback, error_callback, None)
+}
+
+//----------------------------------------------------------------------------------------------- `Sink` Impl
+impl<R: Resampler> Sink for Cpal<R> {
+	impl_sink!();
+
 	fn play(&mut self) -> Result<(), OutputError> {
 		debug2!("AudioOutput - play()");
 		self.stream.play().map_err(Into::into)
@@ -316,6 +509,32 @@ impl<R: Resampler> AudioOutput for Cpal<R> {
 		debug2!("AudioOutput - pause()");
 		self.stream.pause().map_err(Into::into)
 	}
+
+	fn flush(&mut self) {
+		debug2!("AudioOutput - flush()");
+
+		while !self.sender.is_empty() {
+			std::thread::yield_now();
+		}
+	}
+
+	fn discard(&mut self) {
+		debug2!("AudioOutput - discard()");
+
+		// Leftover frames from a partial chunk must not
+		// end up in front of whatever is written next.
+		if let Some(resampler) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+
+		self.discard.store(true, Ordering::Release);
+
+		while !self.sender.is_empty() {
+			std::thread::yield_now();
+		}
+
+		// INVARIANT The callback thread sets `discard` back to `false`.
+	}
 }
 
 //----------------------------------------------------------------------------------------------- Error re-map
@@ -330,6 +549,15 @@ impl From<cpal::DefaultStreamConfigError> for OutputError {
 	}
 }
 
+impl From<cpal::DevicesError> for OutputError {
+	fn from(error: cpal::DevicesError) -> Self {
+		use cpal::DevicesError as E;
+		match error {
+			E::BackendSpecific { err } => Self::Unknown(Cow::Owned(err.description)),
+		}
+	}
+}
+
 impl From<cpal::StreamError> for OutputError {
 	fn from(error: cpal::StreamError) -> Self {
 		use cpal::StreamError as E;
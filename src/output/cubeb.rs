@@ -10,32 +10,35 @@
 //----------------------------------------------------------------------------------------------- use
 use crate::{
 	signal::Volume,
-	audio::output::AudioOutput,
+	audio::output::{AudioOutput,Sink,OutputDevice,OutputBackend,OpenConfig,SampleFormat,impl_sink,frames_to_duration},
 	audio::resampler::Resampler,
 	error::OutputError,
 	macros::{recv,send,try_send,try_recv,debug2,trace2,error2},
 	audio::constants::{
-		AUDIO_MILLISECOND_BUFFER_FALLBACK,
 		SAMPLE_RATE_FALLBACK,
 		AUDIO_SAMPLE_BUFFER_LEN,
+		SAMPLE_RATES_COMMON,
 	},
 };
 use symphonia::core::audio::{AudioBuffer,SignalSpec, SampleBuffer,Signal};
-use cubeb::StereoFrame;
+use cubeb::{MonoFrame,StereoFrame};
 use crossbeam::channel::{Sender,Receiver};
 use std::num::NonZeroUsize;
 use std::borrow::Cow;
+use std::time::Duration;
 use std::sync::{
 	Arc,
-	atomic::{AtomicBool,Ordering},
+	atomic::{AtomicBool,AtomicUsize,Ordering},
 };
 
 //----------------------------------------------------------------------------------------------- Cubeb
 /// TODO
 pub(crate) struct Cubeb<R: Resampler> {
-	/// We send audio data to this channel which
+	/// We send audio frames to this channel which
 	/// the audio stream will receive and write.
-	sender: Sender<StereoFrame<f32>>,
+	///
+	/// Mono streams only read the first sample.
+	sender: Sender<[f32; 2]>,
 
 	/// A signal to `cubeb` that is should ignore
 	/// and discard all sent audio samples and
@@ -47,7 +50,7 @@ pub(crate) struct Cubeb<R: Resampler> {
 	drained: Receiver<()>,
 
 	/// The actual audio stream.
-	stream: cubeb::Stream<StereoFrame<f32>>,
+	stream: Stream,
 
 	/// `cubeb` sent us an error (we don't know what it was).
 	error: Receiver<()>,
@@ -59,6 +62,10 @@ pub(crate) struct Cubeb<R: Resampler> {
 	spec: SignalSpec,
 	/// Duration this output was opened with.
 	duration: u64,
+	/// The negotiated latency (our channel + `cubeb`'s buffer).
+	latency: Duration,
+	/// How many times the audio callback underran.
+	underruns: Arc<AtomicUsize>,
 
 	/// A re-usable sample buffer.
 	sample_buf: SampleBuffer<f32>,
@@ -67,6 +74,8 @@ pub(crate) struct Cubeb<R: Resampler> {
 
 	/// How many channels?
 	channels: usize,
+	/// Are samples sent to the device untouched?
+	bit_perfect: bool,
 
 	/// Are we currently playing?
 	playing: bool,
@@ -74,7 +83,7 @@ pub(crate) struct Cubeb<R: Resampler> {
 
 //----------------------------------------------------------------------------------------------- `AudioOutput` Impl
 impl<R: Resampler> AudioOutput for Cubeb<R> {
-	fn write(
+	fn write_audio(
 		&mut self,
 		mut audio: AudioBuffer<f32>,
 		to_gc:  &Sender<AudioBuffer<f32>>,
@@ -131,22 +140,14 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 		};
 
 		trace2!("AudioOutput(cubeb) - sending {} samples to backend", samples.len());
-		// Send audio data to cubeb.
-		// Duplicate channel data if mono, else split left/right.
+		// Send audio frames to cubeb, the stream was
+		// opened with our channel count, so this is 1:1.
 		//
 		// This hangs until we've sent all the samples, which
 		// most likely take a while as [cubeb] will have a
 		// backlog of previous samples.
-		if self.channels == 2 {
-			samples.chunks_exact(2).for_each(|f| {
-				let l = f[0];
-				let r = f[1];
-				send!(self.sender, StereoFrame { l, r });
-			});
-		} else {
-			for f in samples {
-				send!(self.sender, StereoFrame { l: *f, r: *f });
-			}
+		for frame in samples.chunks_exact(self.channels) {
+			send!(self.sender, [frame[0], frame[self.channels - 1]]);
 		}
 
 		// Send garbage to GC.
@@ -168,67 +169,94 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 		}
 	}
 
-	fn flush(&mut self) {
-		debug2!("AudioOutput(cubeb) - flush()");
-
-		if !self.playing {
-			return;
-		}
-
-		// We're playing, which means `cubeb` is calling
-		// the callback over and over again, which means
-		// it will eventually play all audio data.
-		//
-		// `cubeb` will tell us when it has drained,
-		// so hang until it has.
-		recv!(self.drained);
+	fn resampling(&mut self) -> bool {
+		self.resampler.is_some()
 	}
 
-	fn discard(&mut self) {
-		debug2!("AudioOutput(cubeb) - discard()");
+	fn bit_perfect(&self) -> bool {
+		self.bit_perfect
+	}
 
-		if !self.playing {
-			return;
-		}
+	#[cold]
+	#[inline(never)]
+	fn devices() -> Result<Vec<OutputDevice>, OutputError> {
+		debug2!("AudioOutput(cubeb) - devices()");
+
+		let ctx = cubeb::Context::init(None, None)
+			.map_err(|e| map_error(&e, "cubeb context error"))?;
+		let collection = ctx
+			.enumerate_devices(cubeb::DeviceType::OUTPUT)
+			.map_err(|e| map_error(&e, "cubeb device enumeration error"))?;
+
+		let vec: Vec<OutputDevice> = collection.iter().filter_map(|info| {
+			let id = info.device_id()?.to_string();
+			let name = info.friendly_name().unwrap_or(&id).to_string();
+
+			// `cubeb` reports sample rates as a range, so
+			// report the common sample rates within that range.
+			let range = info.min_rate()..=info.max_rate();
+			let mut sample_rates: Vec<u32> = SAMPLE_RATES_COMMON
+				.into_iter()
+				.filter(|rate| range.contains(rate))
+				.collect();
+			if !sample_rates.contains(&info.default_rate()) {
+				sample_rates.push(info.default_rate());
+				sample_rates.sort_unstable();
+			}
 
-		// INVARIANT:
-		// Bounded channels, [try_*]
-		// methods not applicable.
+			let channels = (1..=info.max_channels())
+				.filter_map(|c| u16::try_from(c).ok())
+				.collect();
 
-		if self.discard.is_empty() {
-			send!(self.discard, ());
-		}
+			Some(OutputDevice {
+				name,
+				id,
+				sample_rates,
+				channels,
+				default: !info.preferred().is_empty(),
+			})
+		}).collect();
 
-		// Wait until cubeb has drained.
-		recv!(self.drained);
+		debug2!("AudioOutput(cubeb) - devices: {vec:#?}");
+		Ok(vec)
 	}
 
 	#[cold]
 	#[inline(never)]
 	#[allow(clippy::unwrap_in_result)]
 	fn try_open(
-		name: String,
+		device: Option<&str>,
 		signal_spec: SignalSpec,
 		duration: symphonia::core::units::Duration,
 		disable_device_switch: bool,
-		buffer_milliseconds: Option<u8>,
+		open_config: OpenConfig,
 	) -> Result<Self, OutputError> {
 		debug2!("AudioOutput(cubeb) - try_open()");
-		debug2!("AudioOutput(cubeb) - signal_spec: {signal_spec:?} duration: {duration}, disable_device_switch: {disable_device_switch}, buffer_milliseconds: {buffer_milliseconds:?}");
+		debug2!("AudioOutput(cubeb) - device: {device:?}, signal_spec: {signal_spec:?} duration: {duration}, disable_device_switch: {disable_device_switch}, latency: {:?}", open_config.latency);
 
-		let channels = std::cmp::max(signal_spec.channels.count(), 2);
+		// The device is opened with the audio's channels, there is no
+		// up/down-mixing, so anything `cubeb` can't be given is rejected.
+		//
+		// TODO: support more than stereo.
+		let channels = signal_spec.channels.count();
+		if channels > 2 {
+			return Err(OutputError::InvalidChannels);
+		}
 		// For the resampler.
 		let Some(channel_count) = NonZeroUsize::new(channels) else {
 			return Err(OutputError::InvalidChannels);
 		};
 
-		// INVARIANT:
+		// The stream is always opened as `f32`, `cubeb` converts
+		// to the device's format itself, so any other requested
+		// format can't be honoured.
 		//
-		// Sample rate must be applied PER channel.
-		// `pulseaudio` and `cpal` backends do this for us
-		// but `cubeb` is explicit.
+		// TODO: support integer formats.
+		if open_config.sample_format.is_some_and(|f| f != SampleFormat::F32) {
+			return Err(OutputError::InvalidFormat);
+		}
+
 		let sample_rate = signal_spec.rate;
-		let sample_rate_cubeb = (sample_rate as usize * channels) as u32;
 		// For the resampler.
 		let Some(sample_rate_input) = NonZeroUsize::new(sample_rate as usize) else {
 			return Err(OutputError::InvalidChannels);
@@ -247,7 +275,6 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 
 		debug2!("AudioOutput(cubeb) - channel_count: {channel_count}, sample_rate: {sample_rate}, sample_rate_input: {sample_rate_input}");
 
-		// TODO: support more than stereo.
 		let layout = if channels == 2 {
 			cubeb::ChannelLayout::STEREO
 		} else {
@@ -260,14 +287,6 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 			cubeb::StreamPrefs::NONE
 		};
 
-		let params = cubeb::StreamParamsBuilder::new()
-			.format(cubeb::SampleFormat::Float32NE)
-			.rate(sample_rate_cubeb)
-			.channels(channels as u32) // OK: Is <= 2
-			.layout(layout)
-			.prefs(prefs)
-			.take();
-
 		// cubeb Context.
 		let ctx = match cubeb::Context::init(None, None) { // TODO: add names?
 			Ok(c) => c,
@@ -285,83 +304,90 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 			},
 		};
 
-		// The `cubeb` <-> AudioOutput channel will hold up to 20ms of audio data by default.
-		let buffer_milliseconds = match buffer_milliseconds {
-			Some(u) => u as usize,
-			None => AUDIO_MILLISECOND_BUFFER_FALLBACK,
+		// `cubeb` always converts to the device's rate itself, it can't be
+		// told to switch the device to the audio's rate, so we open the
+		// stream at the preferred rate and resample to it ourselves.
+		//
+		// This means `bit_perfect` is only possible when the audio's
+		// rate already is the device's preferred rate.
+		let sample_rate_target = ctx.preferred_sample_rate().unwrap_or(SAMPLE_RATE_FALLBACK);
+		let sample_rate_target = if sample_rate_target == 0 { SAMPLE_RATE_FALLBACK } else { sample_rate_target };
+		let bit_perfect = open_config.bit_perfect(&signal_spec, sample_rate_target, channels, SampleFormat::F32);
+		debug2!("AudioOutput(cubeb) - sample_rate_target: {sample_rate_target}, bit_perfect: {bit_perfect}");
+
+		// INVARIANT:
+		//
+		// Sample rate must be applied PER channel.
+		// `pulseaudio` and `cpal` backends do this for us
+		// but `cubeb` is explicit.
+		let sample_rate_cubeb = (sample_rate_target as usize * channels) as u32;
+		let params = cubeb::StreamParamsBuilder::new()
+			.format(cubeb::SampleFormat::Float32NE)
+			.rate(sample_rate_cubeb)
+			.channels(channels as u32) // OK: Is <= 2
+			.layout(layout)
+			.prefs(prefs)
+			.take();
+
+		// If a specific device was selected, find it.
+		//
+		// INVARIANT: `collection` must outlive the stream
+		// builder as the device ID points into it.
+		let collection = match device {
+			Some(_) => match ctx.enumerate_devices(cubeb::DeviceType::OUTPUT) {
+				Ok(c) => Some(c),
+				Err(e) => return Err(map_error(&e, "cubeb device enumeration error")),
+			},
+			None => None,
+		};
+		let devid = match (device, collection.as_ref()) {
+			(Some(id), Some(collection)) => {
+				let Some(info) = collection.iter().find(|i| i.device_id() == Some(id)) else {
+					return Err(OutputError::DeviceUnavailable);
+				};
+				Some(info.devid())
+			},
+			_ => None,
+		};
+
+		// Ask `cubeb` for a buffer matching our latency,
+		// although it won't go below the backend's minimum.
+		let frames = open_config.latency.frames(sample_rate_target);
+		let latency_frames = u32::try_from(frames).unwrap_or(u32::MAX);
+		let latency_frames = match ctx.min_latency(&params) {
+			Ok(min) => std::cmp::max(min, latency_frames),
+			Err(_) => latency_frames,
 		};
-		let channel_len = ((buffer_milliseconds * sample_rate as usize) / 1000) * channels;
-		debug2!("AudioOutput(cubeb) - buffer_milliseconds: {buffer_milliseconds}, channel_len: {channel_len}");
+
+		// The `cubeb` <-> AudioOutput channel holds the requested
+		// latency (in frames), `cubeb`'s own buffer adds onto that.
+		let channel_len = frames;
+		let latency = frames_to_duration(frames + latency_frames as usize, sample_rate_target);
+		debug2!("AudioOutput(cubeb) - channel_len: {channel_len}, latency_frames: {latency_frames}, latency: {latency:?}");
 
 		let (sender, receiver)           = crossbeam::channel::bounded(channel_len);
 		let (discard, discard_recv)      = crossbeam::channel::bounded(1);
 		let (drained_send, drained_recv) = crossbeam::channel::bounded(1);
 		let (error_send, error_recv)     = crossbeam::channel::unbounded();
 
-		// The actual audio stream.
-		let mut builder = cubeb::StreamBuilder::<StereoFrame<f32>>::new();
-		#[allow(clippy::cast_possible_wrap)]
-		builder
-			.name(name)
-			.default_output(&params)
-			.latency(1) // TODO: find a good value for this.
-			// The actual callback `cubeb` will
-			// call when polling for audio data.
-			.data_callback(move |_, output| {
-				trace2!("AudioOutput - data callback, output.len(): {}", output.len());
-
-				// We received a "discard" signal.
-				// Discard all audio and return ASAP.
-				if discard_recv.try_recv().is_ok() {
-					while receiver.try_recv().is_ok() {} // drain channel
-					return;
-				}
-
-				// Fill output buffer while there are
-				// messages in the channel.
-				for o in output.iter_mut() {
-					if let Ok(audio) = receiver.try_recv() {
-						*o = audio;
-					} else {
-						break;
-					}
-				}
-				// INVARIANT:
-				// We must tell cubeb how many bytes we wrote.
-				let written = output.len() as isize;
-				trace2!("AudioOutput - data callback, written: {written}");
-				written
-			})
-			// Cubeb calls this when the audio stream has changed
-			// states, e.g, play, pause, drained, error, etc.
-			.state_callback(move |state| {
-				use cubeb::State as S;
-
-				match state {
-					S::Drained => {
-						if drained_send.is_empty() {
-							send!(drained_send, ());
-						}
-					},
-					S::Error => drop(error_send.try_send(())),
-					S::Started | S::Stopped => {},
-				}
-			});
+		let underruns = Arc::new(AtomicUsize::new(0));
+		let shared = Shared {
+			receiver,
+			discard: discard_recv,
+			drained: drained_send,
+			error: error_send,
+			underruns: Arc::clone(&underruns),
+		};
 
-		let stream = match builder.init(&ctx) {
+		// The actual audio stream, opened with the audio's channel count.
+		let stream = if channels == 2 {
+			build_stream(&ctx, &params, devid, latency_frames, shared, |[l, r]| StereoFrame { l, r }).map(Stream::Stereo)
+		} else {
+			build_stream(&ctx, &params, devid, latency_frames, shared, |[m, _]| MonoFrame { m }).map(Stream::Mono)
+		};
+		let stream = match stream {
 			Ok(s) => s,
-			Err(e) => {
-				use cubeb::ErrorCode as E;
-				use OutputError as E2;
-
-				return Err(match e.code() {
-					E::DeviceUnavailable => E2::DeviceUnavailable,
-					E::InvalidFormat |
-					E::NotSupported  |
-					E::InvalidParameter => E2::InvalidFormat,
-					E::Error => E2::Unknown(Cow::Borrowed("cubeb init error")),
-				});
-			},
+			Err(e) => return Err(map_error(&e, "cubeb init error")),
 		};
 
 		// Ok, we have an audio stream,
@@ -371,18 +397,16 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 		// If the default device's preferred sample rate
 		// is not the same as the audio itself, we need
 		// a resampler.
-		let sample_rate_target = ctx.preferred_sample_rate().unwrap_or(SAMPLE_RATE_FALLBACK) as usize;
-		let sample_rate_target = match NonZeroUsize::new(sample_rate_target) {
-			Some(s) => s,
-			// SAFETY: input is non-zero.
-			None => NonZeroUsize::new(SAMPLE_RATE_FALLBACK as usize).unwrap(),
-		};
+		//
+		// SAFETY: `sample_rate_target` is non-zero.
+		let sample_rate_target = NonZeroUsize::new(sample_rate_target as usize).unwrap();
 		let resampler = if sample_rate_target == sample_rate_input {
 			debug2!("AudioOutput(cubeb) - skipping resampler, {sample_rate_input} == {sample_rate_target}");
 			None
 		} else {
 			debug2!("AudioOutput(cubeb) - creating resampler, {sample_rate_input} -> {sample_rate_target}");
 			Some(R::new(
+				open_config.resampler,
 				sample_rate_input,
 				sample_rate_target,
 				duration_non_zero,
@@ -403,13 +427,45 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 			resampler,
 			spec: signal_spec,
 			duration,
+			latency,
+			underruns,
 			sample_buf: SampleBuffer::new(duration, signal_spec),
 			samples: Vec::with_capacity(AUDIO_SAMPLE_BUFFER_LEN),
 			channels,
+			bit_perfect,
 			playing: false,
 		})
 	}
 
+	fn latency(&self) -> Option<Duration> {
+		Some(self.latency)
+	}
+
+	fn underruns(&self) -> usize {
+		self.underruns.load(Ordering::Relaxed)
+	}
+
+	fn is_playing(&mut self) -> bool {
+		self.playing
+	}
+
+	fn signal_spec(&self) -> &SignalSpec {
+		&self.spec
+	}
+
+	fn duration(&self) -> u64 {
+		self.duration
+	}
+
+	fn backend(&self) -> OutputBackend {
+		OutputBackend::Cubeb
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `Sink` Impl
+impl<R: Resampler> Sink for Cubeb<R> {
+	impl_sink!();
+
 	fn play(&mut self) -> Result<(), OutputError> {
 		use cubeb::ErrorCode as E;
 		use OutputError as E2;
@@ -440,15 +496,192 @@ impl<R: Resampler> AudioOutput for Cubeb<R> {
 		}
 	}
 
-	fn is_playing(&mut self) -> bool {
-		self.playing
+	fn flush(&mut self) {
+		debug2!("AudioOutput(cubeb) - flush()");
+
+		if !self.playing {
+			return;
+		}
+
+		// We're playing, which means `cubeb` is calling
+		// the callback over and over again, which means
+		// it will eventually play all audio data.
+		//
+		// `cubeb` will tell us when it has drained,
+		// so hang until it has.
+		recv!(self.drained);
 	}
 
-	fn spec(&self) -> &SignalSpec {
-		&self.spec
+	fn discard(&mut self) {
+		debug2!("AudioOutput(cubeb) - discard()");
+
+		// Leftover frames from a partial chunk must not
+		// end up in front of whatever is written next.
+		if let Some(resampler) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+
+		if !self.playing {
+			return;
+		}
+
+		// INVARIANT:
+		// Bounded channels, [try_*]
+		// methods not applicable.
+
+		if self.discard.is_empty() {
+			send!(self.discard, ());
+		}
+
+		// Wait until cubeb has drained.
+		recv!(self.drained);
 	}
+}
+//----------------------------------------------------------------------------------------------- Stream
+/// A `cubeb` stream, the frame type is
+/// the channel count it was opened with.
+enum Stream {
+	/// 1 channel.
+	Mono(cubeb::Stream<MonoFrame<f32>>),
+	/// 2 channels.
+	Stereo(cubeb::Stream<StereoFrame<f32>>),
+}
 
-	fn duration(&self) -> u64 {
-		self.duration
+impl Stream {
+	/// Start the stream.
+	fn start(&self) -> Result<(), cubeb::Error> {
+		match self {
+			Self::Mono(s) => s.start(),
+			Self::Stereo(s) => s.start(),
+		}
+	}
+
+	/// Stop the stream.
+	fn stop(&self) -> Result<(), cubeb::Error> {
+		match self {
+			Self::Mono(s) => s.stop(),
+			Self::Stereo(s) => s.stop(),
+		}
 	}
-}
\ No newline at end of file
+}
+
+/// The channels and counters moved into the stream callbacks.
+struct Shared {
+	/// Audio frames from `write_audio()`.
+	receiver: Receiver<[f32; 2]>,
+	/// Discard all audio signal.
+	discard: Receiver<()>,
+	/// Tell `Cubeb` the stream drained.
+	drained: Sender<()>,
+	/// Tell `Cubeb` the stream errored.
+	error: Sender<()>,
+	/// How many times the callback underran.
+	underruns: Arc<AtomicUsize>,
+}
+
+/// Build a `cubeb` stream of `F` frames, `frame` maps
+/// the frames received from `write_audio()` into `F`.
+fn build_stream<F: Send + 'static>(
+	ctx: &cubeb::Context,
+	params: &cubeb::StreamParams,
+	devid: Option<cubeb::DeviceId>,
+	latency_frames: u32,
+	shared: Shared,
+	frame: fn([f32; 2]) -> F,
+) -> Result<cubeb::Stream<F>, cubeb::Error> {
+	let Shared { receiver, discard, drained, error, underruns } = shared;
+
+	// Only running out of audio _after_ having enough is counted as an
+	// underrun, the channel is expected to be empty before the first
+	// write after `play()`, and after pausing or the queue ending.
+	let mut filled = false;
+
+	let mut builder = cubeb::StreamBuilder::<F>::new();
+	builder.name("sansan");
+	match devid {
+		Some(devid) => builder.output(devid, params),
+		None => builder.default_output(params),
+	};
+	#[allow(clippy::cast_possible_wrap)]
+	builder
+		.latency(latency_frames)
+		// The actual callback `cubeb` will
+		// call when polling for audio data.
+		.data_callback(move |_, output| {
+			trace2!("AudioOutput - data callback, output.len(): {}", output.len());
+
+			// We received a "discard" signal.
+			// Discard all audio and return ASAP.
+			if discard.try_recv().is_ok() {
+				while receiver.try_recv().is_ok() {} // drain channel
+				for o in output.iter_mut() {
+					*o = frame([0.0; 2]);
+				}
+				return output.len() as isize;
+			}
+
+			// Fill output buffer while there are
+			// messages in the channel.
+			let mut filled_len = 0;
+			for o in output.iter_mut() {
+				if let Ok(audio) = receiver.try_recv() {
+					*o = frame(audio);
+					filled_len += 1;
+				} else {
+					break;
+				}
+			}
+
+			// We ran out of audio, play silence.
+			if filled_len < output.len() {
+				for o in &mut output[filled_len..] {
+					*o = frame([0.0; 2]);
+				}
+				if filled {
+					underruns.fetch_add(1, Ordering::Relaxed);
+				}
+				filled = false;
+			} else {
+				filled = true;
+			}
+			// INVARIANT:
+			// We must tell cubeb how many bytes we wrote.
+			let written = output.len() as isize;
+			trace2!("AudioOutput - data callback, written: {written}");
+			written
+		})
+		// Cubeb calls this when the audio stream has changed
+		// states, e.g, play, pause, drained, error, etc.
+		.state_callback(move |state| {
+			use cubeb::State as S;
+
+			match state {
+				S::Drained => {
+					if drained.is_empty() {
+						send!(drained, ());
+					}
+				},
+				S::Error => drop(error.try_send(())),
+				S::Started | S::Stopped => {},
+			}
+		});
+
+	builder.init(ctx)
+}
+
+//----------------------------------------------------------------------------------------------- Error re-map
+/// Map a `cubeb` error into an `OutputError`.
+///
+/// `msg` is used for the generic `cubeb` error case.
+fn map_error(error: &cubeb::Error, msg: &'static str) -> OutputError {
+	use cubeb::ErrorCode as E;
+	use OutputError as E2;
+
+	match error.code() {
+		E::DeviceUnavailable => E2::DeviceUnavailable,
+		E::InvalidFormat |
+		E::NotSupported  |
+		E::InvalidParameter => E2::InvalidFormat,
+		E::Error => E2::Unknown(Cow::Borrowed(msg)),
+	}
+}
@@ -0,0 +1,266 @@
+//! Custom audio output.
+//!
+//! This file implements the abstract `AudioOutput`
+//! trait on top of a user supplied `Sink`.
+//!
+//! Unlike the other backends, there is no audio thread or
+//! channels involved, samples are written directly to the
+//! `Sink` from `Audio`, and the `Sink` is never re-opened,
+//! instead `Sink::set_spec()` is called on spec changes.
+//!
+//! For documentation on `AudioOutput`, see `output.rs`.
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	output::{AudioOutput,Sink,OutputDevice,OutputBackend,OpenConfig},
+	output::output::render_spec,
+	render::RenderSpec,
+	resampler::Resampler,
+	signal::Volume,
+	error::OutputError,
+	macros::{trace2,debug2,try_send},
+};
+use symphonia::core::audio::{AudioBuffer,SignalSpec,SampleBuffer,Signal};
+use crossbeam::channel::Sender;
+use std::sync::{Arc,atomic::AtomicBool};
+
+//----------------------------------------------------------------------------------------------- AudioOutputCustom
+/// `AudioOutput` that writes to a user's `Sink`.
+pub(crate) struct AudioOutputCustom<R: Resampler> {
+	/// The user's sink.
+	sink: Box<dyn Sink + Send>,
+	/// Audio spec the sink was last set to.
+	spec: SignalSpec,
+	/// Duration of the audio buffers.
+	duration: u64,
+	/// A re-usable sample buffer.
+	sample_buf: SampleBuffer<f32>,
+	/// A re-usable Vec of samples (unused, returned in `into_inner()`).
+	samples: Vec<f32>,
+	/// Unused, returned in `into_inner()`.
+	discarding: Arc<AtomicBool>,
+	/// Unused, custom sinks are never resampled.
+	resampler: Option<R>,
+}
+
+impl<R: Resampler> AudioOutputCustom<R> {
+	#[cold]
+	#[inline(never)]
+	/// Wrap `sink`, setting it to `spec`.
+	///
+	/// The `AudioOutputCustom` is returned even if
+	/// `Sink::set_spec()` fails, with the error.
+	pub(crate) fn new(
+		mut sink: Box<dyn Sink + Send>,
+		spec: SignalSpec,
+		duration: u64,
+	) -> (Self, Result<(), OutputError>) {
+		debug2!("AudioOutput(custom) - new(), spec: {spec:?}, duration: {duration}");
+
+		let result = sink.set_spec(render_spec(&spec));
+
+		let this = Self {
+			sink,
+			spec,
+			duration,
+			sample_buf: SampleBuffer::new(duration, spec),
+			samples: vec![],
+			discarding: Arc::new(AtomicBool::new(false)),
+			resampler: None,
+		};
+
+		(this, result)
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `AudioOutput` Impl
+impl<R: Resampler> AudioOutput for AudioOutputCustom<R> {
+	type R = R;
+
+	fn signal_spec(&self) -> SignalSpec {
+		self.spec
+	}
+
+	fn duration(&self) -> u64 {
+		self.duration
+	}
+
+	fn backend(&self) -> OutputBackend {
+		OutputBackend::Custom
+	}
+
+	fn into_inner(self) -> (
+		SampleBuffer<f32>,
+		Vec<f32>,
+		Arc<AtomicBool>,
+		Option<R>,
+	) {
+		(self.sample_buf, self.samples, self.discarding, self.resampler)
+	}
+
+	fn devices() -> Result<Vec<OutputDevice>, OutputError> {
+		// The sink is the device.
+		Ok(vec![])
+	}
+
+	fn try_open(
+		_: Option<&str>,
+		_: SignalSpec,
+		_: symphonia::core::units::Duration,
+		_: bool,
+		_: OpenConfig,
+		_: SampleBuffer<f32>,
+		_: Vec<f32>,
+		_: Arc<AtomicBool>,
+		_: Option<R>,
+	) -> Result<Self, OutputError> {
+		// Custom sinks are given to us in `InitConfig`, they can't be opened.
+		Err(OutputError::BackendUnavailable)
+	}
+
+	// There is no backend thread to send samples to so this
+	// isn't an `AudioWrite`, `write_audio()` writes to the sink directly.
+	fn write_audio(
+		&mut self,
+		mut audio: AudioBuffer<f32>,
+		volume: Volume,
+		to_gc: &Sender<AudioBuffer<f32>>,
+	) -> Result<(), OutputError> {
+		trace2!("AudioOutput(custom) - write_audio() with volume: {volume}");
+
+		if audio.frames() == 0 {
+			return Ok(());
+		}
+
+		let volume = volume.inner();
+		audio.transform(|f| f * volume);
+		self.sample_buf.copy_interleaved_typed(&audio);
+		let result = self.sink.write(self.sample_buf.samples());
+
+		try_send!(to_gc, audio);
+		result
+	}
+
+	fn respec(&mut self, spec: SignalSpec, duration: u64) -> Option<Result<(), OutputError>> {
+		debug2!("AudioOutput(custom) - respec(), spec: {spec:?}, duration: {duration}");
+
+		// The sink is kept as is, only the spec changes.
+		if let Err(e) = self.sink.set_spec(render_spec(&spec)) {
+			return Some(Err(e));
+		}
+		self.spec = spec;
+		self.duration = duration;
+		self.sample_buf = SampleBuffer::new(duration, spec);
+		if let Some(resampler) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+		Some(Ok(()))
+	}
+
+	fn resampling(&mut self) -> bool {
+		false
+	}
+
+	fn bit_perfect(&self) -> bool {
+		// The sink gets the samples as they were decoded.
+		true
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `Sink` Impl
+impl<R: Resampler> Sink for AudioOutputCustom<R> {
+	fn spec(&self) -> RenderSpec {
+		self.sink.spec()
+	}
+
+	fn set_spec(&mut self, spec: RenderSpec) -> Result<(), OutputError> {
+		self.sink.set_spec(spec)
+	}
+
+	fn write(&mut self, samples: &[f32]) -> Result<(), OutputError> {
+		self.sink.write(samples)
+	}
+
+	fn play(&mut self) -> Result<(), OutputError> {
+		debug2!("AudioOutput(custom) - play()");
+		self.sink.play()
+	}
+
+	fn pause(&mut self) -> Result<(), OutputError> {
+		debug2!("AudioOutput(custom) - pause()");
+		self.sink.pause()
+	}
+
+	fn flush(&mut self) {
+		debug2!("AudioOutput(custom) - flush()");
+		self.sink.flush();
+	}
+
+	fn discard(&mut self) {
+		debug2!("AudioOutput(custom) - discard()");
+
+		// Leftover frames from a partial chunk must not
+		// end up in front of whatever is written next.
+		if let Some(resampler) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+
+		self.sink.discard();
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn output_sink() {
+		use crate::{
+			config::InitConfig,
+			signal::{Add,AddMethod},
+		};
+		use std::sync::atomic::{AtomicUsize,Ordering};
+
+		/// Counts the samples written.
+		struct Counter(RenderSpec, Arc<AtomicUsize>);
+
+		impl Sink for Counter {
+			fn spec(&self) -> RenderSpec { self.0 }
+			fn set_spec(&mut self, spec: RenderSpec) -> Result<(), OutputError> {
+				self.0 = spec;
+				Ok(())
+			}
+			fn write(&mut self, samples: &[f32]) -> Result<(), OutputError> {
+				self.1.fetch_add(samples.len(), Ordering::Relaxed);
+				std::thread::sleep(std::time::Duration::from_millis(1));
+				Ok(())
+			}
+			fn play(&mut self) -> Result<(), OutputError> { Ok(()) }
+			fn pause(&mut self) -> Result<(), OutputError> { Ok(()) }
+			fn flush(&mut self) {}
+			fn discard(&mut self) {}
+		}
+
+		let count = Arc::new(AtomicUsize::new(0));
+		let sink = Counter(RenderSpec { sample_rate: 0, channels: 0 }, Arc::clone(&count));
+
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			output_sink: Some(Box::new(sink)),
+			..InitConfig::DEFAULT
+		});
+
+		engine.add(Add {
+			source: crate::tests::source(0),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		std::thread::sleep(std::time::Duration::from_millis(500));
+
+		assert!(count.load(Ordering::Relaxed) > 0);
+		assert_eq!(engine.output_backend(), Some(OutputBackend::Custom));
+		assert!(matches!(engine.output_device(None), Err(OutputError::BackendUnavailable)));
+	}
+}
@@ -0,0 +1,92 @@
+//! Audio output devices.
+
+//---------------------------------------------------------------------------------------------------- use
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::InitConfig,
+	output::devices,
+};
+
+//---------------------------------------------------------------------------------------------------- OutputDevice
+/// An audio output device.
+///
+/// This represents a device audio can be played back
+/// on, e.g, speakers, headphones, a USB DAC, etc.
+///
+/// These are returned by [`devices`], and can be selected with
+/// [`InitConfig::output_device`] or [`Engine::output_device`]
+/// by passing the device's [`OutputDevice::id`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+pub struct OutputDevice {
+	/// The human-readable name of the device.
+	pub name: String,
+
+	/// The identifier of this device within the audio backend.
+	///
+	/// This is the value used to select this device.
+	///
+	/// Depending on the backend, this may be the same as [`Self::name`].
+	pub id: String,
+
+	/// The sample rates this device supports.
+	///
+	/// This is sorted from lowest to highest.
+	///
+	/// This may be empty if the backend could not query the device.
+	pub sample_rates: Vec<u32>,
+
+	/// The channel counts this device supports.
+	///
+	/// This is sorted from lowest to highest.
+	///
+	/// This may be empty if the backend could not query the device.
+	pub channels: Vec<u16>,
+
+	/// Is this the system's default output device?
+	pub default: bool,
+}
+
+impl OutputDevice {
+	#[must_use]
+	/// Does this device support the sample rate `rate`?
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// let device = OutputDevice {
+	///     name: "Speakers".into(),
+	///     id: "speakers".into(),
+	///     sample_rates: vec![44_100, 48_000],
+	///     channels: vec![2],
+	///     default: true,
+	/// };
+	///
+	/// assert!(device.supports_sample_rate(48_000));
+	/// assert!(!device.supports_sample_rate(96_000));
+	/// ```
+	pub fn supports_sample_rate(&self, rate: u32) -> bool {
+		self.sample_rates.contains(&rate)
+	}
+
+	#[must_use]
+	/// Does this device support `channels` amount of channels?
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// let device = OutputDevice {
+	///     name: "Speakers".into(),
+	///     id: "speakers".into(),
+	///     sample_rates: vec![44_100, 48_000],
+	///     channels: vec![2],
+	///     default: true,
+	/// };
+	///
+	/// assert!(device.supports_channels(2));
+	/// assert!(!device.supports_channels(6));
+	/// ```
+	pub fn supports_channels(&self, channels: u16) -> bool {
+		self.channels.contains(&channels)
+	}
+}
@@ -15,7 +15,7 @@
 //----------------------------------------------------------------------------------------------- use
 use crate::{
 	signal::Volume,
-	output::AudioOutput,
+	output::{AudioOutput,AudioWrite,Sink,OutputDevice,OutputBackend,OpenConfig,SampleFormat,impl_sink,impl_write,frames_to_duration},
 	resampler::Resampler,
 	error::OutputError,
 };
@@ -28,16 +28,61 @@ use std::{
 	thread::{spawn,sleep},
 	sync::{
 		Arc,
-		atomic::{AtomicBool,Ordering},
+		RwLock,
+		atomic::{AtomicBool,AtomicUsize,Ordering},
 	},
 };
 use crate::macros::{recv,send,try_send,try_recv,trace2,debug2,error2};
 use crate::output::constants::{
-	AUDIO_MILLISECOND_BUFFER_FALLBACK,
 	SAMPLE_RATE_FALLBACK,
 	AUDIO_SAMPLE_BUFFER_LEN,
 };
 
+//----------------------------------------------------------------------------------------------- Devices
+/// The fake devices the dummy backend exposes.
+///
+/// `None` means the default devices in [`default_devices()`] are used.
+static DEVICES: RwLock<Option<Vec<OutputDevice>>> = RwLock::new(None);
+
+/// Incremented each time [`DEVICES`] is set.
+///
+/// Outputs compare this against the generation they last saw so that
+/// [`DEVICES`] is only read (on the audio thread) when it actually changed.
+static GENERATION: AtomicUsize = AtomicUsize::new(0);
+
+/// Set the fake devices the dummy backend exposes.
+pub(super) fn set_devices(devices: Vec<OutputDevice>) {
+	*DEVICES.write().unwrap() = Some(devices);
+	GENERATION.fetch_add(1, Ordering::Release);
+}
+
+/// Get the fake devices the dummy backend exposes.
+fn get_devices() -> Vec<OutputDevice> {
+	DEVICES.read().unwrap().clone().unwrap_or_else(default_devices)
+}
+
+/// The fake devices exposed if [`set_devices()`] was never called.
+///
+/// The default device only supports [`SAMPLE_RATE_FALLBACK`].
+fn default_devices() -> Vec<OutputDevice> {
+	vec![
+		OutputDevice {
+			name: "Dummy Speakers".into(),
+			id: "dummy_speakers".into(),
+			sample_rates: vec![SAMPLE_RATE_FALLBACK],
+			channels: vec![2],
+			default: true,
+		},
+		OutputDevice {
+			name: "Dummy USB DAC".into(),
+			id: "dummy_usb_dac".into(),
+			sample_rates: vec![44_100, 48_000, 96_000, 192_000],
+			channels: vec![1, 2],
+			default: false,
+		},
+	]
+}
+
 //----------------------------------------------------------------------------------------------- AudioOutputDummy
 /// TODO
 pub(crate) struct AudioOutputDummy<R: Resampler> {
@@ -66,6 +111,8 @@ pub(crate) struct AudioOutputDummy<R: Resampler> {
 	spec: SignalSpec,
 	/// Duration this output was opened with.
 	duration: u64,
+	/// The latency of our (fake) audio buffer.
+	latency: Duration,
 
 	/// A re-usable sample buffer.
 	sample_buf: SampleBuffer<f32>,
@@ -74,15 +121,21 @@ pub(crate) struct AudioOutputDummy<R: Resampler> {
 
 	/// How many channels?
 	channels: usize,
+	/// Are samples sent to the (fake) device untouched?
+	bit_perfect: bool,
+
+	/// The ID of the (fake) device this output was opened with.
+	device_id: String,
+	/// The [`GENERATION`] of the device list last checked.
+	generation: usize,
 
 	/// Are we currently playing?
 	playing: Arc<AtomicBool>,
 }
 
-//----------------------------------------------------------------------------------------------- `AudioOutput` Impl
-impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
+//----------------------------------------------------------------------------------------------- `AudioWrite` Impl
+impl<R: Resampler> AudioWrite for AudioOutputDummy<R> {
 	type E = OutputError;
-	type R = R;
 
 	fn write_pre(&mut self) -> (
 		&mut Option<R>,         // Our resampler (none == no resampling needed)
@@ -101,6 +154,16 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 	}
 
 	fn write_post(&mut self) -> Result<(), OutputError> {
+		// Emulate the device being lost (unplugged) if
+		// it was removed from the fake device list.
+		let generation = GENERATION.load(Ordering::Acquire);
+		if generation != self.generation {
+			self.generation = generation;
+			if !get_devices().iter().any(|d| d.id == self.device_id) {
+				return Err(OutputError::DeviceUnavailable);
+			}
+		}
+
 		// Calculate the amount of nominal time to sleep for each `write()` call.
 		// This is a dummy audio output device, although, we'd like to preserve
 		// the behavior where `write()` hangs while waiting for the current
@@ -118,54 +181,30 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 		sleep(sleep_time);
 		Ok(())
 	}
+}
 
-	fn flush(&mut self) {
-		debug2!("AudioOutput - flush()");
-
-		if !self.playing.load(Ordering::Acquire) {
-			return;
-		}
-
-		// We're playing, which means `cubeb` is calling
-		// the callback over and over again, which means
-		// it will eventually play all audio data.
-		//
-		// `cubeb` will tell us when it has drained,
-		// so hang until it has.
-		drop(self.drained.recv());
-	}
-
-	fn discard(&mut self) {
-		debug2!("AudioOutput - discard()");
-
-		if !self.playing.load(Ordering::Acquire) {
-			return;
-		}
-
-		// INVARIANT:
-		// Bounded channels, [try_*]
-		// methods not applicable.
+//----------------------------------------------------------------------------------------------- `AudioOutput` Impl
+impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
+	type R = R;
 
-		if self.discard.is_empty() {
-			drop(self.discard.send(()));
-		}
+	impl_write!();
 
-		// Wait until cubeb has drained.
-		drop(self.drained.recv());
+	fn devices() -> Result<Vec<OutputDevice>, OutputError> {
+		Ok(get_devices())
 	}
 
 	#[cold]
 	#[inline(never)]
 	#[allow(clippy::unwrap_in_result)]
 	fn try_open(
-		name: String,
+		device: Option<&str>,
 		signal_spec: SignalSpec,
 		duration: symphonia::core::units::Duration,
 		disable_device_switch: bool,
-		buffer_milliseconds: Option<u8>,
+		open_config: OpenConfig,
 	) -> Result<Self, OutputError> {
 		debug2!("AudioOutput - try_open()");
-		debug2!("AudioOutput - signal_spec: {signal_spec:?}, duration: {duration}, disable_device_switch: {disable_device_switch}, buffer_milliseconds: {buffer_milliseconds:?}");
+		debug2!("AudioOutput - device: {device:?}, signal_spec: {signal_spec:?}, duration: {duration}, disable_device_switch: {disable_device_switch}, latency: {:?}", open_config.latency);
 
 		let channels = std::cmp::max(signal_spec.channels.count(), 2);
 		// For the resampler.
@@ -197,13 +236,23 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 
 		debug2!("AudioOutput - channel_count: {channel_count}, sample_rate: {sample_rate}, sample_rate_input: {sample_rate_input}");
 
-		// The `cubeb` <-> AudioOutput channel will hold up to 50ms of audio data by default.
-		let buffer_milliseconds = match buffer_milliseconds {
-			Some(u) => u as usize,
-			None => AUDIO_MILLISECOND_BUFFER_FALLBACK,
+		// Find the (fake) device.
+		let generation = GENERATION.load(Ordering::Acquire);
+		let devices = get_devices();
+		let device = match device {
+			Some(id) => devices.iter().find(|d| d.id == id),
+			None => devices.iter().find(|d| d.default),
+		};
+		let Some(device) = device else {
+			return Err(OutputError::DeviceUnavailable);
 		};
-		let channel_len = ((buffer_milliseconds * sample_rate as usize) / 1000) * 2;
-		debug2!("AudioOutput - buffer_milliseconds: {buffer_milliseconds}, channel_len: {channel_len}");
+
+		// The (fake) device has no buffer of its own,
+		// the channel holds all of the requested latency.
+		let frames = open_config.latency.frames(sample_rate);
+		let channel_len = frames * channels;
+		let latency = frames_to_duration(frames, sample_rate);
+		debug2!("AudioOutput - channel_len: {channel_len}, latency: {latency:?}");
 
 		let (sender, receiver)           = crossbeam::channel::bounded(channel_len);
 		let (discard, discard_recv)      = crossbeam::channel::bounded(1);
@@ -251,10 +300,17 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 		// we can try doing the expensive thing now
 		// (create a resampler).
 		//
-		// If the default device's preferred sample rate
-		// is not the same as the audio itself, we need
-		// a resampler.
-		let sample_rate_target = NonZeroUsize::new(SAMPLE_RATE_FALLBACK as usize).unwrap();
+		// If the device does not support the sample rate
+		// of the audio itself, we need a resampler.
+		let sample_rate_target = if device.supports_sample_rate(sample_rate) {
+			sample_rate
+		} else if device.supports_sample_rate(SAMPLE_RATE_FALLBACK) {
+			SAMPLE_RATE_FALLBACK
+		} else {
+			device.sample_rates.last().copied().unwrap_or(SAMPLE_RATE_FALLBACK)
+		};
+		let bit_perfect = open_config.bit_perfect(&signal_spec, sample_rate_target, channels, SampleFormat::F32);
+		let sample_rate_target = NonZeroUsize::new(sample_rate_target as usize).unwrap();
 		#[allow(clippy::branches_sharing_code)]
 		let resampler = if sample_rate_target == sample_rate_input {
 			debug2!("AudioOutput - skipping resampler, {sample_rate_input} == {sample_rate_target}");
@@ -262,6 +318,7 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 		} else {
 			debug2!("AudioOutput - creating resampler, {sample_rate_input} -> {sample_rate_target}");
 			Some(R::new(
+				open_config.resampler,
 				sample_rate_input,
 				sample_rate_target,
 				duration_non_zero,
@@ -278,13 +335,50 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 			resampler,
 			spec: signal_spec,
 			duration,
+			latency,
 			sample_buf: SampleBuffer::new(duration, signal_spec),
 			samples: Vec::with_capacity(AUDIO_SAMPLE_BUFFER_LEN),
 			channels,
+			bit_perfect,
+			device_id: device.id.clone(),
+			generation,
 			playing,
 		})
 	}
 
+	fn bit_perfect(&self) -> bool {
+		self.bit_perfect
+	}
+
+	fn latency(&self) -> Option<Duration> {
+		Some(self.latency)
+	}
+
+	fn default_device() -> Option<String> {
+		get_devices().into_iter().find(|d| d.default).map(|d| d.id)
+	}
+
+	fn is_playing(&mut self) -> bool {
+		self.playing.load(Ordering::Acquire)
+	}
+
+	fn signal_spec(&self) -> &SignalSpec {
+		&self.spec
+	}
+
+	fn duration(&self) -> u64 {
+		self.duration
+	}
+
+	fn backend(&self) -> OutputBackend {
+		OutputBackend::Dummy
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `Sink` Impl
+impl<R: Resampler> Sink for AudioOutputDummy<R> {
+	impl_sink!();
+
 	fn play(&mut self) -> Result<(), OutputError> {
 		debug2!("AudioOutput - play()");
 		self.playing.store(true, Ordering::Release);
@@ -298,15 +392,44 @@ impl<R: Resampler> AudioOutput for AudioOutputDummy<R> {
 		Ok(())
 	}
 
-	fn is_playing(&mut self) -> bool {
-		self.playing.load(Ordering::Acquire)
-	}
+	fn flush(&mut self) {
+		debug2!("AudioOutput - flush()");
 
-	fn spec(&self) -> &SignalSpec {
-		&self.spec
+		if !self.playing.load(Ordering::Acquire) {
+			return;
+		}
+
+		// We're playing, which means `cubeb` is calling
+		// the callback over and over again, which means
+		// it will eventually play all audio data.
+		//
+		// `cubeb` will tell us when it has drained,
+		// so hang until it has.
+		drop(self.drained.recv());
 	}
 
-	fn duration(&self) -> u64 {
-		self.duration
+	fn discard(&mut self) {
+		debug2!("AudioOutput - discard()");
+
+		// Leftover frames from a partial chunk must not
+		// end up in front of whatever is written next.
+		if let Some(resampler) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+
+		if !self.playing.load(Ordering::Acquire) {
+			return;
+		}
+
+		// INVARIANT:
+		// Bounded channels, [try_*]
+		// methods not applicable.
+
+		if self.discard.is_empty() {
+			drop(self.discard.send(()));
+		}
+
+		// Wait until cubeb has drained.
+		drop(self.drained.recv());
 	}
 }
\ No newline at end of file
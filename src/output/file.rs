@@ -0,0 +1,802 @@
+//! File audio output.
+//!
+//! This file implements the abstract `AudioOutput`
+//! trait using a file as the "device".
+//!
+//! The exact stream that would be sent to an audio device (post-volume,
+//! post-resample) is written to a WAV file, or optionally a raw PCM file,
+//! in the `SampleFormat` requested in `InitConfig` (32-bit float by default).
+//!
+//! Like the dummy backend, this runs in real-time, i.e.
+//! `write()` hangs for as long as the audio would take to play.
+//!
+//! For documentation on `AudioOutput`, see `output.rs`.
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	output::{AudioOutput,AudioWrite,Sink,OutputDevice,OutputBackend,OpenConfig,SampleFormat,Quantizer,impl_sink,impl_write,frames_to_duration},
+	resampler::Resampler,
+	error::OutputError,
+};
+use symphonia::core::audio::{SignalSpec,SampleBuffer};
+use crossbeam::channel::{Sender,Receiver,select};
+use std::{
+	borrow::Cow,
+	fs::File,
+	io::{BufWriter,Seek,SeekFrom,Write},
+	path::{Path,PathBuf},
+	time::Duration,
+	thread::{JoinHandle,spawn,sleep},
+	sync::{
+		Arc,
+		atomic::{AtomicBool,Ordering},
+	},
+};
+use crate::macros::{send,trace2,debug2,error2};
+
+//----------------------------------------------------------------------------------------------- Constants
+/// File extensions that are written as raw PCM instead of WAV.
+///
+/// Raw PCM is interleaved little-endian samples with no header.
+const RAW_EXTENSIONS: [&str; 3] = ["pcm", "raw", "f32"];
+
+/// `WAVE_FORMAT_PCM`.
+const WAVE_FORMAT_PCM: u16 = 1;
+/// `WAVE_FORMAT_IEEE_FLOAT`.
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+/// `WAVE_FORMAT_EXTENSIBLE`.
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// The `KSDATAFORMAT_SUBTYPE_*` GUID of `WAVE_FORMAT_EXTENSIBLE`,
+/// without the first 2 bytes, which are the actual format tag.
+const SUBFORMAT_GUID: [u8; 14] = [
+	0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00,
+	0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
+
+//----------------------------------------------------------------------------------------------- AudioOutputFile
+/// `AudioOutput` that writes to a file.
+///
+/// The "device" is the path to the file, i.e. the `device`
+/// passed to `try_open()` is the path, e.g `/tmp/sansan.wav`.
+///
+/// The file is created lazily upon the first sample written,
+/// if a file already exists at that path, the next available
+/// `{stem}_{n}.{extension}` path is used instead.
+///
+/// This means re-opening (e.g. on spec changes) rolls to a new file.
+pub(crate) struct AudioOutputFile<R: Resampler> {
+	/// We send audio data to this channel which
+	/// the writer thread will receive and write.
+	sender: Sender<f32>,
+	/// Errors from the writer thread.
+	error: Receiver<OutputError>,
+
+	/// The writer thread.
+	writer: WriterThread,
+	/// Are we currently playing?
+	playing: Arc<AtomicBool>,
+
+	/// Audio spec output was opened with.
+	spec: SignalSpec,
+	/// Duration this output was opened with.
+	duration: u64,
+	/// The sample format being written.
+	format: SampleFormat,
+	/// Are samples written to the file untouched?
+	bit_perfect: bool,
+	/// The latency of our audio buffer.
+	latency: Duration,
+
+	/// The resampler (always `None`, a file accepts any sample rate).
+	resampler: Option<R>,
+	/// A re-usable sample buffer.
+	sample_buf: SampleBuffer<f32>,
+	/// A re-usable Vec of samples.
+	samples: Vec<f32>,
+	/// Unused, this is kept for `into_inner()`,
+	/// the writer thread is told to discard with [`Control`].
+	discard: Arc<AtomicBool>,
+}
+
+/// Messages to the writer thread.
+///
+/// The `Sender<()>` is used to reply once done.
+enum Control {
+	/// Wake up and start writing.
+	Play,
+	/// Write all the sent samples, and
+	/// update the file so it is complete.
+	Flush(Sender<()>),
+	/// Throw away all the sent samples.
+	Discard(Sender<()>),
+	/// `Flush`, then close the file and exit.
+	Finish,
+}
+
+//----------------------------------------------------------------------------------------------- `AudioWrite` Impl
+impl<R: Resampler> AudioWrite for AudioOutputFile<R> {
+	type E = OutputError;
+
+	fn write_pre(&mut self) -> (
+		&mut Option<Self::R>,   // Our resampler (none == no resampling needed)
+		&mut SampleBuffer<f32>, // A local buffer used for sample processing
+		&mut Vec<f32>,          // A local buffer of the _end result_ samples (potentially after resampling)
+		&Sender<f32>,           // Channel to send sample to audio backend
+		&Receiver<Self::E>,     // Channel to potentially receive an error, after writing the sample
+	) {
+		(
+			&mut self.resampler,
+			&mut self.sample_buf,
+			&mut self.samples,
+			&self.sender,
+			&self.error,
+		)
+	}
+
+	fn write_post(&mut self) -> Result<(), OutputError> {
+		// Preserve the real-time behavior where `write()` hangs
+		// while waiting for the current audio buffer to "play".
+		//
+		// See `dummy.rs` for this formula.
+		#[allow(clippy::cast_lossless)]
+		let micro = (self.duration * 995_000) / self.spec.rate as u64;
+		sleep(Duration::from_micros(micro));
+		Ok(())
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `AudioOutput` Impl
+impl<R: Resampler> AudioOutput for AudioOutputFile<R> {
+	type R = R;
+
+	impl_write!();
+
+	fn signal_spec(&self) -> SignalSpec {
+		self.spec
+	}
+
+	fn duration(&self) -> u64 {
+		self.duration
+	}
+
+	fn backend(&self) -> OutputBackend {
+		OutputBackend::File
+	}
+
+	fn into_inner(self) -> (
+		SampleBuffer<f32>,
+		Vec<f32>,
+		Arc<AtomicBool>,
+		Option<R>,
+	) {
+		(
+			self.sample_buf,
+			self.samples,
+			self.discard,
+			self.resampler,
+		)
+	}
+
+	fn sample_format(&self) -> SampleFormat {
+		self.format
+	}
+
+	fn bit_perfect(&self) -> bool {
+		self.bit_perfect
+	}
+
+	fn latency(&self) -> Option<Duration> {
+		Some(self.latency)
+	}
+
+	fn devices() -> Result<Vec<OutputDevice>, OutputError> {
+		// Any file path is a valid "device".
+		Ok(vec![])
+	}
+
+	#[cold]
+	#[inline(never)]
+	fn try_open(
+		device: Option<&str>,
+		signal_spec: SignalSpec,
+		duration: symphonia::core::units::Duration,
+		disable_device_switch: bool,
+		open_config: OpenConfig,
+		sample_buf: SampleBuffer<f32>,
+		samples: Vec<f32>,
+		discard: Arc<AtomicBool>,
+		_: Option<R>,
+	) -> Result<Self, OutputError> {
+		debug2!("AudioOutput(file) - try_open()");
+		debug2!("AudioOutput(file) - device: {device:?}, signal_spec: {signal_spec:?}, duration: {duration}, disable_device_switch: {disable_device_switch}, latency: {:?}", open_config.latency);
+
+		// There is no "default" file.
+		let Some(path) = device else {
+			return Err(OutputError::DeviceUnavailable);
+		};
+		let path = PathBuf::from(path);
+
+		// Make sure the directory exists, so we
+		// don't find out later in the writer thread.
+		if let Some(parent) = path.parent() {
+			if !parent.as_os_str().is_empty() && !parent.is_dir() {
+				return Err(OutputError::DeviceUnavailable);
+			}
+		}
+
+		let channels = signal_spec.channels.count();
+		let Ok(channels) = u16::try_from(channels) else {
+			return Err(OutputError::InvalidChannels);
+		};
+		if channels == 0 {
+			return Err(OutputError::InvalidChannels);
+		}
+		if signal_spec.rate == 0 {
+			return Err(OutputError::InvalidSampleRate);
+		}
+		if duration == 0 {
+			return Err(OutputError::InvalidSpec);
+		}
+
+		// A file accepts any format, default to not touching the samples.
+		let format = open_config.sample_format.unwrap_or(SampleFormat::F32);
+
+		let raw = path
+			.extension()
+			.and_then(|e| e.to_str())
+			.is_some_and(|e| RAW_EXTENSIONS.contains(&e));
+
+		// The channel holds all of the requested latency.
+		let frames = open_config.latency.frames(signal_spec.rate);
+		let channel_len = frames * channels as usize;
+		let latency = frames_to_duration(frames, signal_spec.rate);
+		debug2!("AudioOutput(file) - path: {}, raw: {raw}, format: {format}, channel_len: {channel_len}, latency: {latency:?}", path.display());
+
+		let (sender, receiver)         = crossbeam::channel::bounded(channel_len);
+		let (error_send, error_recv)   = crossbeam::channel::unbounded();
+		let (control_send, control_recv) = crossbeam::channel::unbounded();
+		let playing = Arc::new(AtomicBool::new(false));
+
+		let mut writer = Writer {
+			path,
+			raw,
+			rate: signal_spec.rate,
+			channels,
+			format,
+			quantizer: Quantizer::new(format, open_config.dither(), usize::from(channels)),
+			file: None,
+			data_len: 0,
+		};
+
+		let playing_clone = Arc::clone(&playing);
+		// Spawn the writer thread.
+		let handle = spawn(move || {
+			let write = |writer: &mut Writer, sample: f32| {
+				if let Err(e) = writer.write(sample) {
+					error2!("AudioOutput(file) - write error: {e}");
+					drop(error_send.try_send(OutputError::Write));
+				}
+			};
+
+			loop {
+				// Only receive samples while we're "playing".
+				let control = if playing_clone.load(Ordering::Acquire) {
+					select! {
+						recv(receiver) -> sample => {
+							match sample {
+								Ok(sample) => write(&mut writer, sample),
+								// This `AudioOutput` was dropped.
+								Err(_) => break,
+							}
+							continue;
+						},
+						recv(control_recv) -> control => control,
+					}
+				} else {
+					control_recv.recv()
+				};
+
+				match control {
+					Ok(Control::Play) => (),
+					Ok(Control::Flush(done)) => {
+						// INVARIANT: all samples were sent before
+						// this message, so they're all in the channel.
+						for sample in receiver.try_iter() {
+							write(&mut writer, sample);
+						}
+						if let Err(e) = writer.sync() {
+							error2!("AudioOutput(file) - flush error: {e}");
+							drop(error_send.try_send(OutputError::Write));
+						}
+						drop(done.send(()));
+					},
+					Ok(Control::Discard(done)) => {
+						receiver.try_iter().for_each(drop);
+						drop(done.send(()));
+					},
+					Ok(Control::Finish) => {
+						for sample in receiver.try_iter() {
+							write(&mut writer, sample);
+						}
+						break;
+					},
+					// This `AudioOutput` was dropped.
+					Err(_) => break,
+				}
+			}
+
+			writer.finish();
+		});
+
+		Ok(Self {
+			sender,
+			error: error_recv,
+			writer: WriterThread {
+				control: control_send,
+				handle: Some(handle),
+			},
+			playing,
+			spec: signal_spec,
+			duration,
+			format,
+			bit_perfect: open_config.bit_perfect(&signal_spec, signal_spec.rate, usize::from(channels), format),
+			latency,
+			resampler: None,
+			sample_buf,
+			samples,
+			discard,
+		})
+	}
+}
+
+//----------------------------------------------------------------------------------------------- `Sink` Impl
+impl<R: Resampler> Sink for AudioOutputFile<R> {
+	impl_sink!();
+
+	fn play(&mut self) -> Result<(), OutputError> {
+		debug2!("AudioOutput(file) - play()");
+		self.playing.store(true, Ordering::Release);
+		send!(self.writer.control, Control::Play);
+		Ok(())
+	}
+
+	fn pause(&mut self) -> Result<(), OutputError> {
+		debug2!("AudioOutput(file) - pause()");
+		self.playing.store(false, Ordering::Release);
+		Ok(())
+	}
+
+	fn flush(&mut self) {
+		debug2!("AudioOutput(file) - flush()");
+
+		// Block until the writer thread has written everything.
+		let (done, wait) = crossbeam::channel::bounded(1);
+		send!(self.writer.control, Control::Flush(done));
+		drop(wait.recv());
+	}
+
+	fn discard(&mut self) {
+		debug2!("AudioOutput(file) - discard()");
+
+		// Leftover frames from a partial chunk must not
+		// end up in front of whatever is written next.
+		if let Some(resampler) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+
+		// Block until the writer thread has thrown everything away.
+		let (done, wait) = crossbeam::channel::bounded(1);
+		send!(self.writer.control, Control::Discard(done));
+		drop(wait.recv());
+	}
+}
+
+//----------------------------------------------------------------------------------------------- WriterThread
+/// Handle to the writer thread.
+///
+/// This is joined on drop so the file is complete
+/// (and can be read) once the `AudioOutputFile` is gone.
+struct WriterThread {
+	/// Control messages to the writer thread.
+	control: Sender<Control>,
+	/// The writer thread itself.
+	handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for WriterThread {
+	fn drop(&mut self) {
+		debug2!("AudioOutput(file) - joining writer thread");
+		drop(self.control.send(Control::Finish));
+		if let Some(handle) = self.handle.take() {
+			drop(handle.join());
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------------------- Writer
+/// The state of the file the writer thread is writing to.
+struct Writer {
+	/// The path requested (may be rolled to `{stem}_{n}.{extension}`).
+	path: PathBuf,
+	/// Write raw PCM instead of WAV?
+	raw: bool,
+	/// Sample rate.
+	rate: u32,
+	/// Channel count.
+	channels: u16,
+	/// The sample format to write.
+	format: SampleFormat,
+	/// Converts samples to `format` (if it is an integer format).
+	quantizer: Quantizer,
+	/// The file, created upon the first sample.
+	file: Option<BufWriter<File>>,
+	/// Amount of sample bytes written to the current file.
+	data_len: u64,
+}
+
+impl Writer {
+	/// Write a single sample, creating the file if needed.
+	///
+	/// WAV files are limited to 4 GiB, so if this sample
+	/// doesn't fit, the file is finished and a new one is rolled to.
+	fn write(&mut self, sample: f32) -> std::io::Result<()> {
+		let len = u64::from(self.format.bits() / 8);
+
+		if !self.raw && self.file.is_some() && self.data_len + len > wav_data_max(self.channels, self.format) {
+			debug2!("AudioOutput(file) - 4 GiB WAV limit reached, rolling to a new file");
+			self.finish();
+		}
+
+		let file = match self.file.as_mut() {
+			Some(file) => file,
+			None => {
+				let path = next_path(&self.path);
+				debug2!("AudioOutput(file) - creating: {}", path.display());
+				let mut file = BufWriter::new(File::create(path)?);
+				if !self.raw {
+					// Sizes are filled in by `sync()`.
+					write_wav_header(&mut file, self.rate, self.channels, self.format, 0)?;
+				}
+				self.data_len = 0;
+				self.file.insert(file)
+			},
+		};
+
+		trace2!("AudioOutput(file) - writing sample");
+		match self.format {
+			SampleFormat::F32 => file.write_all(&sample.to_le_bytes())?,
+			SampleFormat::I16 => file.write_all(&self.quantizer.quantize_i16(sample).to_le_bytes())?,
+			SampleFormat::I24 => file.write_all(&self.quantizer.quantize(sample).to_le_bytes()[..3])?,
+			SampleFormat::I32 => file.write_all(&self.quantizer.quantize(sample).to_le_bytes())?,
+		}
+		self.data_len += len;
+		Ok(())
+	}
+
+	/// Flush the file, and fill in the WAV header sizes.
+	///
+	/// The file is complete after this, but can still be written to.
+	fn sync(&mut self) -> std::io::Result<()> {
+		let Some(file) = self.file.as_mut() else {
+			return Ok(());
+		};
+
+		if !self.raw {
+			file.seek(SeekFrom::Start(0))?;
+			write_wav_header(file, self.rate, self.channels, self.format, self.data_len)?;
+			file.seek(SeekFrom::End(0))?;
+		}
+		file.flush()
+	}
+
+	/// `sync()` and close the file.
+	fn finish(&mut self) {
+		if let Err(e) = self.sync() {
+			error2!("AudioOutput(file) - finish error: {e}");
+		}
+		self.file = None;
+	}
+}
+
+/// Returns `path` if nothing exists there, else
+/// the first `{stem}_{n}.{extension}` that doesn't exist.
+fn next_path(path: &Path) -> PathBuf {
+	if !path.exists() {
+		return path.to_path_buf();
+	}
+
+	let stem = path.file_stem().map_or(Cow::Borrowed(""), |s| s.to_string_lossy());
+	let extension = path.extension().map(|e| e.to_string_lossy());
+
+	(1_usize..)
+		.map(|n| {
+			let name = match extension.as_ref() {
+				Some(e) => format!("{stem}_{n}.{e}"),
+				None => format!("{stem}_{n}"),
+			};
+			path.with_file_name(name)
+		})
+		.find(|p| !p.exists())
+		.unwrap_or_else(|| path.to_path_buf())
+}
+
+/// Does `format` need a `WAVE_FORMAT_EXTENSIBLE` header?
+///
+/// Only 16-bit PCM with 1-2 channels can use the plain header,
+/// (24/32-bit PCM, float, and > 2 channels must be extensible).
+const fn wav_extensible(channels: u16, format: SampleFormat) -> bool {
+	!matches!(format, SampleFormat::I16) || channels > 2
+}
+
+/// The length of the header `write_wav_header()` writes.
+const fn wav_header_len(channels: u16, format: SampleFormat) -> u64 {
+	if wav_extensible(channels, format) {
+		// `RIFF` + `fmt ` (40) + `fact` + `data`.
+		12 + 48 + 12 + 8
+	} else {
+		// `RIFF` + `fmt ` (16) + `data`.
+		12 + 24 + 8
+	}
+}
+
+/// The maximum amount of sample bytes a WAV file with
+/// this header can hold, rounded down to whole frames.
+const fn wav_data_max(channels: u16, format: SampleFormat) -> u64 {
+	let block_align = channels as u64 * (format.bits() / 8) as u64;
+	let max = u32::MAX as u64 - (wav_header_len(channels, format) - 8);
+	max - (max % block_align)
+}
+
+/// Write a WAV header for `format`, with `data_len` bytes of samples.
+///
+/// Formats other than 16-bit PCM (and > 2 channels) are written
+/// as `WAVE_FORMAT_EXTENSIBLE` with a `fact` chunk.
+///
+/// # Errors
+/// Besides I/O errors, this errors if `data_len`
+/// is more than `wav_data_max()` (WAV files are limited to 4 GiB).
+pub(crate) fn write_wav_header(
+	w: &mut impl Write,
+	rate: u32,
+	channels: u16,
+	format: SampleFormat,
+	data_len: u64,
+) -> std::io::Result<()> {
+	if data_len > wav_data_max(channels, format) {
+		return Err(std::io::Error::new(
+			std::io::ErrorKind::InvalidInput,
+			"WAV data is larger than 4 GiB",
+		));
+	}
+
+	let extensible  = wav_extensible(channels, format);
+	let tag         = if format.is_integer() { WAVE_FORMAT_PCM } else { WAVE_FORMAT_IEEE_FLOAT };
+	let bits        = format.bits();
+	let block_align = channels * (bits / 8);
+	let byte_rate   = rate * u32::from(block_align);
+
+	// INVARIANT: checked above, these fit.
+	#[allow(clippy::cast_possible_truncation)]
+	let riff_len = (wav_header_len(channels, format) - 8 + data_len) as u32;
+	#[allow(clippy::cast_possible_truncation)]
+	let data_len = data_len as u32;
+
+	w.write_all(b"RIFF")?;
+	w.write_all(&riff_len.to_le_bytes())?;
+	w.write_all(b"WAVE")?;
+
+	w.write_all(b"fmt ")?;
+	w.write_all(&(if extensible { 40_u32 } else { 16 }).to_le_bytes())?;
+	w.write_all(&(if extensible { WAVE_FORMAT_EXTENSIBLE } else { tag }).to_le_bytes())?;
+	w.write_all(&channels.to_le_bytes())?;
+	w.write_all(&rate.to_le_bytes())?;
+	w.write_all(&byte_rate.to_le_bytes())?;
+	w.write_all(&block_align.to_le_bytes())?;
+	w.write_all(&bits.to_le_bytes())?;
+
+	if extensible {
+		// The standard speaker positions, in order.
+		let channel_mask: u32 = match channels {
+			1 => 0x4, // Front center.
+			2 => 0x3, // Front left + right.
+			3..=18 => (1 << channels) - 1,
+			_ => 0,
+		};
+
+		w.write_all(&22_u16.to_le_bytes())?; // Extension size.
+		w.write_all(&bits.to_le_bytes())?;   // Valid bits per sample.
+		w.write_all(&channel_mask.to_le_bytes())?;
+		w.write_all(&tag.to_le_bytes())?;    // Sub-format GUID.
+		w.write_all(&SUBFORMAT_GUID)?;
+
+		// Frames per channel.
+		w.write_all(b"fact")?;
+		w.write_all(&4_u32.to_le_bytes())?;
+		w.write_all(&(data_len / u32::from(block_align)).to_le_bytes())?;
+	}
+
+	w.write_all(b"data")?;
+	w.write_all(&data_len.to_le_bytes())
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::config::Dither;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn wav_roll() {
+		let dir = std::env::temp_dir().join(format!("sansan_test_wav_roll_{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let path = dir.join("out.wav");
+
+		let new_writer = || Writer {
+			path: path.clone(),
+			raw: false,
+			rate: 48_000,
+			channels: 2,
+			format: SampleFormat::F32,
+			quantizer: Quantizer::new(SampleFormat::F32, Dither::None, 2),
+			file: None,
+			data_len: 0,
+		};
+
+		//---------------------------------- 1st file
+		let mut writer = new_writer();
+		for sample in [0.0, 0.25, 0.5, 1.0] {
+			writer.write(sample).unwrap();
+		}
+		writer.finish();
+
+		let bytes = std::fs::read(&path).unwrap();
+		assert_eq!(bytes.len(), 80 + 16);
+		assert_eq!(&bytes[0..4], b"RIFF");
+		assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 72 + 16);
+		assert_eq!(&bytes[8..12], b"WAVE");
+		assert_eq!(u32::from_le_bytes(bytes[16..20].try_into().unwrap()), 40);     // fmt size
+		assert_eq!(u16::from_le_bytes(bytes[20..22].try_into().unwrap()), 0xFFFE); // extensible
+		assert_eq!(u16::from_le_bytes(bytes[22..24].try_into().unwrap()), 2);      // channels
+		assert_eq!(u32::from_le_bytes(bytes[24..28].try_into().unwrap()), 48_000); // rate
+		assert_eq!(u16::from_le_bytes(bytes[34..36].try_into().unwrap()), 32);     // bits
+		assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 0x3);    // channel mask
+		assert_eq!(u16::from_le_bytes(bytes[44..46].try_into().unwrap()), 3);      // float
+		assert_eq!(&bytes[60..64], b"fact");
+		assert_eq!(u32::from_le_bytes(bytes[68..72].try_into().unwrap()), 2);      // frames
+		assert_eq!(&bytes[72..76], b"data");
+		assert_eq!(u32::from_le_bytes(bytes[76..80].try_into().unwrap()), 16);     // data
+		assert_eq!(f32::from_le_bytes(bytes[92..96].try_into().unwrap()), 1.0);
+
+		//---------------------------------- Roll to the 2nd file
+		let mut writer = new_writer();
+		writer.write(0.0).unwrap();
+		writer.finish();
+		assert!(dir.join("out_1.wav").exists());
+
+		//---------------------------------- Nothing written, no file
+		let mut writer = new_writer();
+		writer.finish();
+		assert!(!dir.join("out_2.wav").exists());
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn wav_i16() {
+		let dir = std::env::temp_dir().join(format!("sansan_test_wav_i16_{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let path = dir.join("out.wav");
+
+		let mut writer = Writer {
+			path: path.clone(),
+			raw: false,
+			rate: 44_100,
+			channels: 1,
+			format: SampleFormat::I16,
+			quantizer: Quantizer::new(SampleFormat::I16, Dither::None, 1),
+			file: None,
+			data_len: 0,
+		};
+		for sample in [0.0, 0.5, -1.0] {
+			writer.write(sample).unwrap();
+		}
+		writer.finish();
+
+		let bytes = std::fs::read(&path).unwrap();
+		assert_eq!(bytes.len(), 44 + 6);
+		assert_eq!(u16::from_le_bytes(bytes[20..22].try_into().unwrap()), 1);      // PCM
+		assert_eq!(u32::from_le_bytes(bytes[28..32].try_into().unwrap()), 88_200); // byte rate
+		assert_eq!(u16::from_le_bytes(bytes[32..34].try_into().unwrap()), 2);      // block align
+		assert_eq!(u16::from_le_bytes(bytes[34..36].try_into().unwrap()), 16);     // bits
+		assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 6);      // data
+		assert_eq!(i16::from_le_bytes(bytes[46..48].try_into().unwrap()), 16384);
+		assert_eq!(i16::from_le_bytes(bytes[48..50].try_into().unwrap()), i16::MIN);
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	fn wav_limit() {
+		let max = wav_data_max(2, SampleFormat::I24);
+		assert_eq!(max % 6, 0);
+		assert!(max + wav_header_len(2, SampleFormat::I24) - 8 <= u64::from(u32::MAX));
+
+		let mut header = vec![];
+		write_wav_header(&mut header, 48_000, 2, SampleFormat::I24, max).unwrap();
+		assert_eq!(header.len() as u64, wav_header_len(2, SampleFormat::I24));
+
+		// Too big, this used to silently saturate.
+		let error = write_wav_header(&mut vec![], 48_000, 2, SampleFormat::I24, max + 6).unwrap_err();
+		assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+	}
+
+	#[test]
+	/// Play a source through the `Engine` into the file
+	/// backend, and compare it to the same source decoded directly.
+	fn golden() {
+		use crate::{
+			config::InitConfig,
+			event::{Event,EventKind},
+			signal::{Add,AddMethod,Volume},
+			source::{Source,source_decode::SourceDecode},
+		};
+
+		const AUDIO: &str = "assets/audio/dialog-information.oga";
+
+		let dir = std::env::temp_dir().join(format!("sansan_test_wav_golden_{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let path = dir.join("out.wav");
+
+		//---------------------------------- The expected file.
+		let mut decode = SourceDecode::try_from(Source::from((Path::new(AUDIO), 0_usize))).unwrap();
+		let mut samples = vec![];
+		let mut spec = None;
+		while let Ok(packet) = decode.reader.next_packet() {
+			let decoded = decode.decoder.decode(&packet).unwrap();
+			let mut audio = decoded.make_equivalent::<f32>();
+			decoded.convert(&mut audio);
+			let mut buf = SampleBuffer::<f32>::new(audio.capacity() as u64, *audio.spec());
+			buf.copy_interleaved_typed(&audio);
+			samples.extend(buf.samples().iter().flat_map(|s| s.to_le_bytes()));
+			spec = Some(*audio.spec());
+		}
+		let spec = spec.unwrap();
+		let channels = u16::try_from(spec.channels.count()).unwrap();
+		let mut expected = vec![];
+		write_wav_header(&mut expected, spec.rate, channels, SampleFormat::F32, samples.len() as u64).unwrap();
+		expected.extend(samples);
+
+		//---------------------------------- Play it.
+		let mut engine = crate::tests::init_with_config(InitConfig {
+			output_backends: Cow::Borrowed(&[OutputBackend::File]),
+			output_device: Some(path.display().to_string()),
+			..InitConfig::DEFAULT
+		});
+		engine.volume(Volume::new(1.0));
+		let events = engine.subscribe(EventKind::SourceChanged.into());
+		engine.add(Add {
+			source: Source::from((Path::new(AUDIO), 0)),
+			method: AddMethod::Back,
+			clear: false,
+			play: true,
+		});
+		loop {
+			let event = events.recv_timeout(Duration::from_secs(30)).unwrap();
+			if matches!(event, Event::SourceChanged(None)) {
+				break;
+			}
+		}
+
+		// Switching files drops (and completes) the 1st one.
+		engine.output_device(Some(dir.join("unused.wav").display().to_string())).unwrap();
+
+		let bytes = std::fs::read(&path).unwrap();
+		assert_eq!(bytes.len(), expected.len());
+		assert!(bytes == expected, "played file differs from the golden file");
+		assert!(!dir.join("unused.wav").exists());
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+}
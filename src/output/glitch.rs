@@ -0,0 +1,192 @@
+//! Audio output glitch detection.
+
+//---------------------------------------------------------------------------------------------------- use
+use std::time::{Duration,Instant};
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	config::Callbacks,
+	error::OutputError,
+	state::AudioStateReader,
+};
+
+//---------------------------------------------------------------------------------------------------- Glitch
+/// An audible playback glitch
+///
+/// These are reported as [`OutputError::Glitch`] through
+/// [`Callbacks::error_output`], and counted in [`Glitches`].
+///
+/// Glitches are not fatal, playback continues as normal and the
+/// [`ErrorAction`](crate::config::ErrorAction) is _not_ acted upon.
+///
+/// Which glitch occurred hints at what was at fault:
+///
+/// | Glitch                 | Meaning | Usually at fault |
+/// |------------------------|---------|------------------|
+/// | [`Glitch::Underrun`]   | The output device asked for audio, but there wasn't enough buffered | `Decode`, the disk, or the [`Latency`](crate::output::Latency) is too low
+/// | [`Glitch::LateWrite`]  | Audio arrived after the output's buffer had already drained | `Decode` or the disk
+/// | [`Glitch::Overrun`]    | Writing audio blocked for much longer than the audio itself lasts | The output device or audio server
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Glitch {
+	/// The output device's callback found too few samples buffered.
+	///
+	/// This is only detected by backends that
+	/// drive a real audio device (`cpal`, `cubeb`).
+	Underrun,
+	/// The output device was not accepting
+	/// audio, so writing it stalled.
+	Overrun,
+	/// Audio was written to the output later than it had to be to play
+	/// seamlessly, i.e. `Decode` did not provide the audio in time.
+	LateWrite,
+}
+
+//---------------------------------------------------------------------------------------------------- Glitches
+/// Counters of each [`Glitch`] that has occurred
+///
+/// These are counted since the `Engine` was created,
+/// across output device switches, and can be read at
+/// any time with [`AudioStateReader::glitches`].
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Glitches {
+	/// How many [`Glitch::Underrun`]'s have occurred?
+	pub underruns: usize,
+	/// How many [`Glitch::Overrun`]'s have occurred?
+	pub overruns: usize,
+	/// How many [`Glitch::LateWrite`]'s have occurred?
+	pub late_writes: usize,
+}
+
+impl Glitches {
+	/// No glitches.
+	pub const DEFAULT: Self = Self {
+		underruns: 0,
+		overruns: 0,
+		late_writes: 0,
+	};
+
+	#[must_use]
+	/// The total amount of glitches.
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// let glitches = Glitches {
+	///     underruns: 1,
+	///     overruns: 2,
+	///     late_writes: 3,
+	/// };
+	/// assert_eq!(glitches.total(), 6);
+	/// ```
+	pub const fn total(&self) -> usize {
+		self.underruns
+			.saturating_add(self.overruns)
+			.saturating_add(self.late_writes)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- GlitchDetector
+/// Detects `Glitch::LateWrite` and `Glitch::Overrun` by timing
+/// `AudioOutput::write_audio()`, and tracks new `Glitch::Underrun`'s
+/// reported by the backend.
+///
+/// This must be `reset()` whenever the gap between writes
+/// is expected (paused, discarded, new output, etc).
+#[derive(Debug,Default)]
+pub(crate) struct GlitchDetector {
+	/// When the last write finished, `None` if reset.
+	last_write: Option<Instant>,
+	/// The last `AudioOutput::underruns()` seen.
+	underruns: usize,
+}
+
+impl GlitchDetector {
+	/// Forget the last write, the next
+	/// write will not be checked for lateness.
+	///
+	/// `underruns` is the current output's underrun count.
+	pub(crate) fn reset(&mut self, underruns: usize) {
+		self.last_write = None;
+		self.underruns = underruns;
+	}
+
+	/// Check a write to the output that took place
+	/// from `start` to `end` for glitches.
+	///
+	/// - `audio` is how long the written audio lasts
+	/// - `latency` is the output's latency, if known
+	/// - `underruns` is the current output's underrun count
+	///
+	/// Returns the amount of (underruns, overruns, late writes) that occurred.
+	pub(crate) fn write(
+		&mut self,
+		start: Instant,
+		end: Instant,
+		audio: Duration,
+		latency: Option<Duration>,
+		underruns: usize,
+	) -> (usize, usize, usize) {
+		// Without a known latency, assume the output
+		// only buffers the audio being written.
+		let latency = latency.unwrap_or(audio);
+
+		// The output's buffer would have fully
+		// drained if we took longer than this.
+		let late_write = self
+			.last_write
+			.is_some_and(|last| start.saturating_duration_since(last) > latency);
+
+		// A write should only block for as long as the audio
+		// takes to play, give it generous room before blaming the device.
+		let overrun = end.saturating_duration_since(start) > (audio + latency) * 2;
+
+		let new_underruns = underruns.saturating_sub(self.underruns);
+		self.underruns = underruns;
+		self.last_write = Some(end);
+
+		(new_underruns, usize::from(overrun), usize::from(late_write))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn detector() {
+		const MS: Duration = Duration::from_millis(1);
+
+		let mut detector = GlitchDetector::default();
+		let now = Instant::now();
+		let audio = 20 * MS;
+		let latency = Some(50 * MS);
+
+		// First write is never late.
+		assert_eq!(detector.write(now, now + 20 * MS, audio, latency, 0), (0, 0, 0));
+		// On time.
+		assert_eq!(detector.write(now + 30 * MS, now + 50 * MS, audio, latency, 0), (0, 0, 0));
+		// Late.
+		assert_eq!(detector.write(now + 150 * MS, now + 170 * MS, audio, latency, 0), (0, 0, 1));
+		// Blocked for too long.
+		assert_eq!(detector.write(now + 170 * MS, now + 500 * MS, audio, latency, 0), (0, 1, 0));
+		// The backend underran twice.
+		assert_eq!(detector.write(now + 500 * MS, now + 520 * MS, audio, latency, 2), (2, 0, 0));
+		assert_eq!(detector.write(now + 520 * MS, now + 540 * MS, audio, latency, 2), (0, 0, 0));
+
+		// Gaps after a reset are expected.
+		detector.reset(0);
+		assert_eq!(detector.write(now + 5000 * MS, now + 5020 * MS, audio, latency, 0), (0, 0, 0));
+	}
+}
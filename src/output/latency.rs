@@ -0,0 +1,198 @@
+//! Audio output latency.
+
+//---------------------------------------------------------------------------------------------------- use
+use std::time::Duration;
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::InitConfig,
+};
+
+//---------------------------------------------------------------------------------------------------- Latency
+/// How much audio is buffered between `sansan` and the output device
+///
+/// This is a wrapper around a [`u16`] amount of milliseconds
+/// that is between [`Latency::MIN`] and [`Latency::MAX`].
+///
+/// Lower values mean playback reacts faster (e.g. pausing, seeking, volume
+/// changes are heard sooner) but need more CPU wake-ups, and are more likely
+/// to underrun (audible gaps) on slow or busy systems.
+///
+/// Higher values use less power and are more resistant
+/// to hiccups, at the cost of slower reactions.
+///
+/// Some profiles are provided:
+///
+/// | Profile              | Milliseconds | Use-case |
+/// |----------------------|--------------|----------|
+/// | [`Latency::LOW`]     | 10           | Interactive use, e.g. games, instruments
+/// | [`Latency::DEFAULT`] | 50           | Desktop music players
+/// | [`Latency::HIGH`]    | 500          | Battery-powered and embedded devices
+///
+/// This is set with [`InitConfig::latency`] and can be changed at runtime with
+/// [`Engine::output_latency`]. This is the _requested_ latency, the latency
+/// actually negotiated with the device can be checked with [`Engine::latency`].
+///
+/// When (de)serialized, this is the inner [`u16`], deserialized values
+/// go through [`Latency::new`] so they are also saturated.
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode))]
+#[cfg_attr(feature = "serde", serde(from = "u16", into = "u16"))]
+pub struct Latency(u16);
+
+impl Latency {
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::MIN.milliseconds(), 1);
+	/// ```
+	pub const MIN: Self = Self(1);
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::MAX.milliseconds(), 2_000);
+	/// ```
+	pub const MAX: Self = Self(2_000);
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::LOW.milliseconds(), 10);
+	/// ```
+	pub const LOW: Self = Self(10);
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::HIGH.milliseconds(), 500);
+	/// ```
+	pub const HIGH: Self = Self(500);
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::DEFAULT.milliseconds(), 50);
+	/// ```
+	pub const DEFAULT: Self = Self(50);
+
+	#[inline]
+	#[must_use]
+	/// Create a new [`Latency`] from an amount of milliseconds.
+	///
+	/// This saturates at [`Latency::MIN`] and [`Latency::MAX`].
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::new(0), Latency::MIN);
+	/// assert_eq!(Latency::new(25).milliseconds(), 25);
+	/// assert_eq!(Latency::new(u16::MAX), Latency::MAX);
+	/// ```
+	pub const fn new(milliseconds: u16) -> Self {
+		if milliseconds < Self::MIN.0 {
+			Self::MIN
+		} else if milliseconds > Self::MAX.0 {
+			Self::MAX
+		} else {
+			Self(milliseconds)
+		}
+	}
+
+	#[inline]
+	#[must_use]
+	/// Returns the inner amount of milliseconds.
+	pub const fn milliseconds(self) -> u16 {
+		self.0
+	}
+
+	#[inline]
+	#[must_use]
+	/// Returns this [`Latency`] as a [`Duration`].
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// # use std::time::Duration;
+	/// assert_eq!(Latency::DEFAULT.duration(), Duration::from_millis(50));
+	/// ```
+	pub const fn duration(self) -> Duration {
+		Duration::from_millis(self.0 as u64)
+	}
+
+	#[inline]
+	#[must_use]
+	/// How many frames this [`Latency`] is at `sample_rate`.
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(Latency::DEFAULT.frames(44_100), 2_205);
+	/// assert_eq!(Latency::LOW.frames(48_000), 480);
+	/// ```
+	pub const fn frames(self, sample_rate: u32) -> usize {
+		(self.0 as usize * sample_rate as usize) / 1000
+	}
+}
+
+impl Default for Latency {
+	fn default() -> Self {
+		Self::DEFAULT
+	}
+}
+
+impl From<u16> for Latency {
+	/// Same as [`Latency::new`].
+	fn from(milliseconds: u16) -> Self {
+		Self::new(milliseconds)
+	}
+}
+
+impl From<Latency> for u16 {
+	fn from(latency: Latency) -> Self {
+		latency.0
+	}
+}
+
+#[cfg(feature = "bincode")]
+impl bincode::Decode for Latency {
+	fn decode<D: bincode::de::Decoder>(decoder: &mut D) -> Result<Self, bincode::error::DecodeError> {
+		<u16 as bincode::Decode>::decode(decoder).map(Self::new)
+	}
+}
+#[cfg(feature = "bincode")]
+bincode::impl_borrow_decode!(Latency);
+
+//---------------------------------------------------------------------------------------------------- Free functions
+/// How long `frames` frames takes to play at `sample_rate`.
+pub(crate) fn frames_to_duration(frames: usize, sample_rate: u32) -> Duration {
+	if sample_rate == 0 {
+		return Duration::ZERO;
+	}
+	Duration::from_micros((frames as u64 * 1_000_000) / u64::from(sample_rate))
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[cfg(feature = "serde")]
+	// Out of range values are saturated, not taken as-is.
+	fn serde() {
+		use serde::de::{Deserialize,IntoDeserializer,value::Error};
+
+		let de = |ms: u16| Latency::deserialize(IntoDeserializer::<Error>::into_deserializer(ms)).unwrap();
+		assert_eq!(de(0), Latency::MIN);
+		assert_eq!(de(25), Latency::new(25));
+		assert_eq!(de(u16::MAX), Latency::MAX);
+	}
+
+	#[test]
+	#[cfg(feature = "bincode")]
+	// Out of range values are saturated, not taken as-is.
+	fn bincode() {
+		let config = bincode::config::standard();
+		let de = |ms: u16| {
+			let bytes = bincode::encode_to_vec(ms, config).unwrap();
+			bincode::decode_from_slice::<Latency, _>(&bytes, config).unwrap().0
+		};
+		assert_eq!(de(0), Latency::MIN);
+		assert_eq!(de(25), Latency::new(25));
+		assert_eq!(de(u16::MAX), Latency::MAX);
+
+		let bytes = bincode::encode_to_vec(Latency::HIGH, config).unwrap();
+		assert_eq!(bincode::decode_from_slice::<Latency, _>(&bytes, config).unwrap().0, Latency::HIGH);
+	}
+}
@@ -1,34 +1,100 @@
 //! Audio output backends.
+//!
+//! This module contains the audio output device types and functions.
 
 mod constants;
+pub(crate) use constants::{AUDIO_SAMPLE_BUFFER_LEN,SAMPLE_RATE_FALLBACK};
+
+mod device;
+pub use device::OutputDevice;
 
 mod output;
-pub(crate) use output::AudioOutput;
+pub(crate) use output::{AudioOutput,AudioWrite,impl_sink,impl_write,render_spec,process};
 
-mod output_or_dummy;
-pub(crate) use output_or_dummy::OutputOrDummy;
+mod open_config;
+pub(crate) use open_config::OpenConfig;
 
-mod dummy;
-pub(crate) use dummy::AudioOutputDummy;
+mod sample_format;
+pub use sample_format::SampleFormat;
+
+mod latency;
+pub use latency::Latency;
+
+mod glitch;
+pub use glitch::{Glitch,Glitches};
+pub(crate) use glitch::GlitchDetector;
+pub(crate) use latency::frames_to_duration;
+
+mod quantizer;
+pub(crate) use quantizer::Quantizer;
+
+mod sink;
+pub use sink::Sink;
+
+mod pull;
+pub use pull::Pull;
+pub(crate) use pull::{InitArgs as PullInitArgs,PullKeepAlive};
 
-// Use cubeb if:
-// - (only) it is enabled
-// - all backends are enabled
-// - no backend is enabled
+mod backend;
+pub use backend::OutputBackend;
+pub(crate) use backend::AudioOutputBackend as AudioOutputStruct;
+
+// The audio output backend tried first by default, this is
+// the backend that used to be the only one compiled in:
+// - `dummy` if testing or the `dummy` feature is enabled
+// - `cpal` if it is enabled
+// - `cubeb` otherwise
 cfg_if::cfg_if! {
 	if #[cfg(any(test, feature = "dummy"))] {
-		pub(crate) use dummy::AudioOutputDummy as AudioOutputStruct;
-		/// The audio output backend used.
+		/// The default audio output backend.
 		pub(crate) const AUDIO_OUTPUT_BACKEND: &str = "dummy";
 	} else if #[cfg(feature = "cpal")] {
-		mod cpal;
-		pub(crate) use self::cpal::Cpal as AudioOutputStruct;
-		/// The audio output backend used.
+		/// The default audio output backend.
 		pub(crate) const AUDIO_OUTPUT_BACKEND: &str = "cpal";
 	} else {
-		mod cubeb;
-		pub(crate) use self::cubeb::Cubeb as AudioOutputStruct;
-		/// The audio output backend used.
+		/// The default audio output backend.
 		pub(crate) const AUDIO_OUTPUT_BACKEND: &str = "cubeb";
 	}
 }
+
+// Backends.
+mod dummy;
+pub(crate) use dummy::AudioOutputDummy;
+mod file;
+pub(crate) use file::{AudioOutputFile,write_wav_header};
+mod custom;
+pub(crate) use custom::AudioOutputCustom;
+#[cfg(feature = "cpal")]
+mod cpal;
+#[cfg(feature = "cubeb")]
+mod cubeb;
+
+//---------------------------------------------------------------------------------------------------- Free functions
+/// List all the audio output devices available in `backend`.
+///
+/// Device IDs are specific to each backend.
+///
+/// The returned [`OutputDevice::id`]'s can be used to select an output device with
+/// [`InitConfig::output_device`](crate::config::InitConfig::output_device) or
+/// [`Engine::output_device`](crate::Engine::output_device).
+///
+/// # Errors
+/// This errors if the audio backend could not be queried for devices.
+///
+/// [`OutputError::BackendUnavailable`](crate::error::OutputError::BackendUnavailable)
+/// is returned if `backend` was not compiled in.
+pub fn devices(backend: OutputBackend) -> Result<Vec<OutputDevice>, crate::error::OutputError> {
+	AudioOutputStruct::<crate::resampler::ResamplerStruct>::devices_backend(backend)
+}
+
+/// Set the fake devices the `dummy` audio output backend exposes.
+///
+/// This is used for testing device selection without actual audio hardware.
+///
+/// The devices set here will be the ones returned by [`devices`]
+/// with [`OutputBackend::Dummy`] and will be the only ones that can be selected.
+///
+/// Exactly 1 of these devices should have [`OutputDevice::default`] set to `true`.
+pub fn dummy_devices(devices: Vec<OutputDevice>) {
+	dummy::set_devices(devices);
+}
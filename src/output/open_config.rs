@@ -0,0 +1,103 @@
+//! Settings used when opening an `AudioOutput`.
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	config::Dither,
+	output::{SampleFormat,Latency},
+	resampler::ResamplerConfig,
+};
+use symphonia::core::audio::SignalSpec;
+
+//----------------------------------------------------------------------------------------------- OpenConfig
+/// The `InitConfig` settings passed to `AudioOutput::try_open()`.
+#[derive(Copy,Clone,Debug)]
+pub(crate) struct OpenConfig {
+	/// Which resampler to create, if the
+	/// device does not support the audio's sample rate.
+	pub(crate) resampler: ResamplerConfig,
+	/// Should the device be opened at the audio's native
+	/// sample rate if it supports it, even if it is not
+	/// the device's default/preferred rate?
+	pub(crate) bit_perfect: bool,
+	/// The sample format to open the device with,
+	/// `None` means the device's preferred format.
+	pub(crate) sample_format: Option<SampleFormat>,
+	/// The dither to use when the sample format is an integer,
+	/// use `OpenConfig::dither()` which respects `bit_perfect`.
+	pub(crate) dither: Dither,
+	/// How much audio should be buffered between
+	/// us and the device (and within the device).
+	pub(crate) latency: Latency,
+}
+
+//----------------------------------------------------------------------------------------------- OpenConfig Impl
+impl OpenConfig {
+	/// Are samples of `spec` sent to a device opened at
+	/// `rate`, `channels` and `format` completely untouched?
+	///
+	/// This is only `true` if `bit_perfect` was requested, and there is no
+	/// resampling, no channel mapping and no format conversion, integer
+	/// formats are always quantized (and maybe dithered), so never count.
+	pub(crate) fn bit_perfect(
+		&self,
+		spec: &SignalSpec,
+		rate: u32,
+		channels: usize,
+		format: SampleFormat,
+	) -> bool {
+		self.bit_perfect &&
+		spec.rate == rate &&
+		spec.channels.count() == channels &&
+		format == SampleFormat::F32
+	}
+
+	/// The dither to quantize integer formats with.
+	///
+	/// Dither adds noise, so it is never used if `bit_perfect` is set.
+	pub(crate) const fn dither(&self) -> Dither {
+		if self.bit_perfect {
+			Dither::None
+		} else {
+			self.dither
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::resampler::ResamplerQuality;
+	use symphonia::core::audio::Channels;
+
+	const CONFIG: OpenConfig = OpenConfig {
+		resampler: ResamplerConfig { quality: ResamplerQuality::DEFAULT, custom: None },
+		bit_perfect: true,
+		sample_format: None,
+		dither: Dither::DEFAULT,
+		latency: Latency::DEFAULT,
+	};
+
+	#[test]
+	fn bit_perfect() {
+		let spec = SignalSpec::new(48_000, Channels::FRONT_LEFT | Channels::FRONT_RIGHT);
+
+		assert!(CONFIG.bit_perfect(&spec, 48_000, 2, SampleFormat::F32));
+		// Not requested.
+		assert!(!OpenConfig { bit_perfect: false, ..CONFIG }.bit_perfect(&spec, 48_000, 2, SampleFormat::F32));
+		// Resampled.
+		assert!(!CONFIG.bit_perfect(&spec, 44_100, 2, SampleFormat::F32));
+		// Channel mapped.
+		assert!(!CONFIG.bit_perfect(&spec, 48_000, 6, SampleFormat::F32));
+		// Quantized (and dithered).
+		assert!(!CONFIG.bit_perfect(&spec, 48_000, 2, SampleFormat::I16));
+		assert!(!CONFIG.bit_perfect(&spec, 48_000, 2, SampleFormat::I32));
+	}
+
+	#[test]
+	fn dither() {
+		assert_eq!(CONFIG.dither(), Dither::None);
+		let config = OpenConfig { bit_perfect: false, ..CONFIG };
+		assert_eq!(config.dither(), Dither::DEFAULT);
+	}
+}
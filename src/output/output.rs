@@ -11,8 +11,11 @@
 
 //----------------------------------------------------------------------------------------------- use
 use std::sync::{Arc, atomic::AtomicBool, OnceLock};
+use std::time::Duration;
 use crate::{
 	error::OutputError,
+	output::{OutputDevice,OutputBackend,OpenConfig,SampleFormat,Sink},
+	render::RenderSpec,
 	resampler::Resampler,
 	signal::Volume,
 	macros::{debug2,trace2,send,error2,try_send},
@@ -27,10 +30,10 @@ use crossbeam::channel::{Sender,Receiver};
 /// # Safety Notes
 /// Implementors are expected to implement these functions
 /// correctly according to the documentation invariants.
-pub(crate) trait AudioOutput: Sized {
-	/// The backend-specific error type, that can be
-	/// converted into our generic `OutputError` type.
-	type E: Into<OutputError>;
+///
+/// The public [`Sink`] trait is the simplified version of this that
+/// users can implement, all `AudioOutput`'s are also `Sink`'s, see `impl_sink!()`.
+pub(crate) trait AudioOutput: Sink + Sized {
 	/// The resampler we're using.
 	type R: Resampler;
 
@@ -48,10 +51,12 @@ pub(crate) trait AudioOutput: Sized {
 
 	/// What is the audio specification
 	/// this `AudioOutput` was created for?
-	fn spec(&self) -> SignalSpec;
+	fn signal_spec(&self) -> SignalSpec;
 	/// What is the duration
 	/// this `AudioOutput` was created for?
 	fn duration(&self) -> u64;
+	/// Which backend is this?
+	fn backend(&self) -> OutputBackend;
 	/// Drop all the stack stuff, return all heavy
 	/// (potentially real-time unsafe) stuff.
 	fn into_inner(self) -> (
@@ -62,44 +67,26 @@ pub(crate) trait AudioOutput: Sized {
 	);
 
 	//---------------------------------------------------------------------------- Must implement
-	/// Start playback
-	///
-	/// This should "enable" the stream so that it is
-	/// active and playing whatever audio buffers it has.
-	fn play(&mut self) -> Result<(), OutputError>;
+	// `play()`, `pause()`, `flush()` and `discard()` are from the public `Sink` trait.
 
-	/// Pause playback
-	///
-	/// This should completely "disable" the stream so that it
-	/// is playing nothing and using absolutely 0% CPU.
+	/// List all the output devices this backend can open.
 	///
-	/// This should _not_ flush the current buffer if any,
-	/// it should solely pause the stream and return immediately.
-	fn pause(&mut self) -> Result<(), OutputError>;
-
-	/// Flush all the current audio in the internal buffer (if any).
-	///
-	/// This means that all the audio have, we _must_ play it back to the speakers.
-	///
-	/// This function is expected to and is allowed to block.
-	fn flush(&mut self);
-
-	/// Discard all the audio in the internal buffer (if any).
-	///
-	/// This is like `flush()`, but we must _not_ play the
-	/// audio to the speakers, we must simply discard them.
-	///
-	/// This function is expected to and is allowed to block.
-	fn discard(&mut self);
+	/// Exactly 1 of these should be marked as the default device.
+	fn devices() -> Result<Vec<OutputDevice>, OutputError>;
 
 	/// Initialize a connection with the audio hardware/server.
 	///
 	/// The `signal_spec`'s sample rate and channel layout
 	/// must be followed, and an appropriate audio connection
 	/// with the same specification must be created.
+	///
+	/// If the `device` cannot be found, this must
+	/// return [`OutputError::DeviceUnavailable`].
 	fn try_open(
-		// The name of the audio stream?
-		name: String,
+		// The `OutputDevice::id` of the device to open.
+		//
+		// `None` means the system's default output device.
+		device: Option<&str>,
 		// The audio's signal specification.
 		// We're opening a stream matching this spec.
 		signal_spec: SignalSpec,
@@ -109,10 +96,8 @@ pub(crate) trait AudioOutput: Sized {
 		// device switching and continue playing
 		// to the original device opened.
 		disable_device_switch: bool,
-		// How many milliseconds should the audio buffer be?
-		//
-		// `None` will pick a reasonable default for low-latency pause/play.
-		buffer_milliseconds: Option<u8>,
+		// Settings from `InitConfig` (resampler, bit-perfect, sample format, latency, etc).
+		open_config: OpenConfig,
 		// Re-usable sample buffer.
 		// This could be from the last time `AudioOutput`.
 		sample_buf: SampleBuffer<f32>,
@@ -127,32 +112,37 @@ pub(crate) trait AudioOutput: Sized {
 	) -> Result<Self, OutputError>;
 
 	//---------------------------------------------------------------------------- Pre-implemented fns
-	/// Slight hack to access the local struct fields
-	/// of `AudioOutput` implementors.
-	///
-	/// In order for `write()` to be generic across/
-	/// all audio backends, it needs access to this
-	/// local data in a generic way, so basically:
-	///
-	/// Map local struct fields into this
-	/// function's inputs so `write()` can work.
-	fn write_pre(&mut self) -> (
-		&mut Option<Self::R>,   // Our resampler (none == no resampling needed)
-		&mut SampleBuffer<f32>, // A local buffer used for sample processing
-		&mut Vec<f32>,          // A local buffer of the _end result_ samples (potentially after resampling)
-		&Sender<f32>,           // Channel to send sample to audio backend
-		&Receiver<Self::E>,     // Channel to potentially receive an error, after writing the sample
-	);
-
-	/// Called once at the end of `write()` automatically.
-	///
-	/// Any other post-processing operations should be done here.
+	#[allow(clippy::too_many_arguments)]
+	/// Same as `try_open()`, but try each backend in `backends` in order.
 	///
-	/// If this errors, `write()` will return the error.
+	/// Backends that were not compiled in are skipped,
+	/// the error returned is the one from the last backend tried.
 	///
-	/// By default, it does nothing.
-	fn write_post(&mut self) -> Result<(), OutputError> {
-		Ok(())
+	/// This is only meaningful for `AudioOutputBackend` which wraps
+	/// multiple backends, by default `backends` is ignored.
+	fn try_open_backends(
+		backends: &[OutputBackend],
+		device: Option<&str>,
+		signal_spec: SignalSpec,
+		duration: symphonia::core::units::Duration,
+		disable_device_switch: bool,
+		open_config: OpenConfig,
+		sample_buf: SampleBuffer<f32>,
+		samples: Vec<f32>,
+		discarding: Arc<AtomicBool>,
+		resampler: Option<Self::R>,
+	) -> Result<Self, OutputError> {
+		Self::try_open(
+			device,
+			signal_spec,
+			duration,
+			disable_device_switch,
+			open_config,
+			sample_buf,
+			samples,
+			discarding,
+			resampler,
+		)
 	}
 
 	/// Fully write an audio buffer to the hardware/server (or internal buffer).
@@ -163,109 +153,82 @@ pub(crate) trait AudioOutput: Sized {
 	/// Invariants:
 	/// 1. `audio` may be a zero amount of frames (silence)
 	/// 2. `audio` may need to be resampled
-	/// 3. This should _not_ be re-implemented
-	/// 4. `write_post()` _must_ be implemented
-	fn write(
+	///
+	/// Backends that implement [`AudioWrite`] should
+	/// use `impl_write!()` instead of implementing this.
+	fn write_audio(
 		&mut self,
-		mut audio:  AudioBuffer<f32>,    // The actual audio buffer to be played
+		audio:  AudioBuffer<f32>,        // The actual audio buffer to be played
 		volume: Volume,                  // Volume target to multiply the samples by
 		to_gc: &Sender<AudioBuffer<f32>> // Channel to send garbage in a real-time safe manner
-	) -> Result<(), OutputError> {
-		trace2!("AudioOutput - write() with volume: {volume}");
+	) -> Result<(), OutputError>;
 
-		// Return if empty audio.
-		if audio.frames() == 0  {
-			trace2!("AudioOutput - audio.frames() == 0, returning early");
-			return Ok(());
-		}
+	/// Is the audio being resampled before being sent to the device?
+	///
+	/// This is implemented by `impl_write!()` for [`AudioWrite`] backends.
+	fn resampling(&mut self) -> bool;
 
-		// Get access to local struct fields.
-		let (
-			resampler,
-			sample_buffer,
-			samples_vec,
-			to_backend,
-			from_backend,
-		) = self.write_pre();
-
-		// PERF:
-		// Applying volume after resampling
-		// leads to (less) lossy audio.
-		let volume = volume.inner();
-		debug_assert!((0.0..=2.0).contains(&volume));
-
-		// Get raw `[f32]` sample data.
-		let samples = match resampler {
-			// No resampling required (common path).
-			None => {
-				// Apply volume transformation.
-				audio.transform(|f| f * volume);
-
-				// Copy into a `SampleBuffer` to access raw `f32`'s.
-				sample_buffer.copy_interleaved_typed(&audio);
-				sample_buffer.samples()
-			},
-
-			// We have a `Resampler`.
-			// That means when initializing, the audio device's
-			// preferred sample rate was not equal to the input
-			// audio spec. Assuming all future audio buffers
-			// have the sample spec, we need to resample this.
-			Some(resampler) => {
-				// Resample.
-				let resampled = resampler.resample(&audio);
-
-				// INVARIANT:
-				// This must be cleared as the buffer is probably
-				// full with samples from the previous `write()` call.
-				//
-				// Clearing a bunch of [f32]'s locally
-				// is probably faster than swapping with [Pool].
-				samples_vec.clear();
-				samples_vec.extend_from_slice(resampled);
-
-				let capacity = audio.capacity();
-				let frames   = audio.frames();
-
-				// Apply volume transformation.
-				// We can't use `.transform()` since we're
-				// working directly on `f32`'s and not `AudioBuffer`.
-				//
-				// Taken from: https://docs.rs/symphonia-core/0.5.3/src/symphonia_core/audio.rs.html#680-692
-				for plane in samples_vec.chunks_mut(capacity) {
-					for sample in &mut plane[0..frames] {
-						*sample *= volume;
-					}
-				}
-
-				samples_vec.as_ref()
-			},
-		};
+	/// Are the samples written with `write_audio()`
+	/// sent to the device bit-for-bit?
+	///
+	/// This must only be `true` if the samples are not resampled,
+	/// channel mapped, converted to another format or dithered on the
+	/// way to the device, `Audio` only asks if `OpenConfig::bit_perfect`
+	/// is set (and locks the volume at unity if so).
+	///
+	/// By default, this returns `false`.
+	fn bit_perfect(&self) -> bool {
+		false
+	}
 
-		// INVARIANT: other parts of `sansan` rely on the fact this hangs.
-		//
-		// Send audio data to the audio output backend.
-		//
-		// This hangs until we've sent all the samples, which
-		// most likely take a while as the backend will have a
-		// backlog of previous samples (buffer).
-		trace2!("AudioOutput - sending {} samples to backend", samples.len());
-		for sample in samples {
-			send!(to_backend, *sample);
-		};
+	/// The [`OutputDevice::id`] of the system's current default output device.
+	///
+	/// This should only return `Some` if the backend does
+	/// not already follow default device changes itself.
+	///
+	/// This is polled periodically by a helper thread (never by `Audio`
+	/// itself) so it is allowed to be relatively expensive.
+	///
+	/// By default, this returns `None`.
+	fn default_device() -> Option<String> {
+		None
+	}
 
-		// Send garbage to GC.
-		try_send!(to_gc, audio);
+	/// Change the spec/duration of this output without re-opening it.
+	///
+	/// This returns `None` if this output must be re-opened
+	/// with `try_open()` instead, which is the default.
+	///
+	/// Only outputs that cannot be re-opened (a user's `Sink`) implement this.
+	fn respec(&mut self, _spec: SignalSpec, _duration: u64) -> Option<Result<(), OutputError>> {
+		None
+	}
 
-		// If the backend errored, forward it.
-		if let Ok(error) = from_backend.try_recv() {
-			let error = error.into();
-			error2!("AudioOutput - error: {error}");
-			return Err(error);
-		}
+	/// The sample format the output was opened with.
+	///
+	/// By default, this returns `SampleFormat::F32`.
+	fn sample_format(&self) -> SampleFormat {
+		SampleFormat::F32
+	}
 
-		// Run post-processing function.
-		self.write_post()
+	/// The latency negotiated with the device when opened.
+	///
+	/// This is all the audio buffered between `write()` and the
+	/// device actually playing it (our channel + the device's buffer).
+	///
+	/// By default, this returns `None` (unknown).
+	fn latency(&self) -> Option<Duration> {
+		None
+	}
+
+	/// How many times has the device's callback underran?
+	///
+	/// This is the total since this output was opened,
+	/// `Audio` tracks the difference between writes.
+	///
+	/// By default, this returns `0` (not detected).
+	fn underruns(&self) -> usize {
+		0
 	}
 
 	/// `flush()` + `pause()`.
@@ -278,8 +241,14 @@ pub(crate) trait AudioOutput: Sized {
 
 	/// Create a "fake" dummy connection to the audio hardware/server.
 	///
+	/// `backends`, `device` and `open_config` are the same as in `try_open_backends()`.
+	///
 	/// NOTE: This pre-allocates the needed buffers and should only be called once.
-	fn dummy() -> Result<Self, OutputError> {
+	fn dummy(
+		backends: &[OutputBackend],
+		device: Option<&str>,
+		open_config: OpenConfig,
+	) -> Result<Self, OutputError> {
 		debug2!("AudioOutput - dummy()");
 
 		let spec = SignalSpec {
@@ -301,16 +270,248 @@ pub(crate) trait AudioOutput: Sized {
 		let discarding = Arc::new(AtomicBool::new(false));
 		let resampler = None;
 
-		Self::try_open(
-			"TODO".into(),
+		Self::try_open_backends(
+			backends,
+			device,
 			spec,
 			4096,
 			false,
-			None,
+			open_config,
 			sample_buf,
 			samples,
 			discarding,
 			resampler,
 		)
 	}
-}
\ No newline at end of file
+}
+
+//----------------------------------------------------------------------------------------------- AudioWrite Trait
+/// The generic sample-writing half of an [`AudioOutput`].
+///
+/// Backends that send interleaved `f32` samples over a channel
+/// to their audio thread implement this, and then get
+/// `AudioOutput::write_audio()` for free with `impl_write!()`.
+///
+/// Backends that cannot (e.g. the `AudioOutputBackend`
+/// wrapper, or a user's `Sink`) implement `write_audio()` directly.
+pub(crate) trait AudioWrite: AudioOutput {
+	/// The backend-specific error type, that can be
+	/// converted into our generic `OutputError` type.
+	type E: Into<OutputError>;
+
+	/// Slight hack to access the local struct fields
+	/// of `AudioOutput` implementors.
+	///
+	/// In order for `write()` to be generic across/
+	/// all audio backends, it needs access to this
+	/// local data in a generic way, so basically:
+	///
+	/// Map local struct fields into this
+	/// function's inputs so `write()` can work.
+	fn write_pre(&mut self) -> (
+		&mut Option<Self::R>,   // Our resampler (none == no resampling needed)
+		&mut SampleBuffer<f32>, // A local buffer used for sample processing
+		&mut Vec<f32>,          // A local buffer of the _end result_ samples (potentially after resampling)
+		&Sender<f32>,           // Channel to send sample to audio backend
+		&Receiver<Self::E>,     // Channel to potentially receive an error, after writing the sample
+	);
+
+	/// Called once at the end of `write_audio()` automatically.
+	///
+	/// Any other post-processing operations should be done here.
+	///
+	/// If this errors, `write_audio()` will return the error.
+	///
+	/// By default, it does nothing.
+	fn write_post(&mut self) -> Result<(), OutputError> {
+		Ok(())
+	}
+}
+
+//----------------------------------------------------------------------------------------------- AudioWrite helpers
+/// Fully write an audio buffer to the hardware/server (or internal buffer).
+///
+/// `Audio` will be calling this function so `gc` is where the `audio`
+/// should be sent to after usage - as we're (soft) real-time.
+///
+/// Invariants:
+/// 1. `audio` may be a zero amount of frames (silence)
+/// 2. `audio` may need to be resampled
+/// 3. `O::write_post()` is called after all samples are sent
+pub(crate) fn write_audio<O: AudioWrite>(
+	output: &mut O,
+	mut audio:  AudioBuffer<f32>,    // The actual audio buffer to be played
+	volume: Volume,                  // Volume target to multiply the samples by
+	to_gc: &Sender<AudioBuffer<f32>> // Channel to send garbage in a real-time safe manner
+) -> Result<(), OutputError> {
+	trace2!("AudioOutput - write_audio() with volume: {volume}");
+
+	// Return if empty audio.
+	if audio.frames() == 0  {
+		trace2!("AudioOutput - audio.frames() == 0, returning early");
+		return Ok(());
+	}
+
+	// Get access to local struct fields.
+	let (
+		resampler,
+		sample_buffer,
+		samples_vec,
+		to_backend,
+		from_backend,
+	) = output.write_pre();
+
+	// Get raw `[f32]` sample data.
+	let samples = process(&mut audio, volume, resampler.as_mut(), sample_buffer, samples_vec);
+
+	// INVARIANT: other parts of `sansan` rely on the fact this hangs.
+	//
+	// Send audio data to the audio output backend.
+	//
+	// This hangs until we've sent all the samples, which
+	// most likely take a while as the backend will have a
+	// backlog of previous samples (buffer).
+	trace2!("AudioOutput - sending {} samples to backend", samples.len());
+	for sample in samples {
+		send!(to_backend, *sample);
+	};
+
+	// Send garbage to GC.
+	try_send!(to_gc, audio);
+
+	// If the backend errored, forward it.
+	if let Ok(error) = from_backend.try_recv() {
+		let error = error.into();
+		error2!("AudioOutput - error: {error}");
+		return Err(error);
+	}
+
+	// Run post-processing function.
+	output.write_post()
+}
+
+/// Resample (if needed) and apply `volume` to `audio`,
+/// returning the interleaved samples to send to a device.
+///
+/// This is the processing every `AudioWrite` backend does, it is also
+/// used by `Render` and `Pull` so that they output exactly what is played.
+///
+/// `sample_buffer` must be able to hold `audio`.
+pub(crate) fn process<'a, R: Resampler>(
+	audio: &mut AudioBuffer<f32>,
+	volume: Volume,
+	resampler: Option<&'a mut R>,
+	sample_buffer: &'a mut SampleBuffer<f32>,
+	samples_vec: &'a mut Vec<f32>,
+) -> &'a [f32] {
+	// PERF:
+	// Applying volume after resampling
+	// leads to (less) lossy audio.
+	let volume = volume.inner();
+	debug_assert!((0.0..=2.0).contains(&volume));
+
+	match resampler {
+		// No resampling required (common path).
+		None => {
+			// Apply volume transformation.
+			audio.transform(|f| f * volume);
+
+			// Copy into a `SampleBuffer` to access raw `f32`'s.
+			sample_buffer.copy_interleaved_typed(audio);
+			sample_buffer.samples()
+		},
+
+		// We have a `Resampler`.
+		// That means when initializing, the audio device's
+		// preferred sample rate was not equal to the input
+		// audio spec. Assuming all future audio buffers
+		// have the sample spec, we need to resample this.
+		Some(resampler) => {
+			// Resample.
+			let resampled = resampler.resample(audio);
+
+			// INVARIANT:
+			// This must be cleared as the buffer is probably
+			// full with samples from the previous `write()` call.
+			//
+			// The resampled samples are already interleaved,
+			// so volume is applied to all of them while copying.
+			samples_vec.clear();
+			samples_vec.extend(resampled.iter().map(|f| f * volume));
+			samples_vec.as_ref()
+		},
+	}
+}
+
+/// Implement `AudioOutput::{write_audio,resampling}`
+/// for an [`AudioWrite`] backend.
+macro_rules! impl_write {
+	() => {
+		fn write_audio(
+			&mut self,
+			audio: symphonia::core::audio::AudioBuffer<f32>,
+			volume: $crate::signal::Volume,
+			to_gc: &crossbeam::channel::Sender<symphonia::core::audio::AudioBuffer<f32>>,
+		) -> Result<(), $crate::error::OutputError> {
+			$crate::output::output::write_audio(self, audio, volume, to_gc)
+		}
+
+		fn resampling(&mut self) -> bool {
+			$crate::output::AudioWrite::write_pre(self).0.is_some()
+		}
+	};
+}
+pub(crate) use impl_write;
+
+//----------------------------------------------------------------------------------------------- Sink helpers
+/// Convert a `SignalSpec` into the public `RenderSpec`.
+pub(crate) fn render_spec(spec: &SignalSpec) -> RenderSpec {
+	RenderSpec {
+		sample_rate: spec.rate,
+		channels: u16::try_from(spec.channels.count()).unwrap_or(u16::MAX),
+	}
+}
+
+/// `Sink::write()` for the built-in `AudioOutput`'s.
+///
+/// This sends already processed samples straight
+/// to the backend, skipping resampling and volume.
+pub(crate) fn write_samples<O: AudioWrite>(output: &mut O, samples: &[f32]) -> Result<(), OutputError> {
+	let (_, _, _, to_backend, from_backend) = output.write_pre();
+
+	for sample in samples {
+		send!(to_backend, *sample);
+	}
+
+	if let Ok(error) = from_backend.try_recv() {
+		return Err(error.into());
+	}
+
+	output.write_post()
+}
+
+/// Implement `Sink::{spec,set_spec,write}` for a built-in
+/// `AudioOutput`, in terms of its `AudioOutput` functions.
+///
+/// `play()`, `pause()`, `flush()` and `discard()` must still be implemented.
+macro_rules! impl_sink {
+	() => {
+		fn spec(&self) -> $crate::render::RenderSpec {
+			$crate::output::output::render_spec(&$crate::output::AudioOutput::signal_spec(self))
+		}
+
+		fn set_spec(&mut self, spec: $crate::render::RenderSpec) -> Result<(), $crate::error::OutputError> {
+			// Built-in outputs are re-opened upon spec changes instead.
+			if spec == $crate::output::Sink::spec(self) {
+				Ok(())
+			} else {
+				Err($crate::error::OutputError::InvalidSpec)
+			}
+		}
+
+		fn write(&mut self, samples: &[f32]) -> Result<(), $crate::error::OutputError> {
+			$crate::output::output::write_samples(self, samples)
+		}
+	};
+}
+pub(crate) use impl_sink;
@@ -0,0 +1,369 @@
+//! Pull-based audio output.
+//!
+//! Instead of `sansan` owning an audio output device (the `Audio` actor),
+//! the host application pulls samples out of `sansan` from its own audio
+//! callback, e.g. a game engine, DAW plugin, or another mixer.
+//!
+//! `Kernel`, `Decode` and the `Engine` signal API keep working as normal,
+//! this replaces the `Audio` actor's work, driven by the host's pulls.
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	actor::{
+		audio::AudioToKernel,
+		decode::DecodeToAudio,
+		kernel::KernelToAudio,
+	},
+	render::{Convert,RenderSpec},
+	signal::Volume,
+	resampler::ResamplerConfig,
+	state::AtomicState,
+	error::OutputError,
+	output::AUDIO_SAMPLE_BUFFER_LEN,
+	macros::{debug2,trace2,try_send},
+};
+use symphonia::core::{
+	audio::{AudioBuffer,Signal},
+	units::Time,
+};
+use crossbeam::channel::{Receiver,Sender};
+use std::sync::{
+	Arc,
+	atomic::{AtomicBool,Ordering},
+};
+
+#[allow(unused_imports)] // docs
+use crate::{Engine,config::InitConfig};
+
+//----------------------------------------------------------------------------------------------- Pull
+/// Pull-based audio output.
+///
+/// This exists when [`InitConfig::pull`] is set, in which case
+/// no `Audio` actor or audio output backend is spawned, and audio
+/// only progresses as samples are pulled with [`Pull::render`].
+///
+/// It can be used directly with [`Engine::render`], or taken out of the
+/// [`Engine`] with [`Engine::take_pull`] and moved into the host's audio callback.
+///
+/// Elapsed time in the `AudioState` and the [`Callbacks`](crate::config::Callbacks)
+/// (e.g. `elapsed`, `source_new`, `queue_end`) are driven by what is pulled.
+///
+/// Dropping the `Pull` is fine, the rest of the [`Engine`] keeps
+/// working, although audio (and elapsed time) no longer progresses.
+///
+/// Since there are no output devices, [`Engine::output_device`]
+/// always returns [`OutputError::BackendUnavailable`].
+pub struct Pull {
+	/// The spec the host wants samples in.
+	spec: RenderSpec,
+	/// Shared atomic audio state with the rest of the actors.
+	atomic_state: Arc<AtomicState>,
+	/// Our way of telling `Decode` we are ready for samples.
+	ready_to_recv: Arc<AtomicBool>,
+	/// Converted samples from the last `AudioBuffer`, not yet pulled.
+	leftover: Vec<f32>,
+	/// How many samples of `leftover` have been pulled.
+	leftover_pos: usize,
+	/// The timestamp and length (in seconds) of the `AudioBuffer`
+	/// in `leftover`, reported once it has been fully pulled.
+	leftover_time: Option<(Time, f32)>,
+	/// Resampler and buffers.
+	convert: Convert,
+	/// Should we output bit-perfect audio?
+	bit_perfect: bool,
+	/// Is the audio currently bit-perfect?
+	bit_perfect_active: bool,
+	/// Elapsed time, used for the elapsed callback.
+	elapsed_callback: f32,
+	/// Elapsed time, used for the `atomic_state.elapsed_refresh_rate`.
+	elapsed_audio_state: f32,
+	/// Has `Kernel` told us to shutdown?
+	shutdown: bool,
+
+	/// Channels the `Audio` actor would have owned.
+	to_gc:                   Sender<AudioBuffer<f32>>,
+	to_caller_elapsed:       Option<(Sender<Time>, f32)>,
+	from_decode:             Receiver<DecodeToAudio>,
+	to_kernel:               Sender<AudioToKernel>,
+	from_kernel:             Receiver<KernelToAudio>,
+	/// We never have output errors, but this must stay
+	/// alive so `Kernel`'s end of the channel stays connected.
+	_to_kernel_error:        Sender<OutputError>,
+}
+
+/// The arguments needed to create a [`Pull`].
+#[allow(clippy::missing_docs_in_private_items)]
+pub(crate) struct InitArgs {
+	pub(crate) spec:                    RenderSpec,
+	pub(crate) resampler:               ResamplerConfig,
+	pub(crate) bit_perfect:             bool,
+	pub(crate) atomic_state:            Arc<AtomicState>,
+	pub(crate) ready_to_recv:           Arc<AtomicBool>,
+	pub(crate) to_gc:                   Sender<AudioBuffer<f32>>,
+	pub(crate) to_caller_elapsed:       Option<(Sender<Time>, f32)>,
+	pub(crate) from_decode:             Receiver<DecodeToAudio>,
+	pub(crate) to_kernel:               Sender<AudioToKernel>,
+	pub(crate) from_kernel:             Receiver<KernelToAudio>,
+	pub(crate) to_kernel_error:         Sender<OutputError>,
+}
+
+impl Pull {
+	#[cold]
+	#[inline(never)]
+	/// Create a new `Pull`.
+	pub(crate) fn new(args: InitArgs) -> Self {
+		let InitArgs {
+			spec,
+			resampler,
+			bit_perfect,
+			atomic_state,
+			ready_to_recv,
+			to_gc,
+			to_caller_elapsed,
+			from_decode,
+			to_kernel,
+			from_kernel,
+			to_kernel_error,
+		} = args;
+
+		debug2!("Pull - new(), spec: {spec:?}");
+
+		// Allocate the buffers up-front so the
+		// first pulls don't allocate (in the common case).
+		let capacity = AUDIO_SAMPLE_BUFFER_LEN * usize::from(spec.channels);
+
+		Self {
+			spec,
+			atomic_state,
+			ready_to_recv,
+			leftover: Vec::with_capacity(capacity),
+			leftover_pos: 0,
+			leftover_time: None,
+			convert: Convert::with_capacity(resampler, capacity),
+			bit_perfect,
+			bit_perfect_active: false,
+			elapsed_callback: 0.0,
+			elapsed_audio_state: 0.0,
+			shutdown: false,
+			to_gc,
+			to_caller_elapsed,
+			from_decode,
+			to_kernel,
+			from_kernel,
+			_to_kernel_error: to_kernel_error,
+		}
+	}
+
+	#[must_use]
+	/// The spec samples are rendered in, as set in [`InitConfig::pull`].
+	pub const fn spec(&self) -> RenderSpec {
+		self.spec
+	}
+
+	#[cold]
+	#[inline(never)]
+	/// Clone our ends of the channels, for `Kernel` to hold onto.
+	///
+	/// The host may drop the `Pull` at any time, these keep
+	/// the channels connected such that the other actors
+	/// don't see a disconnect (and panic) when it does.
+	pub(crate) fn keep_alive(&self) -> PullKeepAlive {
+		PullKeepAlive {
+			_to_gc:           self.to_gc.clone(),
+			_from_decode:     self.from_decode.clone(),
+			_to_kernel:       self.to_kernel.clone(),
+			_from_kernel:     self.from_kernel.clone(),
+			_to_kernel_error: self._to_kernel_error.clone(),
+		}
+	}
+
+	/// Fill `out` with `frames` frames of interleaved samples.
+	///
+	/// `out` is interpreted as interleaved samples with [`Pull::spec`]'s
+	/// channel count, i.e. `frames * channels` samples are written.
+	/// If `out` is shorter than that, only as many whole frames as fit are written.
+	///
+	/// Anything that could not be filled (paused, nothing queued, `Decode` is
+	/// not keeping up, etc) is filled with silence.
+	///
+	/// Returns how many frames of actual audio were written.
+	///
+	/// This does not allocate in the common case, and
+	/// is meant to be called from a real-time audio callback.
+	pub fn render(&mut self, out: &mut [f32], frames: usize) -> usize {
+		let channels = usize::from(self.spec.channels);
+		let len = std::cmp::min(frames.saturating_mul(channels), out.len() - out.len() % channels);
+		let out = &mut out[..len];
+
+		self.handle_kernel();
+
+		if self.shutdown || !self.atomic_state.playing.load(Ordering::Acquire) {
+			out.fill(0.0);
+			return 0;
+		}
+
+		let mut written = 0;
+		while written < out.len() {
+			// Use up what we have first.
+			if self.leftover_pos < self.leftover.len() {
+				let n = std::cmp::min(out.len() - written, self.leftover.len() - self.leftover_pos);
+				out[written..written + n].copy_from_slice(&self.leftover[self.leftover_pos..self.leftover_pos + n]);
+				written += n;
+				self.leftover_pos += n;
+
+				// The whole buffer has been pulled, report the elapsed time.
+				if self.leftover_pos == self.leftover.len() {
+					if let Some((time, secs)) = self.leftover_time.take() {
+						self.elapsed(time, secs);
+					}
+				}
+				continue;
+			}
+
+			match self.from_decode.try_recv() {
+				Ok(DecodeToAudio::Buffer(data)) => self.audio_buffer(data),
+				Ok(DecodeToAudio::EndOfTrack) => {
+					debug2!("Pull - end_of_track()");
+					try_send!(self.to_kernel, AudioToKernel::EndOfTrack);
+				},
+				// Nothing is ready, fill the rest with silence.
+				Err(_) => {
+					trace2!("Pull - no audio ready, filling {} samples with silence", out.len() - written);
+					break;
+				},
+			}
+		}
+
+		out[written..].fill(0.0);
+		written / channels
+	}
+
+	/// Handle all pending messages from `Kernel`.
+	fn handle_kernel(&mut self) {
+		while let Ok(msg) = self.from_kernel.try_recv() {
+			match msg {
+				// We start playing on the next pull.
+				KernelToAudio::StartPlaying => (),
+				KernelToAudio::DiscardAudio => self.discard_audio(),
+				// There are no devices, the host owns the device.
+				KernelToAudio::OutputDevice(device, to_engine) => {
+					debug2!("Pull - output_device(), device: {device:?}, no backend");
+					try_send!(to_engine, Err(OutputError::BackendUnavailable));
+				},
+				KernelToAudio::OutputLatency(latency, to_engine) => {
+					debug2!("Pull - output_latency(), latency: {latency:?}, no backend");
+					try_send!(to_engine, Err(OutputError::BackendUnavailable));
+				},
+				KernelToAudio::Shutdown => {
+					debug2!("Pull - shutdown");
+					self.shutdown = true;
+				},
+			}
+		}
+	}
+
+	/// Convert a freshly decoded buffer into our `leftover` samples.
+	///
+	/// The elapsed time is reported once they are all pulled.
+	fn audio_buffer(&mut self, (mut audio, time): (AudioBuffer<f32>, Time)) {
+		let spec = *audio.spec();
+
+		#[allow(clippy::cast_precision_loss)]
+		let nominal_seconds = audio.capacity() as f32 / spec.rate as f32;
+
+		// In bit-perfect mode, volume is locked at unity, and the
+		// audio is only bit-perfect if the host's spec matches it.
+		let volume = if self.bit_perfect {
+			let active = spec.rate == self.spec.sample_rate && spec.channels.count() == usize::from(self.spec.channels);
+			if active != self.bit_perfect_active {
+				debug2!("Pull - bit-perfect: {active}");
+				self.bit_perfect_active = active;
+				try_send!(self.to_kernel, AudioToKernel::BitPerfect(active));
+			}
+			Volume::ONE
+		} else {
+			self.atomic_state.volume.load()
+		};
+
+		// Resample and map the channels to what the host wants.
+		let samples = self.convert.convert(&mut audio, self.spec, volume);
+		self.leftover.clear();
+		self.leftover_pos = 0;
+		self.leftover.extend_from_slice(samples);
+
+		// The resampler may hold onto partial buffers, in which
+		// case there is nothing to pull, so report it right away.
+		if self.leftover.is_empty() {
+			self.elapsed(time, nominal_seconds);
+		} else {
+			self.leftover_time = Some((time, nominal_seconds));
+		}
+
+		// Send garbage to [Gc] instead of dropping locally.
+		try_send!(self.to_gc, audio);
+	}
+
+	/// `secs` of audio starting at `time` have been pulled,
+	/// update `Kernel` and `Caller` if needed.
+	fn elapsed(&mut self, time: Time, secs: f32) {
+		// If we're past the refresh rate for `AudioState`,
+		// tell [Kernel] to update with the new timestamp.
+		self.elapsed_audio_state += secs;
+		if self.elapsed_audio_state >= self.atomic_state.elapsed_refresh_rate.load() {
+			try_send!(self.to_kernel, AudioToKernel::WroteAudioBuffer(time));
+			self.elapsed_audio_state = 0.0;
+		}
+
+		// Notify [Caller] if enough time
+		// has elapsed in the current track.
+		if let Some((sender, elapsed_target)) = self.to_caller_elapsed.as_ref() {
+			self.elapsed_callback += secs;
+			if self.elapsed_callback >= *elapsed_target {
+				try_send!(sender, time);
+				self.elapsed_callback = 0.0;
+			}
+		}
+	}
+
+	/// Discard all the audio available, _do not_ play it.
+	fn discard_audio(&mut self) {
+		debug2!("Pull - discard_audio()");
+
+		self.elapsed_callback = 0.0;
+		self.elapsed_audio_state = 0.0;
+		self.leftover.clear();
+		self.leftover_pos = 0;
+		self.leftover_time = None;
+		self.convert.reset();
+
+		while let Ok(msg) = self.from_decode.try_recv() {
+			match msg {
+				DecodeToAudio::Buffer(msg) => try_send!(self.to_gc, msg.0),
+				DecodeToAudio::EndOfTrack => continue,
+			}
+		}
+
+		self.ready_to_recv.store(true, Ordering::Release);
+	}
+}
+
+//----------------------------------------------------------------------------------------------- PullKeepAlive
+/// [`Pull`]'s ends of its channels, held by `Kernel`, see [`Pull::keep_alive`].
+#[allow(clippy::missing_docs_in_private_items)]
+pub(crate) struct PullKeepAlive {
+	_to_gc:           Sender<AudioBuffer<f32>>,
+	_from_decode:     Receiver<DecodeToAudio>,
+	_to_kernel:       Sender<AudioToKernel>,
+	_from_kernel:     Receiver<KernelToAudio>,
+	_to_kernel_error: Sender<OutputError>,
+}
+
+impl std::fmt::Debug for Pull {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Pull")
+			.field("spec", &self.spec)
+			.field("leftover", &(self.leftover.len() - self.leftover_pos))
+			.field("shutdown", &self.shutdown)
+			.finish_non_exhaustive()
+	}
+}
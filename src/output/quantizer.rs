@@ -0,0 +1,177 @@
+//! Float to integer sample conversion.
+//!
+//! This file implements `Quantizer`, which converts the
+//! `f32` samples `sansan` processes into integer samples
+//! for outputs that use an integer `SampleFormat`,
+//! applying the configured `Dither`.
+//!
+//! This is used in the backend's audio callback/thread, i.e.
+//! it is the very last step before samples reach the output.
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	config::Dither,
+	output::SampleFormat,
+};
+
+//----------------------------------------------------------------------------------------------- Quantizer
+/// Converts `f32` samples to integers, with dither.
+///
+/// Samples must be given interleaved, in order, the current
+/// channel is tracked internally for noise shaping.
+pub(crate) struct Quantizer {
+	/// The dither to apply.
+	dither: Dither,
+	/// `2^(bits - 1)`, what `1.0` maps to.
+	scale: f32,
+	/// Max integer value.
+	max: f32,
+	/// Min integer value.
+	min: f32,
+	/// How much to shift the result left (`I32` only).
+	shift: u32,
+	/// `xorshift32` state for the dither noise.
+	rng: u32,
+	/// The last rounding error of each channel (`Dither::NoiseShaped` only).
+	error: Vec<f32>,
+	/// The channel of the next sample.
+	channel: usize,
+}
+
+impl Quantizer {
+	#[cold]
+	#[inline(never)]
+	/// Create a new `Quantizer` for `format`.
+	///
+	/// `f32` only has 24 bits of precision, so `SampleFormat::I32`
+	/// is quantized to 24 bits and shifted into the upper bits.
+	///
+	/// `channels` is clamped to at least `1`.
+	pub(crate) fn new(format: SampleFormat, dither: Dither, channels: usize) -> Self {
+		let bits = std::cmp::min(format.bits(), 24);
+		let shift = u32::from(format.bits() - bits);
+		#[allow(clippy::cast_precision_loss)]
+		let scale = (1_u32 << (bits - 1)) as f32;
+
+		Self {
+			dither,
+			scale,
+			max: scale - 1.0,
+			min: -scale,
+			shift,
+			rng: 0x9E37_79B9,
+			error: vec![0.0; std::cmp::max(channels, 1)],
+			channel: 0,
+		}
+	}
+
+	#[inline]
+	/// Uniform random number in `0.0..1.0`.
+	fn random(&mut self) -> f32 {
+		// xorshift32, more than good enough for dither.
+		self.rng ^= self.rng << 13;
+		self.rng ^= self.rng >> 17;
+		self.rng ^= self.rng << 5;
+		#[allow(clippy::cast_precision_loss)]
+		let r = (self.rng >> 8) as f32 / (1_u32 << 24) as f32;
+		r
+	}
+
+	#[inline]
+	#[allow(clippy::cast_possible_truncation)]
+	/// Quantize a single sample, returning the integer
+	/// in the output format's range (left aligned for `I32`).
+	pub(crate) fn quantize(&mut self, sample: f32) -> i32 {
+		let channel = self.channel;
+		self.channel = (channel + 1) % self.error.len();
+
+		let x = sample * self.scale;
+
+		// Subtract the last error (noise shaping).
+		let v = match self.dither {
+			Dither::NoiseShaped => x - self.error[channel],
+			Dither::None | Dither::Tpdf => x,
+		};
+
+		// TPDF, ±1 LSB.
+		let noise = match self.dither {
+			Dither::None => 0.0,
+			Dither::Tpdf | Dither::NoiseShaped => self.random() - self.random(),
+		};
+
+		let q = (v + noise).round().clamp(self.min, self.max);
+
+		if self.dither == Dither::NoiseShaped {
+			// Clamped, so clipping doesn't feed back huge errors.
+			self.error[channel] = (q - v).clamp(-2.0, 2.0);
+		}
+
+		(q as i32) << self.shift
+	}
+
+	#[inline]
+	#[allow(clippy::cast_possible_truncation)]
+	/// `quantize()` for `SampleFormat::I16`.
+	pub(crate) fn quantize_i16(&mut self, sample: f32) -> i16 {
+		self.quantize(sample) as i16
+	}
+}
+
+//----------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	// No dither is plain rounding, with clipping.
+	fn none() {
+		let mut q = Quantizer::new(SampleFormat::I16, Dither::None, 2);
+		assert_eq!(q.quantize_i16(0.0), 0);
+		assert_eq!(q.quantize_i16(0.5), 16384);
+		assert_eq!(q.quantize_i16(-0.5), -16384);
+		assert_eq!(q.quantize_i16(1.0), i16::MAX);
+		assert_eq!(q.quantize_i16(-1.0), i16::MIN);
+		assert_eq!(q.quantize_i16(2.0), i16::MAX);
+		assert_eq!(q.quantize_i16(-2.0), i16::MIN);
+
+		let mut q = Quantizer::new(SampleFormat::I24, Dither::None, 2);
+		assert_eq!(q.quantize(0.5), 1 << 22);
+		assert_eq!(q.quantize(1.0), (1 << 23) - 1);
+
+		// 24 bits shifted into the upper bits.
+		let mut q = Quantizer::new(SampleFormat::I32, Dither::None, 2);
+		assert_eq!(q.quantize(0.5), 1 << 30);
+		assert_eq!(q.quantize(-1.0), i32::MIN);
+	}
+
+	#[test]
+	// TPDF stays within 1 LSB of the rounded value.
+	fn tpdf() {
+		let mut q = Quantizer::new(SampleFormat::I16, Dither::Tpdf, 1);
+		let mut sum = 0_i64;
+		for _ in 0..10_000 {
+			let s = q.quantize(0.25);
+			assert!((8191..=8193).contains(&s), "{s}");
+			sum += i64::from(s);
+		}
+		// Unbiased on average.
+		assert_eq!((sum as f64 / 10_000.0).round(), 8192.0);
+	}
+
+	#[test]
+	// Noise shaping keeps the average error around 0.
+	fn noise_shaped() {
+		let mut q = Quantizer::new(SampleFormat::I16, Dither::NoiseShaped, 2);
+		let sample = 0.123_45_f32;
+		let target = f64::from(sample) * 32768.0;
+
+		let mut sum = 0.0;
+		for _ in 0..10_000 {
+			sum += f64::from(q.quantize(sample));
+			// Other channel.
+			q.quantize(-sample);
+		}
+		assert!((sum / 10_000.0 - target).abs() < 0.01);
+	}
+}
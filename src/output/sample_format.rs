@@ -0,0 +1,79 @@
+//! Audio output sample formats.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::{InitConfig,Dither},
+};
+
+//---------------------------------------------------------------------------------------------------- SampleFormat
+/// The sample format audio is sent to the output in.
+///
+/// By default, the output device's preferred format is used,
+/// a different one can be requested with [`InitConfig::sample_format`].
+///
+/// The format currently being used can be checked with [`Engine::output_format`].
+///
+/// Audio is always processed as [`SampleFormat::F32`], integer formats
+/// are converted to at the very end, with [`InitConfig::dither`] applied.
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SampleFormat {
+	#[default]
+	/// 32-bit float.
+	F32,
+	/// 16-bit signed integer.
+	I16,
+	/// 24-bit signed integer (packed, 3 bytes).
+	I24,
+	/// 32-bit signed integer.
+	///
+	/// Since audio is processed as 32-bit floats, only
+	/// 24 bits of this are meaningful, the lower 8 bits are `0`.
+	I32,
+}
+
+impl SampleFormat {
+	/// Returns [`Self::F32`].
+	pub const DEFAULT: Self = Self::F32;
+
+	#[must_use]
+	/// How many bits each sample takes up.
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert_eq!(SampleFormat::F32.bits(), 32);
+	/// assert_eq!(SampleFormat::I16.bits(), 16);
+	/// assert_eq!(SampleFormat::I24.bits(), 24);
+	/// assert_eq!(SampleFormat::I32.bits(), 32);
+	/// ```
+	pub const fn bits(self) -> u16 {
+		match self {
+			Self::I16 => 16,
+			Self::I24 => 24,
+			Self::F32 | Self::I32 => 32,
+		}
+	}
+
+	#[must_use]
+	/// Is this an integer format?
+	///
+	/// ```rust
+	/// # use sansan::output::*;
+	/// assert!(!SampleFormat::F32.is_integer());
+	/// assert!(SampleFormat::I16.is_integer());
+	/// ```
+	pub const fn is_integer(self) -> bool {
+		!matches!(self, Self::F32)
+	}
+}
@@ -0,0 +1,147 @@
+//! Public audio output trait.
+//!
+//! This file defines `Sink`, the public (simplified) version
+//! of `AudioOutput`, which users can implement to route audio
+//! somewhere other than the built-in backends.
+//!
+//! All the built-in backends also implement `Sink`.
+
+//----------------------------------------------------------------------------------------------- use
+use crate::{
+	error::OutputError,
+	render::RenderSpec,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	config::InitConfig,
+	output::OutputBackend,
+	signal::Volume,
+};
+
+//----------------------------------------------------------------------------------------------- Sink
+/// An audio output.
+///
+/// This is the trait all audio outputs in `sansan` implement,
+/// including the built-in backends (`cubeb`, `cpal`, etc).
+///
+/// A custom `Sink` can be given to [`InitConfig::output_sink`] to route
+/// audio to something else entirely, e.g. an RTP stream, shared memory,
+/// or a test harness. In that case, [`OutputBackend::Custom`] is used.
+///
+/// The samples a `Sink` receives are always:
+/// - interleaved [`f32`]'s
+/// - already multiplied by the [`Volume`]
+/// - in the spec last given to [`Sink::set_spec`]
+///
+/// No resampling is done for custom `Sink`'s, the spec
+/// will be whatever the currently playing audio is.
+///
+/// ## Timing
+/// `sansan`'s playback is paced by the output, i.e. [`Sink::write`]
+/// is expected to block roughly as long as the samples take to "play",
+/// like a real audio device does once its buffer is full.
+///
+/// If `write()` returns immediately, tracks will be played
+/// as fast as they can be decoded.
+///
+/// ## Errors
+/// Errors are reported through
+/// [`Callbacks::error_output`](crate::config::Callbacks::error_output).
+///
+/// ```rust
+/// # use sansan::{output::*,render::*,error::*};
+/// /// A sink that counts the samples it receives.
+/// struct Counter {
+///     spec: RenderSpec,
+///     samples: usize,
+///     playing: bool,
+/// }
+///
+/// impl Sink for Counter {
+///     fn spec(&self) -> RenderSpec { self.spec }
+///     fn set_spec(&mut self, spec: RenderSpec) -> Result<(), OutputError> {
+///         self.spec = spec;
+///         Ok(())
+///     }
+///     fn write(&mut self, samples: &[f32]) -> Result<(), OutputError> {
+///         self.samples += samples.len();
+///         Ok(())
+///     }
+///     fn play(&mut self) -> Result<(), OutputError> {
+///         self.playing = true;
+///         Ok(())
+///     }
+///     fn pause(&mut self) -> Result<(), OutputError> {
+///         self.playing = false;
+///         Ok(())
+///     }
+///     fn flush(&mut self) {}
+///     fn discard(&mut self) {}
+/// }
+/// ```
+pub trait Sink {
+	/// The spec this `Sink` is currently set to.
+	fn spec(&self) -> RenderSpec;
+
+	/// Set the spec of the samples that will be written next.
+	///
+	/// This is called before any samples are written,
+	/// and whenever the audio's spec changes (e.g. a
+	/// track with a different sample rate starts).
+	///
+	/// Any audio written before this call should be
+	/// played (or discarded) as is in the old spec.
+	///
+	/// # Errors
+	/// If the spec is not supported, return an error,
+	/// [`OutputError::InvalidSpec`] and friends are the common ones.
+	fn set_spec(&mut self, spec: RenderSpec) -> Result<(), OutputError>;
+
+	/// Write interleaved samples.
+	///
+	/// See the [`Sink`] docs for the expected timing.
+	///
+	/// # Errors
+	/// If the samples could not be written.
+	fn write(&mut self, samples: &[f32]) -> Result<(), OutputError>;
+
+	/// Start playback.
+	///
+	/// This should "enable" the output so that it is
+	/// active and playing whatever samples it has.
+	///
+	/// # Errors
+	/// If playback could not be started.
+	fn play(&mut self) -> Result<(), OutputError>;
+
+	/// Pause playback.
+	///
+	/// This should completely "disable" the output so that it
+	/// is playing nothing, it should _not_ flush the current
+	/// samples (if any) and should return immediately.
+	///
+	/// # Errors
+	/// If playback could not be paused.
+	fn pause(&mut self) -> Result<(), OutputError>;
+
+	/// Play all the samples currently buffered (if any).
+	///
+	/// This is allowed to block until they have been played.
+	fn flush(&mut self);
+
+	/// Discard all the samples currently buffered (if any).
+	///
+	/// This is like `flush()`, but the samples must _not_ be played.
+	///
+	/// This is allowed to block until they have been discarded.
+	fn discard(&mut self);
+}
+
+impl std::fmt::Debug for dyn Sink + Send {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Sink")
+			.field("spec", &self.spec())
+			.finish_non_exhaustive()
+	}
+}
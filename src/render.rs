@@ -0,0 +1,498 @@
+//! Offline rendering.
+//!
+//! This module renders [`Source`]'s to a WAV file or a callback
+//! as fast as they can be decoded, i.e. faster than real-time.
+//!
+//! No [`Engine`], audio output device, or actors are involved,
+//! rendering happens entirely on the calling thread.
+//!
+//! The same code the [`Engine`] uses for playback is used:
+//! 1. Decode (with gapless trimming of encoder delay/padding), corrupt
+//!    packets are skipped and their gap filled with silence
+//! 2. Resample (only if the output sample rate differs)
+//! 3. Apply [`Volume`]
+//!
+//! Sources with a different channel count than the first
+//! are mapped to its channels, the same way [`Pull`](crate::output::Pull) does.
+//!
+//! Note that `sansan` currently has no crossfade or
+//! gain (ReplayGain) settings, so none are applied here,
+//! sources are simply rendered back-to-back.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	source::{Source, source_decode::{SourceDecode,PacketDecode,Next}},
+	state::AudioState,
+	signal::Volume,
+	extra_data::ExtraData,
+	resampler::{Resampler,ResamplerStruct,ResamplerConfig,ResamplerQuality},
+	error::{RenderError,DecodeError},
+	output::{SampleFormat,write_wav_header,process},
+	macros::{debug2,trace2},
+};
+use symphonia::core::{
+	audio::{AudioBuffer,SampleBuffer,Signal},
+	formats::{SeekMode,SeekTo},
+	units::Time,
+};
+use std::{
+	fs::File,
+	io::{BufWriter,Seek,SeekFrom,Write},
+	num::NonZeroUsize,
+	path::Path,
+};
+
+#[allow(unused_imports)] // docs
+use crate::Engine;
+
+//---------------------------------------------------------------------------------------------------- RenderSpec
+/// The audio spec of rendered samples.
+///
+/// All samples are interleaved [`f32`]'s.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub struct RenderSpec {
+	/// Sample rate in Hz.
+	pub sample_rate: u32,
+	/// Amount of channels.
+	pub channels: u16,
+}
+
+//---------------------------------------------------------------------------------------------------- Rendered
+/// A summary of a finished render.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,PartialEq,Eq,PartialOrd,Ord,Hash)]
+pub struct Rendered {
+	/// The spec of the rendered samples.
+	pub spec: RenderSpec,
+	/// Total amount of frames rendered (samples per channel).
+	pub frames: u64,
+	/// Amount of sources rendered.
+	pub sources: usize,
+	/// Amount of corrupt packets that were skipped.
+	pub packets_skipped: usize,
+}
+
+impl Rendered {
+	#[must_use]
+	#[allow(clippy::cast_precision_loss)]
+	/// The length of the rendered audio in seconds.
+	pub fn secs(&self) -> f64 {
+		self.frames as f64 / f64::from(self.spec.sample_rate)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Render
+/// Offline renderer.
+///
+/// Create with [`Render::new`] or from an [`AudioState`], then
+/// render with [`Render::to_wav`] or [`Render::to_callback`].
+///
+/// ```rust,no_run
+/// # use sansan::{render::*,source::*,signal::*};
+/// let sources = vec![
+///     Source::<()>::from("/path/to/1.mp3"),
+///     Source::<()>::from("/path/to/2.flac"),
+/// ];
+///
+/// let rendered = Render {
+///     sources,
+///     volume: Volume::ONE,
+///     sample_rate: Some(48_000),
+///     ..Render::new(vec![])
+/// }.to_wav("/tmp/output.wav").unwrap();
+///
+/// println!("rendered {} seconds", rendered.secs());
+/// ```
+#[derive(Clone,Debug,PartialEq)]
+pub struct Render<Extra: ExtraData> {
+	/// The sources to render, in order.
+	pub sources: Vec<Source<Extra>>,
+
+	/// The volume applied to all samples.
+	pub volume: Volume,
+
+	/// The output sample rate.
+	///
+	/// If `None`, the sample rate of the first source is used.
+	///
+	/// Sources with a different sample rate are resampled.
+	pub sample_rate: Option<u32>,
+
+	/// The resampler used when sources are resampled.
+	pub resampler: ResamplerQuality,
+
+	/// Where to start (in seconds) within the first source.
+	pub start: f32,
+
+	/// How many corrupt packets in a row are skipped
+	/// before returning [`RenderError::Decode`].
+	///
+	/// This acts the same as (and has the same default as)
+	/// [`InitConfig::error_decode_packet_threshold`](crate::config::InitConfig::error_decode_packet_threshold).
+	pub packet_error_threshold: usize,
+}
+
+impl<Extra: ExtraData> Render<Extra> {
+	#[must_use]
+	/// Create a [`Render`] with default settings for `sources`.
+	///
+	/// ```rust
+	/// # use sansan::{render::*,signal::*};
+	/// let render = Render::<()>::new(vec![]);
+	/// assert_eq!(render.volume, Volume::ONE);
+	/// assert_eq!(render.sample_rate, None);
+	/// assert_eq!(render.resampler, sansan::resampler::ResamplerQuality::Fft);
+	/// assert_eq!(render.start, 0.0);
+	/// assert_eq!(render.packet_error_threshold, 0);
+	/// ```
+	pub const fn new(sources: Vec<Source<Extra>>) -> Self {
+		Self {
+			sources,
+			volume: Volume::ONE,
+			sample_rate: None,
+			resampler: ResamplerQuality::DEFAULT,
+			start: 0.0,
+			packet_error_threshold: 0,
+		}
+	}
+
+	/// Render all sources to a 32-bit float WAV file at `path`.
+	///
+	/// The file is created (or truncated) before rendering starts.
+	///
+	/// # Errors
+	/// If any source fails to load/decode, or the file could not
+	/// be written, an error is returned and the file is left partially written.
+	pub fn to_wav(self, path: impl AsRef<Path>) -> Result<Rendered, RenderError> {
+		let path = path.as_ref();
+		debug2!("Render - to_wav(), path: {}", path.display());
+
+		let mut file = BufWriter::new(File::create(path)?);
+		let mut data_len: u64 = 0;
+		let mut header_written = false;
+
+		let rendered = self.render(&mut |samples, spec| {
+			if !header_written {
+				// Sizes are filled in after rendering.
+				write_wav_header(&mut file, spec.sample_rate, spec.channels, SampleFormat::F32, 0)?;
+				header_written = true;
+			}
+			for sample in samples {
+				file.write_all(&sample.to_le_bytes())?;
+			}
+			data_len += samples.len() as u64 * 4;
+			Ok(())
+		})?;
+
+		file.seek(SeekFrom::Start(0))?;
+		write_wav_header(&mut file, rendered.spec.sample_rate, rendered.spec.channels, SampleFormat::F32, data_len)?;
+		file.flush()?;
+
+		Ok(rendered)
+	}
+
+	/// Render all sources into `callback`.
+	///
+	/// `callback` is called with interleaved samples
+	/// and their spec as they are decoded.
+	///
+	/// The spec will be the same for every call.
+	///
+	/// # Errors
+	/// If any source fails to load/decode, an error is returned
+	/// (after `callback` has received all samples up until that point).
+	pub fn to_callback<F>(self, mut callback: F) -> Result<Rendered, RenderError>
+	where
+		F: FnMut(&[f32], RenderSpec),
+	{
+		debug2!("Render - to_callback()");
+		self.render(&mut |samples, spec| {
+			callback(samples, spec);
+			Ok(())
+		})
+	}
+
+	/// Decode, resample, apply volume, and send samples to `write`.
+	fn render(
+		self,
+		write: &mut dyn FnMut(&[f32], RenderSpec) -> std::io::Result<()>,
+	) -> Result<Rendered, RenderError> {
+		if self.sources.is_empty() {
+			return Err(RenderError::Empty);
+		}
+
+		let mut spec: Option<RenderSpec> = None;
+		let mut frames: u64 = 0;
+		let mut packets = PacketDecode::new(self.packet_error_threshold);
+		let mut packets_skipped = 0;
+		let mut convert = Convert::new(ResamplerConfig {
+			quality: self.resampler,
+			custom: None,
+		});
+
+		let sources = self.sources.len();
+		for (i, source) in self.sources.into_iter().enumerate() {
+			debug2!("Render - source [{i}]: {source:?}");
+			let mut s: SourceDecode = source.try_into()?;
+			packets.reset();
+
+			if i == 0 && self.start > 0.0 {
+				s.reader.seek(
+					SeekMode::Accurate,
+					SeekTo::Time { time: Time::from(self.start), track_id: None },
+				).map_err(DecodeError::from)?;
+			}
+
+			loop {
+				let next = s.next(&mut packets);
+				if let Some(error) = packets.error.take() {
+					return Err(error.into());
+				}
+
+				let mut audio = match next {
+					Next::Audio(audio, _, _) => audio,
+					Next::Skipped(_) => continue,
+					Next::End => break,
+					Next::Source(error) => return Err(error.into()),
+				};
+
+				if audio.frames() == 0 {
+					continue;
+				}
+
+				// The first decoded audio decides the output spec.
+				let audio_spec = *audio.spec();
+				let spec = *spec.get_or_insert(RenderSpec {
+					sample_rate: self.sample_rate.unwrap_or(audio_spec.rate),
+					channels: u16::try_from(audio_spec.channels.count()).unwrap_or(u16::MAX),
+				});
+
+				let out = convert.convert(&mut audio, spec, self.volume);
+
+				if out.is_empty() {
+					continue;
+				}
+
+				write(out, spec)?;
+				frames += (out.len() / usize::from(spec.channels)) as u64;
+			}
+
+			packets_skipped += packets.total;
+		}
+
+		let Some(spec) = spec else {
+			return Err(RenderError::Empty);
+		};
+
+		let rendered = Rendered { spec, frames, sources, packets_skipped };
+		debug2!("Render - done: {rendered:?}");
+		Ok(rendered)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Convert
+/// Turns decoded `AudioBuffer`'s into interleaved samples
+/// at some target [`RenderSpec`], with volume applied.
+///
+/// This holds re-usable buffers, and is also used by [`crate::output::Pull`].
+///
+/// Resampling and volume are done by the same code the `AudioOutput` backends use.
+pub(crate) struct Convert {
+	/// Which resampler to create.
+	config: ResamplerConfig,
+	/// Buffer for interleaving without resampling.
+	sample_buffer: Option<SampleBuffer<f32>>,
+	/// The current resampler, along with the `(capacity, sample_rate, channels)` it was created for.
+	resampler: Option<(ResamplerStruct, usize, u32, usize)>,
+	/// Buffer for the resampled samples.
+	samples: Vec<f32>,
+	/// Buffer for samples mapped to the target channel count.
+	remap: Vec<f32>,
+}
+
+impl Convert {
+	/// Create a new `Convert` with no buffers allocated.
+	pub(crate) const fn new(config: ResamplerConfig) -> Self {
+		Self {
+			config,
+			sample_buffer: None,
+			resampler: None,
+			samples: vec![],
+			remap: vec![],
+		}
+	}
+
+	/// Create a new `Convert` with room for `capacity` output samples.
+	pub(crate) fn with_capacity(config: ResamplerConfig, capacity: usize) -> Self {
+		Self {
+			samples: Vec::with_capacity(capacity),
+			remap: Vec::with_capacity(capacity),
+			..Self::new(config)
+		}
+	}
+
+	/// Throw away any partial chunk the resampler is holding onto.
+	pub(crate) fn reset(&mut self) {
+		if let Some((resampler, _, _, _)) = self.resampler.as_mut() {
+			resampler.reset();
+		}
+	}
+
+	/// Convert `audio` into interleaved samples in `target`'s spec, multiplied by `volume`.
+	///
+	/// `audio` is modified in place, the caller keeps ownership of it.
+	pub(crate) fn convert(
+		&mut self,
+		audio: &mut AudioBuffer<f32>,
+		target: RenderSpec,
+		volume: Volume,
+	) -> &[f32] {
+		let spec     = *audio.spec();
+		let channels = spec.channels.count();
+		let capacity = audio.capacity();
+
+		if self.sample_buffer.as_ref().map_or(true, |b| b.capacity() < capacity * channels) {
+			self.sample_buffer = Some(SampleBuffer::new(capacity as u64, spec));
+		}
+		let sample_buffer = self.sample_buffer.as_mut().unwrap();
+
+		// (Re-)create the resampler if the input changed.
+		let resampler = if spec.rate == target.sample_rate {
+			None
+		} else {
+			if !matches!(self.resampler, Some((_, c, r, ch)) if c == capacity && r == spec.rate && ch == channels) {
+				trace2!("Convert - new resampler, {} -> {}", spec.rate, target.sample_rate);
+				self.resampler = Some((
+					ResamplerStruct::new(
+						self.config,
+						NonZeroUsize::new(spec.rate as usize).unwrap(),
+						NonZeroUsize::new(target.sample_rate as usize).unwrap(),
+						NonZeroUsize::new(capacity).unwrap(),
+						NonZeroUsize::new(channels).unwrap(),
+					),
+					capacity,
+					spec.rate,
+					channels,
+				));
+			}
+			self.resampler.as_mut().map(|(r, _, _, _)| r)
+		};
+
+		// Partial buffers are not padded, the
+		// resampler holds onto them until it has
+		// enough frames, the same as in playback.
+		let samples = process(audio, volume, resampler, sample_buffer, &mut self.samples);
+
+		// Map the channels to the target.
+		let channels_out = usize::from(target.channels);
+		if channels == channels_out {
+			return samples;
+		}
+
+		self.remap.clear();
+		for frame in samples.chunks_exact(channels) {
+			self.remap.extend((0..channels_out).map(|ch| {
+				// Mono is copied to all channels, extra channels are
+				// silent, and channels the target doesn't have are dropped.
+				if channels == 1 {
+					frame[0]
+				} else {
+					frame.get(ch).copied().unwrap_or(0.0)
+				}
+			}));
+		}
+		&self.remap
+	}
+}
+
+impl<Extra: ExtraData> From<&AudioState<Extra>> for Render<Extra> {
+	/// Render the rest of an [`AudioState`].
+	///
+	/// This renders from [`AudioState::current`] (starting at its elapsed time)
+	/// until the end of the [`AudioState::queue`], with the state's [`Volume`].
+	///
+	/// If there is no [`AudioState::current`], the whole queue is rendered.
+	fn from(state: &AudioState<Extra>) -> Self {
+		let (index, start) = state
+			.current
+			.as_ref()
+			.map_or((0, 0.0), |c| (c.index, c.elapsed));
+
+		Self {
+			sources: state.queue.iter().skip(index).cloned().collect(),
+			volume: state.volume,
+			start,
+			..Self::new(vec![])
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn render_callback() {
+		let mut total = 0;
+		let mut specs = vec![];
+
+		let rendered = Render {
+			volume: Volume::new(0.5),
+			..Render::new(vec![crate::tests::source(0)])
+		}.to_callback(|samples, spec| {
+			total += samples.len();
+			specs.push(spec);
+		}).unwrap();
+
+		assert!(total > 0);
+		assert_eq!(rendered.sources, 1);
+		assert_eq!(rendered.frames, (total / usize::from(rendered.spec.channels)) as u64);
+		assert!(specs.iter().all(|s| *s == rendered.spec));
+	}
+
+	#[test]
+	fn render_wav() {
+		let path = std::env::temp_dir().join(format!("sansan_test_render_{}.wav", std::process::id()));
+
+		let rendered = Render::new(vec![crate::tests::source(0), crate::tests::source(1)])
+			.to_wav(&path)
+			.unwrap();
+		assert_eq!(rendered.sources, 2);
+
+		let bytes = std::fs::read(&path).unwrap();
+		let data_len = rendered.frames * u64::from(rendered.spec.channels) * 4;
+		// 32-bit float is written with the extensible header.
+		assert_eq!(bytes.len() as u64, 80 + data_len);
+		assert_eq!(&bytes[0..4], b"RIFF");
+		assert_eq!(&bytes[72..76], b"data");
+		assert_eq!(&bytes[76..80], &u32::try_from(data_len).unwrap().to_le_bytes());
+
+		std::fs::remove_file(&path).unwrap();
+	}
+
+	#[test]
+	fn render_empty() {
+		assert!(matches!(Render::<usize>::new(vec![]).to_callback(|_, _| {}), Err(RenderError::Empty)));
+		assert!(matches!(
+			Render::new(vec![crate::tests::source_broken(0)]).to_callback(|_, _| {}),
+			Err(RenderError::Source(_)),
+		));
+	}
+
+	#[test]
+	fn from_audio_state() {
+		let mut state = AudioState::<usize>::DEFAULT;
+		state.queue = [crate::tests::source(0), crate::tests::source(1), crate::tests::source(2)].into();
+		state.current = Some(crate::state::Current { source: crate::tests::source(1), index: 1, elapsed: 1.5 });
+
+		let render = Render::from(&state);
+		assert_eq!(render.sources, vec![crate::tests::source(1), crate::tests::source(2)]);
+		assert_eq!(render.start, 1.5);
+		assert_eq!(render.volume, state.volume);
+	}
+}
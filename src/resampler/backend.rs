@@ -0,0 +1,118 @@
+//! Audio resampling
+//!
+//! This file implements the abstract `Resampler`
+//! trait on top of all the resampler tiers, picking
+//! one at runtime according to `ResamplerConfig`.
+//!
+//! For documentation on `Resampler`, see `resampler.rs`.
+
+//----------------------------------------------------------------------------------------------- use
+use std::num::NonZeroUsize;
+use symphonia::core::audio::AudioBuffer;
+use crate::resampler::{
+	Resampler,
+	ResamplerConfig,
+	ResamplerQuality,
+	custom::ResamplerCustom,
+	linear::Linear,
+	rubato::{RubatoFft,RubatoSinc},
+};
+
+//----------------------------------------------------------------------------------------------- ResamplerBackend
+/// All the resampler implementations.
+pub(crate) enum ResamplerBackend {
+	/// `ResamplerQuality::Linear`.
+	Linear(Linear),
+	/// `ResamplerQuality::Fft`.
+	Fft(RubatoFft),
+	/// `ResamplerQuality::Sinc`.
+	Sinc(RubatoSinc),
+	/// `InitConfig::resampler_custom`.
+	Custom(ResamplerCustom),
+}
+
+//----------------------------------------------------------------------------------------------- Resampler Impl
+impl Resampler for ResamplerBackend {
+	#[cold]
+	#[inline(never)]
+	fn new(
+		config: ResamplerConfig,
+		sample_rate_input: NonZeroUsize,
+		sample_rate_target: NonZeroUsize,
+		duration: NonZeroUsize,
+		channel_count: NonZeroUsize,
+	) -> Self {
+		if let Some(f) = config.custom {
+			return Self::Custom(ResamplerCustom::new(f, sample_rate_input, sample_rate_target, duration, channel_count));
+		}
+
+		match config.quality {
+			ResamplerQuality::Linear => Self::Linear(Linear::new(sample_rate_input, sample_rate_target, duration, channel_count)),
+			ResamplerQuality::Fft => Self::Fft(RubatoFft::fft(sample_rate_input, sample_rate_target, duration, channel_count)),
+			ResamplerQuality::Sinc => Self::Sinc(RubatoSinc::sinc(sample_rate_input, sample_rate_target, duration, channel_count)),
+		}
+	}
+
+	#[inline]
+	fn resample(&mut self, audio: &AudioBuffer<f32>) -> &[f32] {
+		match self {
+			Self::Linear(r) => r.resample(audio),
+			Self::Fft(r) => r.resample(audio),
+			Self::Sinc(r) => r.resample(audio),
+			Self::Custom(r) => r.resample(audio),
+		}
+	}
+
+	#[inline]
+	fn reset(&mut self) {
+		match self {
+			Self::Linear(r) => r.reset(),
+			Self::Fft(r) => r.reset(),
+			Self::Sinc(r) => r.reset(),
+			Self::Custom(r) => r.reset(),
+		}
+	}
+}
+
+//----------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::resampler::{Resample,ResamplerSpec};
+
+	/// Create a `ResamplerBackend` with `config`.
+	fn backend(config: ResamplerConfig) -> ResamplerBackend {
+		ResamplerBackend::new(
+			config,
+			NonZeroUsize::new(44_100).unwrap(),
+			NonZeroUsize::new(48_000).unwrap(),
+			NonZeroUsize::new(128).unwrap(),
+			NonZeroUsize::new(2).unwrap(),
+		)
+	}
+
+	/// Does nothing.
+	struct Nothing;
+	impl Resample for Nothing {
+		fn resample(&mut self, _: &[&[f32]], _: &mut Vec<f32>) {}
+	}
+	fn nothing(_: ResamplerSpec) -> Box<dyn Resample> {
+		Box::new(Nothing)
+	}
+
+	#[test]
+	// Each `ResamplerQuality` creates its tier,
+	// unless a custom resampler is set.
+	fn config() {
+		let quality = |quality| ResamplerConfig { quality, custom: None };
+
+		assert!(matches!(backend(quality(ResamplerQuality::Linear)), ResamplerBackend::Linear(_)));
+		assert!(matches!(backend(quality(ResamplerQuality::Fft)), ResamplerBackend::Fft(_)));
+		assert!(matches!(backend(quality(ResamplerQuality::Sinc)), ResamplerBackend::Sinc(_)));
+
+		for quality in [ResamplerQuality::Linear, ResamplerQuality::Fft, ResamplerQuality::Sinc] {
+			let config = ResamplerConfig { quality, custom: Some(nothing) };
+			assert!(matches!(backend(config), ResamplerBackend::Custom(_)));
+		}
+	}
+}
@@ -0,0 +1,122 @@
+//! Audio resampling
+//!
+//! This file wraps a user's `Resample` so that
+//! it can be used as an internal `Resampler`.
+//!
+//! For documentation on `Resampler`, see `resampler.rs`.
+
+//----------------------------------------------------------------------------------------------- use
+use std::num::NonZeroUsize;
+use symphonia::core::audio::AudioBuffer;
+use crate::{
+	resampler::{Resample,ResamplerFn,ResamplerSpec},
+	macros::{debug2,trace2},
+};
+
+//----------------------------------------------------------------------------------------------- ResamplerCustom
+/// A user's [`Resample`].
+pub(crate) struct ResamplerCustom {
+	/// The user's resampler.
+	resample: Box<dyn Resample>,
+	/// The interleaved output.
+	interleaved: Vec<f32>,
+}
+
+//----------------------------------------------------------------------------------------------- ResamplerCustom Impl
+impl ResamplerCustom {
+	/// Create a new resampler with the user's `ResamplerFn`.
+	pub(crate) fn new(
+		f: ResamplerFn,
+		sample_rate_input: NonZeroUsize,
+		sample_rate_target: NonZeroUsize,
+		duration: NonZeroUsize,
+		channel_count: NonZeroUsize,
+	) -> Self {
+		#[allow(clippy::cast_possible_truncation)]
+		let spec = ResamplerSpec {
+			sample_rate_input: sample_rate_input.get() as u32,
+			sample_rate_target: sample_rate_target.get() as u32,
+			frames: duration.get(),
+			channels: channel_count.get(),
+		};
+
+		debug2!("Resampler(custom) - {spec:?}");
+
+		#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+		let capacity = {
+			let ratio = spec.sample_rate_target as f64 / spec.sample_rate_input as f64;
+			((spec.frames as f64 * ratio).ceil() as usize + 1) * spec.channels
+		};
+
+		Self {
+			resample: f(spec),
+			interleaved: Vec::with_capacity(capacity),
+		}
+	}
+
+	#[inline]
+	/// See `Resampler::resample()`.
+	pub(crate) fn resample(&mut self, audio: &AudioBuffer<f32>) -> &[f32] {
+		trace2!("Resampler(custom) - starting resample()");
+
+		self.interleaved.clear();
+		self.resample.resample(audio.planes().planes(), &mut self.interleaved);
+		&self.interleaved
+	}
+
+	#[inline]
+	/// See `Resampler::reset()`.
+	pub(crate) fn reset(&mut self) {
+		trace2!("Resampler(custom) - reset()");
+		self.resample.reset();
+	}
+}
+
+//----------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	/// Interleaves the input, and checks the spec it was created with.
+	struct Interleave;
+
+	impl Resample for Interleave {
+		fn resample(&mut self, input: &[&[f32]], output: &mut Vec<f32>) {
+			assert!(output.is_empty());
+			for i in 0..input[0].len() {
+				output.extend(input.iter().map(|channel| channel[i]));
+			}
+		}
+	}
+
+	fn copy(spec: ResamplerSpec) -> Box<dyn Resample> {
+		assert_eq!(spec, ResamplerSpec {
+			sample_rate_input: 44_100,
+			sample_rate_target: 48_000,
+			frames: 128,
+			channels: 2,
+		});
+		Box::new(Interleave)
+	}
+
+	#[test]
+	// The user's resampler is given the spec, planar
+	// input, and an empty output every time.
+	fn custom() {
+		let mut resampler = ResamplerCustom::new(
+			copy,
+			NonZeroUsize::new(44_100).unwrap(),
+			NonZeroUsize::new(48_000).unwrap(),
+			NonZeroUsize::new(128).unwrap(),
+			NonZeroUsize::new(2).unwrap(),
+		);
+
+		for _ in 0..2 {
+			let out = resampler.resample(&crate::tests::audio(100, 1.0, 2.0));
+			assert_eq!(out.len(), 100 * 2);
+			assert!(out.chunks_exact(2).all(|f| f == [1.0, 2.0]));
+		}
+	}
+}
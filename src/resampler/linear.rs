@@ -0,0 +1,195 @@
+//! Audio resampling
+//!
+//! This file implements the `Linear` resampler tier,
+//! a simple linear interpolation between frames.
+//!
+//! For documentation on `Resampler`, see `resampler.rs`.
+
+//----------------------------------------------------------------------------------------------- use
+use std::num::NonZeroUsize;
+use symphonia::core::audio::{AudioBuffer,Signal};
+use crate::macros::{debug2,trace2};
+
+//----------------------------------------------------------------------------------------------- Linear
+/// Linear interpolation resampler.
+///
+/// The last frame of each chunk is kept around,
+/// so interpolation is continuous across chunks.
+pub(crate) struct Linear {
+	/// `sample_rate_input / sample_rate_target`, i.e. how
+	/// many input frames are stepped over per output frame.
+	step: f64,
+
+	/// Position of the next output frame, relative to the
+	/// start of the next input chunk.
+	///
+	/// `-1.0..0.0` means it is in-between `last` and the next chunk.
+	position: f64,
+
+	/// The last frame of the previous chunk.
+	last: Vec<f32>,
+
+	/// The interleaved output.
+	interleaved: Vec<f32>,
+
+	/// The amount of channels this Resampler is for.
+	channel_count: usize,
+}
+
+//----------------------------------------------------------------------------------------------- Linear Impl
+impl Linear {
+	/// Create a new linear resampler.
+	pub(crate) fn new(
+		sample_rate_input: NonZeroUsize,
+		sample_rate_target: NonZeroUsize,
+		duration: NonZeroUsize,
+		channel_count: NonZeroUsize,
+	) -> Self {
+		let sample_rate_input  = sample_rate_input.get();
+		let sample_rate_target = sample_rate_target.get();
+		let duration           = duration.get();
+		let channel_count      = channel_count.get();
+
+		debug2!("Resampler(linear) - sample_rate_input: {sample_rate_input}, sample_rate_target: {sample_rate_target}, duration: {duration}, channel_count: {channel_count}");
+
+		#[allow(clippy::cast_precision_loss)]
+		let step = sample_rate_input as f64 / sample_rate_target as f64;
+
+		// Enough for the largest chunk plus rounding.
+		#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+		let capacity = ((duration as f64 / step).ceil() as usize + 1) * channel_count;
+
+		Self {
+			step,
+			position: 0.0,
+			last: vec![0.0; channel_count],
+			interleaved: Vec::with_capacity(capacity),
+			channel_count,
+		}
+	}
+
+	#[inline]
+	/// See `Resampler::resample()`.
+	pub(crate) fn resample(&mut self, audio: &AudioBuffer<f32>) -> &[f32] {
+		trace2!("Resampler(linear) - starting resample()");
+
+		self.interleaved.clear();
+
+		let frames = audio.frames();
+		if frames == 0 {
+			return &self.interleaved;
+		}
+
+		// Frame `i` of this chunk, where `-1` is `self.last`.
+		let sample = |this: &Self, ch: usize, i: isize| -> f32 {
+			if i < 0 {
+				this.last[ch]
+			} else {
+				#[allow(clippy::cast_sign_loss)]
+				audio.chan(ch)[i as usize]
+			}
+		};
+
+		#[allow(clippy::cast_precision_loss)]
+		let end = (frames - 1) as f64;
+
+		// Interpolate between the 2 frames surrounding `position`,
+		// up until the last frame of this chunk, which is kept
+		// for the next call since the frame after it is unknown.
+		while self.position < end {
+			#[allow(clippy::cast_possible_truncation)]
+			let floor = self.position.floor() as isize;
+			#[allow(clippy::cast_possible_truncation)]
+			let fraction = (self.position - self.position.floor()) as f32;
+
+			for ch in 0..self.channel_count {
+				let a = sample(self, ch, floor);
+				let b = sample(self, ch, floor + 1);
+				self.interleaved.push((b - a).mul_add(fraction, a));
+			}
+
+			self.position += self.step;
+		}
+
+		// Make `position` relative to the next chunk.
+		self.position -= end + 1.0;
+		for (ch, last) in self.last.iter_mut().enumerate() {
+			*last = audio.chan(ch)[frames - 1];
+		}
+
+		&self.interleaved
+	}
+
+	#[inline]
+	/// See `Resampler::reset()`.
+	pub(crate) fn reset(&mut self) {
+		trace2!("Resampler(linear) - reset()");
+
+		self.position = 0.0;
+		self.last.fill(0.0);
+	}
+}
+
+//----------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	/// Create a `Linear` for 2 channels, 128 frames.
+	fn linear(input: usize, target: usize) -> Linear {
+		Linear::new(
+			NonZeroUsize::new(input).unwrap(),
+			NonZeroUsize::new(target).unwrap(),
+			NonZeroUsize::new(128).unwrap(),
+			NonZeroUsize::new(2).unwrap(),
+		)
+	}
+
+	#[test]
+	// Upsampling 2x doubles the frames (across chunks).
+	fn upsample() {
+		let mut resampler = linear(44_100, 88_200);
+		let audio = crate::tests::audio(128, 0.5, -0.5);
+
+		let mut frames = 0;
+		for _ in 0..8 {
+			let out = resampler.resample(&audio);
+			assert!(out.chunks_exact(2).skip(2).all(|f| f == [0.5, -0.5]));
+			frames += out.len() / 2;
+		}
+
+		// The last frame is held back for interpolation.
+		assert!((8 * 128 * 2 - 2..=8 * 128 * 2).contains(&frames));
+	}
+
+	#[test]
+	// Downsampling 2x halves the frames, and interpolates in-between.
+	fn downsample() {
+		let mut resampler = linear(88_200, 44_100);
+
+		let mut audio = crate::tests::audio(128, 0.0, 0.0);
+		for (i, s) in audio.chan_mut(0).iter_mut().enumerate() {
+			#[allow(clippy::cast_precision_loss)]
+			let i = i as f32;
+			*s = i;
+		}
+
+		let out = resampler.resample(&audio);
+		assert_eq!(out.len(), 64 * 2);
+
+		// Every other input frame of the ramp.
+		let left: Vec<f32> = out.chunks_exact(2).map(|f| f[0]).collect();
+		#[allow(clippy::cast_precision_loss)]
+		let expected: Vec<f32> = (0..64).map(|i| (i * 2) as f32).collect();
+		assert_eq!(left, expected);
+	}
+
+	#[test]
+	// Empty chunks produce nothing.
+	fn empty() {
+		let mut resampler = linear(44_100, 48_000);
+		assert!(resampler.resample(&crate::tests::audio(0, 0.0, 0.0)).is_empty());
+	}
+}
@@ -1,18 +1,26 @@
-//! Resampler backends.
+//! Resampling.
+//!
+//! Audio is resampled when the output device does not support
+//! the audio's native sample rate, the algorithm used can be
+//! picked with [`InitConfig::resampler`], or replaced entirely
+//! with [`InitConfig::resampler_custom`].
+
+#[allow(unused_imports)] // docs
+use crate::config::InitConfig;
 
 mod resampler;
-pub(crate) use resampler::Resampler;
+pub(crate) use resampler::{Resampler,ResamplerConfig};
+
+mod resample;
+pub use resample::{Resample,ResamplerFn,ResamplerSpec};
+
+mod quality;
+pub use quality::ResamplerQuality;
 
-cfg_if::cfg_if! {
-	if #[cfg(any(test, feature = "dummy"))] {
-		mod dummy;
-		pub(crate) use dummy::DummyResampler as ResamplerStruct;
-		/// The resampler backend used.
-		pub(crate) const RESAMPLER_BACKEND: &str = "dummy";
-	} else {
-		mod rubato;
-		pub(crate) use self::rubato::Rubato as ResamplerStruct;
-		/// The resampler backend used.
-		pub(crate) const RESAMPLER_BACKEND: &str = "rubato";
-	}
-}
+// All tiers are always compiled, the `dummy`
+// feature only replaces the audio output device.
+mod rubato;
+mod linear;
+mod custom;
+mod backend;
+pub(crate) use backend::ResamplerBackend as ResamplerStruct;
@@ -0,0 +1,49 @@
+//! Resampler quality tiers.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::config::InitConfig;
+
+//---------------------------------------------------------------------------------------------------- ResamplerQuality
+/// Which built-in resampler to use.
+///
+/// Resampling only happens when the audio output device
+/// does not support the audio's native sample rate.
+///
+/// Set with [`InitConfig::resampler`], this is ignored
+/// if [`InitConfig::resampler_custom`] is set.
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ResamplerQuality {
+	/// Linear interpolation.
+	///
+	/// Very cheap, but introduces aliasing and
+	/// attenuates high frequencies, audibly so
+	/// when converting between distant sample rates.
+	Linear,
+
+	#[default]
+	/// FFT-based resampling (`rubato`'s `FftFixedIn`).
+	///
+	/// Good quality for fixed ratios at a low CPU cost.
+	Fft,
+
+	/// Band-limited sinc interpolation (`rubato`'s `SincFixedIn`).
+	///
+	/// The highest quality, at the highest CPU cost.
+	Sinc,
+}
+
+impl ResamplerQuality {
+	/// Returns [`Self::Fft`].
+	pub const DEFAULT: Self = Self::Fft;
+}
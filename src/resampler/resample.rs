@@ -0,0 +1,109 @@
+//! Public resampler trait.
+//!
+//! This file defines `Resample`, the public (simplified) version
+//! of the internal `Resampler` trait, which users can implement
+//! to use their own resampling algorithm.
+
+//---------------------------------------------------------------------------------------------------- use
+#[allow(unused_imports)] // docs
+use crate::{
+	config::InitConfig,
+	resampler::ResamplerQuality,
+};
+
+//---------------------------------------------------------------------------------------------------- ResamplerSpec
+/// The audio a [`Resample`] is created for.
+///
+/// A new resampler is created whenever any of these change,
+/// e.g. when a track with a different sample rate starts.
+#[derive(Copy,Clone,Debug,PartialEq,Eq,PartialOrd,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct ResamplerSpec {
+	/// The audio's original sample rate.
+	pub sample_rate_input: u32,
+	/// The sample rate to resample to.
+	pub sample_rate_target: u32,
+	/// How many frames are in each input chunk.
+	pub frames: usize,
+	/// How many audio channels there are.
+	pub channels: usize,
+}
+
+/// A function that creates a custom [`Resample`].
+///
+/// See [`InitConfig::resampler_custom`].
+pub type ResamplerFn = fn(ResamplerSpec) -> Box<dyn Resample>;
+
+//---------------------------------------------------------------------------------------------------- Resample
+/// A resampler.
+///
+/// A custom `Resample` can be created by the function
+/// given to [`InitConfig::resampler_custom`], which is then
+/// used instead of the built-in [`ResamplerQuality`] tiers.
+///
+/// `resample()` is called from the real-time audio thread, so
+/// it should not allocate, block, or otherwise take long.
+/// Any buffers needed should be allocated in the [`ResamplerFn`].
+///
+/// ```rust
+/// # use sansan::resampler::*;
+/// /// A "resampler" that drops or repeats frames.
+/// struct Nearest {
+///     spec: ResamplerSpec,
+/// }
+///
+/// impl Resample for Nearest {
+///     fn resample(&mut self, input: &[&[f32]], output: &mut Vec<f32>) {
+///         let frames = input[0].len();
+///         let ratio  = self.spec.sample_rate_input as f64 / self.spec.sample_rate_target as f64;
+///         let len    = (frames as f64 / ratio) as usize;
+///
+///         for i in 0..len {
+///             let frame = ((i as f64 * ratio) as usize).min(frames - 1);
+///             output.extend(input.iter().map(|channel| channel[frame]));
+///         }
+///     }
+/// }
+///
+/// fn nearest(spec: ResamplerSpec) -> Box<dyn Resample> {
+///     Box::new(Nearest { spec })
+/// }
+///
+/// let _: ResamplerFn = nearest;
+/// ```
+pub trait Resample: Send {
+	/// Resample a chunk of planar/non-interleaved audio.
+	///
+	/// `input` has one slice per channel, all the same length,
+	/// usually [`ResamplerSpec::frames`] samples long, although
+	/// the last chunk of a track may be shorter.
+	///
+	/// The resampled samples must be pushed onto `output` in
+	/// an interleaved format, `output` is always empty when given.
+	///
+	/// | L   | R   | Interleaved |
+	/// |-----|-----|-------------|
+	/// | [0] | [0] | L[0]        |
+	/// | [1] | [1] | R[0]        |
+	/// | [2] | [2] | L[1]        |
+	/// | [3] | [3] | R[1]        |
+	/// | ... | ... | ...         |
+	fn resample(&mut self, input: &[&[f32]], output: &mut Vec<f32>);
+
+	/// Throw away any buffered input and internal state.
+	///
+	/// This is called when audio is discarded (e.g. seeking,
+	/// skipping, or changing devices), so a resampler that holds
+	/// onto frames between calls must drop them here, otherwise
+	/// they end up in front of the next audio given.
+	///
+	/// By default, this does nothing.
+	fn reset(&mut self) {}
+}
+
+impl std::fmt::Debug for dyn Resample {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Resample").finish_non_exhaustive()
+	}
+}
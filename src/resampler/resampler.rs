@@ -9,10 +9,22 @@
 //! (`Resampler::resample()`) should be real-time safe although
 //! creating a new object (`Resampler::new()`) is not.
 //!
-//! The currently used backend for resampling (the only implementor) is `rubato`.
+//! Which implementation is used is picked at runtime
+//! with `ResamplerConfig`, see `backend.rs`.
 
 //----------------------------------------------------------------------------------------------- use
 use std::num::NonZeroUsize;
+use crate::resampler::{ResamplerQuality,ResamplerFn};
+
+//----------------------------------------------------------------------------------------------- ResamplerConfig
+/// Which resampler to create, from `InitConfig`.
+#[derive(Copy,Clone,Debug)]
+pub(crate) struct ResamplerConfig {
+	/// The built-in tier to use.
+	pub(crate) quality: ResamplerQuality,
+	/// A custom resampler, used instead of `quality` if `Some`.
+	pub(crate) custom: Option<ResamplerFn>,
+}
 
 //----------------------------------------------------------------------------------------------- Resampler
 /// TODO
@@ -22,6 +34,7 @@ pub(crate) trait Resampler: Sized {
 	/// This function is heavy, allocates many vectors and space.
 	/// Shouldn't be called that often.
 	fn new(
+		config: ResamplerConfig,          // Which resampler should be created?
 		sample_rate_input: NonZeroUsize,  // What is the original audio's sample rate?
 		sample_rate_target: NonZeroUsize, // What is the desired target sample rate?
 		duration: NonZeroUsize,           // What is the duration/chunk size of the samples?
@@ -43,4 +56,11 @@ pub(crate) trait Resampler: Sized {
 	/// | [3] | [3] | R[1]        |
 	/// | ... | ... | ...         |
 	fn resample(&mut self, audio: &symphonia::core::audio::AudioBuffer<f32>) -> &[f32];
+
+	/// Throw away any buffered input (e.g. a partial chunk)
+	/// and internal state, as if this were freshly created.
+	///
+	/// Called when audio is discarded, so that leftover
+	/// frames don't leak into the next audio written.
+	fn reset(&mut self);
 }
\ No newline at end of file
@@ -1,19 +1,21 @@
 //! Audio resampling
 //!
-//! This file implements the abstract `Resampler`
-//! trait using `rubato` as a backend.
+//! This file implements the `Fft` and `Sinc` resampler
+//! tiers using `rubato` as a backend.
 //!
 //! For documentation on `Resampler`, see `resampler.rs`.
 
 //----------------------------------------------------------------------------------------------- use
-use rubato::FftFixedIn;
+use rubato::{
+	FftFixedIn,SincFixedIn,SincInterpolationParameters,
+	SincInterpolationType,WindowFunction,
+};
 use std::num::NonZeroUsize;
-use crate::resampler::Resampler;
 use crate::macros::{debug2,trace2};
 
 //----------------------------------------------------------------------------------------------- Rubato
 /// # Usage
-/// 1. Create with `Rubato::new()`, providing some audio info
+/// 1. Create with `Rubato::fft()` or `Rubato::sinc()`, providing some audio info
 /// 2. Call `Rubato::resample()` with a freshly decoded
 ///    `AudioBuffer` from `symphonia` and receive a multi-channel
 ///    supporting, interleaved buffer of `[f32]`.
@@ -23,9 +25,9 @@ use crate::macros::{debug2,trace2};
 /// It may be worth pre-creating `Rubato` objects with
 /// common audio sample rates, i.e - `44_100`, `48_000`, `96_000`, etc,
 /// as re-creating one per-track basis is expensive.
-pub(crate) struct Rubato {
+pub(crate) struct Rubato<T: rubato::Resampler<f32>> {
 	/// The actual resampling object.
-	resampler: FftFixedIn<f32>,
+	resampler: T,
 
 	/// Temporary buffer(s) holding all the channels,
 	/// which hold copied input data. It is quite
@@ -55,34 +57,95 @@ pub(crate) struct Rubato {
 	channel_count: usize,
 }
 
-//----------------------------------------------------------------------------------------------- Resampler Impl
-impl Resampler for Rubato {
-	#[inline]
-	fn new(
+/// `ResamplerQuality::Fft`.
+pub(crate) type RubatoFft = Rubato<FftFixedIn<f32>>;
+/// `ResamplerQuality::Sinc`.
+pub(crate) type RubatoSinc = Rubato<SincFixedIn<f32>>;
+
+//----------------------------------------------------------------------------------------------- Rubato Impl
+impl RubatoFft {
+	/// Create a new FFT resampler.
+	///
+	/// This function is heavy, allocates many vectors and space.
+	pub(crate) fn fft(
 		sample_rate_input: NonZeroUsize,
 		sample_rate_target: NonZeroUsize,
 		duration: NonZeroUsize,
 		channel_count: NonZeroUsize,
 	) -> Self {
-		let sample_rate_input  = sample_rate_input.get();
-		let sample_rate_target = sample_rate_target.get();
-		let duration           = duration.get();
-		let channel_count      = channel_count.get();
-
-		debug2!("Resampler(rubato) - sample_rate_input: {sample_rate_input}, sample_rate_target: {sample_rate_target}, duration: {duration}, channel_count: {channel_count}");
+		debug2!("Resampler(rubato) - fft");
 
 		// Create resampler.
 		//
 		// INVARIANT: this panics if input/output sample rate is 0.
-		// This function has a warning against this, so unwrap.
+		// The input is `NonZero`, so unwrap.
 		let resampler = FftFixedIn::<f32>::new(
-			sample_rate_input,
-			sample_rate_target,
-			duration,
+			sample_rate_input.get(),
+			sample_rate_target.get(),
+			duration.get(),
 			2, // FIXME: this is inherited from symphonia, not sure what a good value is
-			channel_count,
+			channel_count.get(),
+		).unwrap();
+
+		Self::from_resampler(resampler, sample_rate_input, sample_rate_target, duration, channel_count)
+	}
+}
+
+impl RubatoSinc {
+	/// Create a new sinc resampler.
+	///
+	/// This function is heavy, allocates many vectors and space.
+	pub(crate) fn sinc(
+		sample_rate_input: NonZeroUsize,
+		sample_rate_target: NonZeroUsize,
+		duration: NonZeroUsize,
+		channel_count: NonZeroUsize,
+	) -> Self {
+		debug2!("Resampler(rubato) - sinc");
+
+		// These are the "best" parameters from `rubato`'s
+		// documentation, trading CPU time for quality.
+		let parameters = SincInterpolationParameters {
+			sinc_len: 256,
+			f_cutoff: 0.95,
+			interpolation: SincInterpolationType::Cubic,
+			oversampling_factor: 256,
+			window: WindowFunction::BlackmanHarris2,
+		};
+
+		#[allow(clippy::cast_precision_loss)]
+		let ratio = sample_rate_target.get() as f64 / sample_rate_input.get() as f64;
+
+		// INVARIANT: this only errors on invalid ratios,
+		// which can't happen with `NonZero` sample rates.
+		let resampler = SincFixedIn::<f32>::new(
+			ratio,
+			1.0, // The ratio is fixed.
+			parameters,
+			duration.get(),
+			channel_count.get(),
 		).unwrap();
 
+		Self::from_resampler(resampler, sample_rate_input, sample_rate_target, duration, channel_count)
+	}
+}
+
+impl<T: rubato::Resampler<f32>> Rubato<T> {
+	/// Allocate the buffers around an already created `rubato` resampler.
+	fn from_resampler(
+		resampler: T,
+		sample_rate_input: NonZeroUsize,
+		sample_rate_target: NonZeroUsize,
+		duration: NonZeroUsize,
+		channel_count: NonZeroUsize,
+	) -> Self {
+		let sample_rate_input  = sample_rate_input.get();
+		let sample_rate_target = sample_rate_target.get();
+		let duration           = duration.get();
+		let channel_count      = channel_count.get();
+
+		debug2!("Resampler(rubato) - sample_rate_input: {sample_rate_input}, sample_rate_target: {sample_rate_target}, duration: {duration}, channel_count: {channel_count}");
+
 		// Create input/output buffers.
 		let output = rubato::Resampler::output_buffer_allocate(&resampler, true);
 		let input = rubato::Resampler::input_buffer_allocate(&resampler, true);
@@ -101,7 +164,8 @@ impl Resampler for Rubato {
 	}
 
 	#[inline]
-	fn resample(&mut self, audio: &symphonia::core::audio::AudioBuffer<f32>) -> &[f32] {
+	/// See `Resampler::resample()`.
+	pub(crate) fn resample(&mut self, audio: &symphonia::core::audio::AudioBuffer<f32>) -> &[f32] {
 		trace2!("Resampler(rubato) - starting resample()");
 
 		// Collect `f32`'s from all channels into a single slice.
@@ -111,6 +175,13 @@ impl Resampler for Rubato {
 			.map(|(channel, input)| (symphonia::core::audio::Signal::chan(audio, channel), input))
 			.for_each(|(channel, input)| input.extend_from_slice(channel));
 
+		// Not enough frames buffered for a full chunk yet
+		// (e.g. a partial packet), wait for the next call.
+		if self.input[0].len() < self.duration {
+			trace2!("Resampler(rubato) - {} frames buffered, waiting for {}", self.input[0].len(), self.duration);
+			return &[];
+		}
+
 		// Resample.
 		//
 		// INVARIANT: we unwrap here because it is
@@ -162,6 +233,15 @@ impl Resampler for Rubato {
 
 		&self.interleaved
 	}
+
+	#[inline]
+	/// See `Resampler::reset()`.
+	pub(crate) fn reset(&mut self) {
+		trace2!("Resampler(rubato) - reset()");
+
+		self.input.iter_mut().for_each(Vec::clear);
+		rubato::Resampler::reset(&mut self.resampler);
+	}
 }
 
 //----------------------------------------------------------------------------------------------- TESTS
@@ -249,7 +329,7 @@ mod tests {
 		let audio: Vec<AudioBuffer<f32>> = create_test_audio();
 
 		// Create resampler.
-		let mut resampler = super::Rubato::new(
+		let mut resampler = super::RubatoFft::fft(
 			NonZeroUsize::new(SAMPLE_RATE).unwrap(),     // Original sample rate
 			NonZeroUsize::new(SAMPLE_RATE * 2).unwrap(), // Target is 2x the sample rate (88,200)
 			NonZeroUsize::new(128).unwrap(),             // 128 duration
@@ -273,4 +353,74 @@ mod tests {
 		assert_eq!(resampler.resample(&audio[3]), &[-0.10750049, -0.105473734, -0.11624278, -0.114145786, -0.124432914, -0.12229608, -0.13207355, -0.12990528, -0.13918436, -0.13697144, -0.1457735, -0.1434937, -0.15182027, -0.14945777, -0.1572656, -0.15481958, -0.16200955, -0.15949118, -0.16592044, -0.16334277, -0.16886757, -0.1662377, -0.17077398, -0.16809273, -0.17166506, -0.16893357, -0.17167863, -0.16890629, -0.17102636, -0.16823424, -0.16992958, -0.16714746, -0.16856954, -0.16582821, -0.16707024, -0.16439256, -0.1654994, -0.1628936, -0.16385828, -0.1613171, -0.16205709, -0.15956432, -0.15990327, -0.15744567, -0.15713346, -0.15471318, -0.15348624, -0.15112656, -0.14877665, -0.14651696, -0.14292984, -0.14081214, -0.13596795, -0.13402167, -0.12797949, -0.1262128, -0.11910361, -0.11750528, -0.109531604, -0.10808133, -0.099502146, -0.09818326, -0.08927028, -0.088077955, -0.0790581, -0.07800083, -0.069014326, -0.068108685, -0.05920119, -0.05846494, -0.049604677, -0.04905089, -0.04015135, -0.039786242, -0.030724794, -0.030548798, -0.021188002, -0.02119842, -0.011417262, -0.011611458, -0.0013401657, -0.0017176401, 0.0090398975, 0.00847728, 0.019641649, 0.01889205, 0.030321283, 0.029386144, 0.04089441, 0.0397798, 0.051161103, 0.04987465, 0.060938187, 0.059482437, 0.0700952, 0.06846292, 0.07857931, 0.07675633, 0.08641333, 0.08438962, 0.093669176, 0.091451466, 0.100436255, 0.09805232, 0.10680449, 0.104294, 0.11285895, 0.110257104, 0.11867058, 0.1159955, 0.12427275, 0.121524975, 0.12963791, 0.12681055, 0.13467838, 0.13176763, 0.13927716, 0.13628669, 0.14332964, 0.14026825, 0.1467671, 0.1436465, 0.14955345, 0.14638935, 0.15167156, 0.14848562, 0.15311864, 0.14993504, 0.15391149, 0.15074834, 0.15408522, 0.15094729, 0.15367451, 0.15055549, 0.15268819, 0.149582, 0.15110005, 0.14801216, 0.14886667, 0.14581436, 0.14595836, 0.14296003, 0.14237987, 0.13944308, 0.13817087, 0.13529095, 0.13339543, 0.13056584, 0.12813479, 0.12536001, 0.12248549, 0.11978466, 0.116553925, 0.1139524, 0.11044005, 0.107957415, 0.10421769, 0.101862155, 0.09792274, 0.09569441, 0.09155257, 0.08944975, 0.085068814, 0.083089605, 0.07839648, 0.07653567, 0.07142674, 0.069673695, 0.06403614, 0.0623803, 0.056126546, 0.05456764, 0.047666926, 0.046221934, 0.038713187, 0.03741169, 0.029394627, 0.028263975, 0.01987942, 0.01892911, 0.010339145, 0.0095553715, 0.0009237975, 0.00027858093, -0.008255471, -0.008786334, -0.017138135, -0.01756018, -0.025715964, -0.026012056, -0.03401781, -0.03415706, -0.04208447, -0.042036608, -0.04995226, -0.04969965, -0.057658352, -0.057199083, -0.06525582, -0.06459927, -0.07281273, -0.07197141, -0.08038001, -0.079363324, -0.087946646, -0.086758, -0.09541735, -0.09405421, -0.10263349, -0.101088986, -0.10942213, -0.10768816, -0.115637824, -0.11371005, -0.121176, -0.11905904, -0.12596686, -0.12367682, -0.1299739, -0.12753521, -0.1332051, -0.13064253, -0.13572097, -0.13305168, -0.13761882, -0.13484952, -0.13899377, -0.13612449, -0.13990025, -0.13693145, -0.14034012, -0.13727778, -0.14028093, -0.13713713, -0.13968723, -0.13647735, -0.13854374, -0.13528349, -0.13686322, -0.13356717, -0.13468367, -0.13136518, -0.13206063, -0.12873134, -0.12905458, -0.12572384, -0.12571365, -0.12238853, -0.12205702, -0.118743934, -0.118067324, -0.11477368, -0.113692656, -0.11042847, -0.10885388, -0.1056315]);
 		assert_eq!(resampler.resample(&audio[4]), &[0.084098384, 0.08388551, 0.08732653, 0.08699783, 0.090127565, 0.08972047, 0.09247813, 0.092003815, 0.094371915, 0.0938143, 0.095808074, 0.095137484, 0.096791334, 0.09598204, 0.09734262, 0.09638316, 0.09750414, 0.0963976, 0.09732719, 0.096086, 0.09684848, 0.09548924, 0.09607392, 0.09461401, 0.09498301, 0.09343726, 0.09354805, 0.091924906, 0.09175117, 0.090050526, 0.08958718, 0.087803125, 0.08705635, 0.08518392, 0.08415946, 0.08220048, 0.080901414, 0.078864686, 0.07729836, 0.07519458, 0.07337993, 0.071216464, 0.06918379, 0.06696502, 0.06474817, 0.062481087, 0.060107976, 0.057807595, 0.055295154, 0.052983724, 0.050339535, 0.04803859, 0.04526746, 0.042988613, 0.040099528, 0.037841063, 0.034849774, 0.032602105, 0.02952704, 0.027282974, 0.024137832, 0.021899933, 0.018690007, 0.016470008, 0.013196485, 0.011007609, 0.007676197, 0.0055248924, 0.002148535, 3.2763928e-5, -0.003376754, -0.0054611303, -0.008906521, -0.010955695, -0.014458505, -0.016455384, -0.02004413, -0.021962598, -0.025651917, -0.027468856, -0.03124551, -0.03295246, -0.036776796, -0.038383015, -0.04220094, -0.04372522, -0.047479365, -0.04893577, -0.05257205, -0.05395849, -0.057432596, -0.058728654, -0.062015645, -0.06319046, -0.06628935, -0.067314275, -0.07023711, -0.07109679, -0.07384487, -0.07454148, -0.07708918, -0.07763799, -0.07994321, -0.08036198, -0.08239831, -0.08269578, -0.084476545, -0.08464699, -0.08621503, -0.08624296, -0.08763175, -0.08750445, -0.08870648, -0.088425815, -0.089399025, -0.08898287, -0.08968936, -0.08915975, -0.089599624, -0.0889655, -0.0891747, -0.08842192, -0.08843996, -0.08753757, -0.08737839, -0.08629803, -0.0859489, -0.08468443, -0.084124364, -0.082698725, -0.081910826, -0.08036537, -0.07933182, -0.07770556, -0.07640188, -0.074713305, -0.07312189, -0.07136353, -0.06950065, -0.06764832, -0.065573834, -0.063604034, -0.0613933, -0.059299164, -0.05699472, -0.054792292, -0.05237865, -0.05010182, -0.047528632, -0.045216538, -0.04244888, -0.040133942, -0.037182685, -0.034885198, -0.03179172, -0.029522074, -0.026318619, -0.024082892, -0.020772286, -0.018576205, -0.015150178, -0.012999558, -0.009471575, -0.0073721586, -0.003785262, -0.0017442964, 0.0018568132, 0.0038275076, 0.0074317884, 0.009315141, 0.01295058, 0.014728451, 0.018430723, 0.020090658, 0.023864217, 0.025403254, 0.02920809, 0.030630734, 0.03440084, 0.03571309, 0.039383784, 0.040588766, 0.044108987, 0.045206863, 0.048534878, 0.04952444, 0.052623678, 0.05350172, 0.05634849, 0.057107005, 0.05970431, 0.060330108, 0.062711, 0.06319229, 0.06540619, 0.06574245, 0.06783395, 0.06804015, 0.070035815, 0.07013421, 0.07204391, 0.072047144, 0.07387377, 0.073772565, 0.07551819, 0.0752852, 0.07694776, 0.07655643, 0.07811938, 0.07756378, 0.07898757, 0.07828744, 0.07951242, 0.07869908, 0.07966371, 0.07875847, 0.079425886, 0.078427866, 0.078804076, 0.077696495, 0.07782371, 0.07659259, 0.07651873, 0.07516765, 0.07491355, 0.07346305, 0.07301547, 0.071488015, 0.070825286, 0.06922862, 0.06835618, 0.066678934, 0.06563963, 0.063862555, 0.06271029, 0.060823865, 0.059584454, 0.057598703, 0.056255326, 0.054194123, 0.052711725, 0.050596684, 0.04896129, 0.046798453, 0.045034472, 0.04281418, 0.040966384, 0.038675416, 0.036777046, 0.034412146, 0.032470588, 0.030042864, 0.028051617, 0.025579758, 0.023537286, 0.021037716]);
 	}
-}
\ No newline at end of file
+
+	/// Resample `chunks` chunks of constant 128 frame stereo
+	/// audio from 44.1kHz to 88.2kHz with `resampler`.
+	fn resample_constant<T: rubato::Resampler<f32>>(resampler: &mut Rubato<T>, chunks: usize) -> Vec<f32> {
+		let audio = crate::tests::audio(128, 0.5, -0.5);
+		let mut out = vec![];
+		for _ in 0..chunks {
+			out.extend_from_slice(resampler.resample(&audio));
+		}
+		out
+	}
+
+	/// `44_100 -> 88_200`, 128 frames, 2 channels.
+	fn args() -> (NonZeroUsize, NonZeroUsize, NonZeroUsize, NonZeroUsize) {
+		(
+			NonZeroUsize::new(SAMPLE_RATE).unwrap(),
+			NonZeroUsize::new(SAMPLE_RATE * 2).unwrap(),
+			NonZeroUsize::new(128).unwrap(),
+			NonZeroUsize::new(2).unwrap(),
+		)
+	}
+
+	#[test]
+	// The FFT tier doubles the frames and keeps the
+	// (constant) channels apart, once it has settled.
+	fn fft() {
+		let (input, target, duration, channels) = args();
+		let mut resampler = RubatoFft::fft(input, target, duration, channels);
+
+		let out = resample_constant(&mut resampler, 32);
+		assert_eq!(out.len(), 32 * 128 * 2 * 2);
+
+		let settled = &out[out.len() / 2..];
+		assert!(settled.chunks_exact(2).all(|f| (f[0] - 0.5).abs() < 0.01 && (f[1] + 0.5).abs() < 0.01));
+	}
+
+	#[test]
+	// Same as `fft()` but for the sinc tier.
+	fn sinc() {
+		let (input, target, duration, channels) = args();
+		let mut resampler = RubatoSinc::sinc(input, target, duration, channels);
+
+		let out = resample_constant(&mut resampler, 32);
+		assert_eq!(out.len(), 32 * 128 * 2 * 2);
+
+		let settled = &out[out.len() / 2..];
+		assert!(settled.chunks_exact(2).all(|f| (f[0] - 0.5).abs() < 0.01 && (f[1] + 0.5).abs() < 0.01));
+	}
+
+	#[test]
+	// Partial chunks are held onto until there is a full chunk.
+	fn partial() {
+		let (input, target, duration, channels) = args();
+		let mut resampler = RubatoFft::fft(input, target, duration, channels);
+
+		assert!(resampler.resample(&crate::tests::audio(100, 0.0, 0.0)).is_empty());
+		assert_eq!(resampler.resample(&crate::tests::audio(28, 0.0, 0.0)).len(), 128 * 2 * 2);
+	}
+
+	#[test]
+	// Partial chunks are thrown away on `reset()`.
+	fn reset() {
+		let (input, target, duration, channels) = args();
+		let mut resampler = RubatoFft::fft(input, target, duration, channels);
+
+		assert!(resampler.resample(&crate::tests::audio(100, 0.0, 0.0)).is_empty());
+		resampler.reset();
+		assert!(resampler.resample(&crate::tests::audio(28, 0.0, 0.0)).is_empty());
+		assert_eq!(resampler.resample(&crate::tests::audio(100, 0.0, 0.0)).len(), 128 * 2 * 2);
+	}
+}
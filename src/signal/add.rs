@@ -13,7 +13,7 @@ use crate::{
 
 //---------------------------------------------------------------------------------------------------- Add
 /// TODO
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[derive(Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
 pub struct Add<Extra: ExtraData> {
@@ -71,7 +71,7 @@ impl<Extra: ExtraData> From<Source<Extra>> for Add<Extra> {
 
 //---------------------------------------------------------------------------------------------------- AddMany
 /// TODO
-// #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[derive(Clone,Debug,PartialEq,PartialOrd)]
 pub struct AddMany<Extra: ExtraData> {
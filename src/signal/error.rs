@@ -0,0 +1,66 @@
+//! Errors from signals.
+
+//---------------------------------------------------------------------------------------------------- use
+use crate::{
+	error::OutputError,
+	signal::{
+		BackError,
+		RemoveError,
+		RestoreError,
+		SeekError,
+		SetIndexError,
+		SkipError,
+	},
+};
+use strum::{
+	AsRefStr,EnumCount,EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::Callbacks,
+	event::Event,
+	signal::Response,
+};
+
+//---------------------------------------------------------------------------------------------------- SignalError
+/// An error from a signal
+///
+/// This is returned in [`Response::Error`] from [`Engine::apply`].
+///
+/// Signals sent with [`Engine::send`] do not wait for a response,
+/// so if they fail, the error is delivered as an [`Event::ErrorSignal`]
+/// and to [`Callbacks::error_signal`] instead of being returned.
+///
+/// Each variant is the error the equivalent waiting signal would have returned.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Clone,Debug,PartialEq)]
+#[derive(AsRefStr,EnumCount,EnumVariantNames,IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[derive(thiserror::Error)]
+pub enum SignalError {
+	/// See [`Engine::seek`].
+	#[error("seek: {0}")]
+	Seek(#[from] SeekError),
+	/// See [`Engine::skip`].
+	#[error("skip: {0}")]
+	Skip(#[from] SkipError),
+	/// See [`Engine::back`].
+	#[error("back: {0}")]
+	Back(#[from] BackError),
+	/// See [`Engine::set_index`].
+	#[error("set_index: {0}")]
+	SetIndex(#[from] SetIndexError),
+	/// See [`Engine::remove`] and [`Engine::remove_range`].
+	#[error("remove: {0}")]
+	Remove(#[from] RemoveError),
+	/// See [`Engine::restore`].
+	#[error("restore: {0}")]
+	Restore(#[from] RestoreError),
+	/// See [`Engine::output_device`] and [`Engine::output_latency`].
+	#[error("output: {0}")]
+	Output(#[from] OutputError),
+}
@@ -12,6 +12,9 @@ pub use back::{Back,BackError,BackThreshold};
 pub(crate) mod clear;
 pub use clear::Clear;
 
+pub(crate) mod error;
+pub use error::SignalError;
+
 pub(crate) mod next;
 pub(crate) use next::Next;
 
@@ -25,7 +28,7 @@ pub(crate) mod previous;
 pub use previous::{Previous,PreviousError};
 
 pub(crate) mod remove_range;
-pub(crate) use remove_range::RemoveRange;
+pub use remove_range::RemoveRange;
 // pub use remove_range::RemoveRangeError;
 
 pub(crate) mod remove;
@@ -35,6 +38,9 @@ pub(crate) mod repeat;
 pub use repeat::Repeat;
 pub(crate) use repeat::AtomicRepeat;
 
+pub(crate) mod restore;
+pub use restore::{Restore,Repair,RestoreError};
+
 pub(crate) mod seek;
 pub use seek::{Seek,SeekError};
 pub(crate) use seek::SeekedTime;
@@ -45,6 +51,12 @@ pub use set_index::{SetIndex,SetIndexError};
 pub(crate) mod shuffle;
 pub use shuffle::Shuffle;
 
+pub(crate) mod send_signal;
+pub(crate) use send_signal::SendSignal;
+
+pub(crate) mod signal;
+pub use signal::{Signal,Response};
+
 pub(crate) mod skip;
 pub use skip::{Skip,SkipError};
 
@@ -13,16 +13,28 @@ use strum::{
 };
 
 //---------------------------------------------------------------------------------------------------- RemoveRange
-/// TODO
+/// Remove a range of indices from the queue
+///
+/// This is [`Engine::remove_range`](crate::Engine::remove_range)'s range
+/// as a concrete type, see [`Signal::RemoveRange`](crate::signal::Signal::RemoveRange).
+///
+/// It can be created from any [`RangeBounds<usize>`](std::ops::RangeBounds).
+///
+/// ```rust
+/// # use sansan::signal::*;
+/// # use std::ops::Bound;
+/// let remove_range = RemoveRange::from(1..3);
+/// assert_eq!(remove_range.start_bound, Bound::Included(1));
+/// assert_eq!(remove_range.end_bound, Bound::Excluded(3));
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
-// #[derive(Copy,Clone,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
-#[derive(Copy,Clone,Debug)]
-pub(crate) struct RemoveRange {
-	/// TODO
-	pub(crate) start_bound: std::ops::Bound<usize>,
-	/// TODO
-	pub(crate) end_bound: std::ops::Bound<usize>,
+#[derive(Copy,Clone,Debug,PartialEq,Eq,Hash)]
+pub struct RemoveRange {
+	/// The start of the range.
+	pub start_bound: std::ops::Bound<usize>,
+	/// The end of the range.
+	pub end_bound: std::ops::Bound<usize>,
 }
 
 impl<T: std::ops::RangeBounds<usize>> From<T> for RemoveRange {
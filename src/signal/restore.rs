@@ -0,0 +1,242 @@
+//! TODO
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,EnumCount,IntoStaticStr,
+};
+use crate::{
+	source::{Source,source_decode::SourceDecode},
+	state::AudioState,
+	extra_data::ExtraData,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	state::Current,
+};
+
+//---------------------------------------------------------------------------------------------------- Restore
+/// Restore an [`AudioState`]
+///
+/// This is the input to [`Engine::restore`].
+///
+/// Before being restored, the [`AudioState`] is validated:
+/// - All [`Source::Path`]'s in the queue must exist
+/// - The [`Current::index`] must exist in the queue
+/// - The [`Current::source`] must be the same audio as the queue's entry at that index
+/// - The [`Current::elapsed`] must be within the track's runtime
+///
+/// If any of these fail, a [`RestoreError`] is returned and nothing is restored,
+/// unless the corresponding [`Repair`] mode is enabled.
+///
+/// Validating is done on the caller's thread and blocks on the filesystem,
+/// the [`Current::source`] is opened to find its runtime if its
+/// [`Current::elapsed`] is positive, so restoring state with large
+/// or slow (e.g. network) files can take a while.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Clone,Debug,PartialEq)]
+pub struct Restore<Extra: ExtraData> {
+	/// The [`AudioState`] to restore.
+	pub audio_state: AudioState<Extra>,
+	/// How should an invalid `audio_state` be repaired?
+	pub repair: Repair,
+}
+
+impl<Extra: ExtraData> From<AudioState<Extra>> for Restore<Extra> {
+	/// Create a [`Restore`] with [`Repair::NONE`], i.e. an invalid [`AudioState`] will error.
+	fn from(audio_state: AudioState<Extra>) -> Self {
+		Self {
+			audio_state,
+			repair: Repair::NONE,
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Repair
+/// How to repair an invalid [`AudioState`] when restoring
+///
+/// This is typically needed when restoring stale state, e.g. after
+/// the user has moved or deleted files since the state was saved.
+///
+/// The repairs are applied in the order of the fields below.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+pub struct Repair {
+	/// Remove [`Source::Path`]'s that no longer exist from the
+	/// queue, and re-index the [`Current`] to its new position.
+	///
+	/// If the [`Current`] itself no longer exists, it is set to `None`.
+	pub drop_missing: bool,
+
+	/// Clamp out-of-range values.
+	///
+	/// An out-of-bounds [`Current::index`] is set to the last
+	/// index of the queue (the [`Current`] is set to that track,
+	/// starting from the beginning), and [`Current::elapsed`]
+	/// is clamped to the track's runtime.
+	///
+	/// If the queue is empty, the [`Current`] is set to `None`.
+	pub clamp: bool,
+
+	/// If the [`Current`] is still invalid, set it to `None`.
+	pub reset_current: bool,
+}
+
+impl Repair {
+	/// Don't repair anything, any invalid state returns a [`RestoreError`].
+	pub const NONE: Self = Self {
+		drop_missing: false,
+		clamp: false,
+		reset_current: false,
+	};
+
+	/// Enable all repairs, this never returns a [`RestoreError`].
+	pub const ALL: Self = Self {
+		drop_missing: true,
+		clamp: true,
+		reset_current: true,
+	};
+}
+
+//---------------------------------------------------------------------------------------------------- Restore Impl
+impl<Extra: ExtraData> Restore<Extra> {
+	/// Validate (and repair) the [`AudioState`].
+	///
+	/// This touches the filesystem, so it is done by
+	/// the caller's thread, before reaching `Kernel`.
+	///
+	/// It blocks on checking that each [`Source::Path`] exists, and on
+	/// probing the [`Current::source`] for its runtime, although the
+	/// latter only happens if [`Current::elapsed`] is positive.
+	pub(crate) fn validate(self) -> Result<AudioState<Extra>, RestoreError> {
+		let Self { mut audio_state, repair } = self;
+
+		//------------------------------------------ Missing files
+		let mut i = 0;
+		while i < audio_state.queue.len() {
+			let missing = match &audio_state.queue[i] {
+				Source::Path { source, .. } => !source.exists(),
+				Source::Byte { .. } => false,
+			};
+
+			if !missing {
+				i += 1;
+				continue;
+			}
+
+			if !repair.drop_missing {
+				return Err(RestoreError::Missing(i));
+			}
+
+			audio_state.queue.remove(i);
+
+			// Re-index the `Current`.
+			if let Some(current) = audio_state.current.as_mut() {
+				match current.index.cmp(&i) {
+					std::cmp::Ordering::Less => (),
+					std::cmp::Ordering::Equal => audio_state.current = None,
+					std::cmp::Ordering::Greater => current.index -= 1,
+				}
+			}
+		}
+
+		let Some(current) = audio_state.current.as_mut() else {
+			return Ok(audio_state);
+		};
+
+		//------------------------------------------ Index
+		let len = audio_state.queue.len();
+		if current.index >= len {
+			if repair.clamp && len != 0 {
+				current.index = len - 1;
+				current.source = audio_state.queue[current.index].clone();
+				current.elapsed = 0.0;
+			} else if repair.reset_current || repair.clamp {
+				audio_state.current = None;
+				return Ok(audio_state);
+			} else {
+				return Err(RestoreError::CurrentIndex { index: current.index, len });
+			}
+		}
+
+		//------------------------------------------ Source
+		if !current.source.same_audio(&audio_state.queue[current.index]) {
+			if repair.reset_current {
+				audio_state.current = None;
+				return Ok(audio_state);
+			}
+			return Err(RestoreError::CurrentSource(current.index));
+		}
+
+		//------------------------------------------ Elapsed
+		// `0.0` is always valid and a negative (or NaN) elapsed never
+		// is, so the file is only probed for its runtime if that's
+		// needed to check `elapsed`, or to report it in the error.
+		let invalid = current.elapsed < 0.0 || current.elapsed.is_nan();
+		let probe = current.elapsed > 0.0 || (invalid && !repair.clamp && !repair.reset_current);
+
+		// If the runtime can't be found, the `Source` will
+		// error in `Decode` as usual, so this is skipped.
+		let runtime = if probe {
+			SourceDecode::try_from(current.source.clone()).map_or(f32::INFINITY, |s| s.secs_total)
+		} else {
+			f32::INFINITY
+		};
+
+		if !(0.0..=runtime).contains(&current.elapsed) {
+			if repair.clamp {
+				current.elapsed = if current.elapsed.is_nan() {
+					0.0
+				} else {
+					current.elapsed.clamp(0.0, runtime)
+				};
+			} else if repair.reset_current {
+				audio_state.current = None;
+			} else {
+				return Err(RestoreError::Elapsed { elapsed: current.elapsed, runtime });
+			}
+		}
+
+		Ok(audio_state)
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- RestoreError
+/// Errors when restoring an invalid [`AudioState`]
+///
+/// See [`Restore`] and [`Repair`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(AsRefStr,EnumCount,IntoStaticStr)]
+#[derive(Copy,Clone,Debug,PartialEq,PartialOrd)]
+#[derive(thiserror::Error)]
+pub enum RestoreError {
+	#[error("the file at queue index {0} does not exist")]
+	/// The [`Source::Path`] at this queue index does not exist
+	Missing(usize),
+
+	#[error("current index {index} is out of bounds for a queue of length {len}")]
+	/// The [`Current::index`] does not exist in the queue
+	CurrentIndex {
+		/// The [`Current::index`]
+		index: usize,
+		/// The length of the queue
+		len: usize,
+	},
+
+	#[error("current source does not match the queue at index {0}")]
+	/// The [`Current::source`] is not the same as the queue's entry at [`Current::index`]
+	CurrentSource(usize),
+
+	#[error("current elapsed {elapsed} is outside of the track's runtime {runtime}")]
+	/// The [`Current::elapsed`] is negative, or past the track's runtime
+	Elapsed {
+		/// The [`Current::elapsed`]
+		elapsed: f32,
+		/// The track's runtime in seconds
+		runtime: f32,
+	},
+}
@@ -0,0 +1,55 @@
+//! Signals that are not waited on.
+
+//---------------------------------------------------------------------------------------------------- use
+use crate::{
+	extra_data::ExtraData,
+	output::Latency,
+	signal::{
+		Add,AddMany,Back,Clear,RemoveRange,Remove,
+		Repeat,Seek,SetIndex,Shuffle,Skip,Volume,
+		RestoreError,
+	},
+	state::AudioState,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	signal::Signal,
+};
+
+//---------------------------------------------------------------------------------------------------- Signal
+/// A signal sent with [`Engine::send`].
+///
+/// These all go through the same channel (unlike the waiting
+/// signals, which each have their own), so that `Kernel`
+/// applies them in the order they were sent.
+///
+/// Unlike [`Signal`], this only contains the signals
+/// that `Kernel` can handle without anyone waiting.
+#[derive(Clone,Debug)]
+#[allow(clippy::missing_docs_in_private_items)] // See the `Engine` methods.
+pub(crate) enum SendSignal<Extra: ExtraData> {
+	Toggle,
+	Play,
+	Pause,
+	Next,
+	Previous,
+	Stop,
+	Clear(Clear),
+	Repeat(Repeat),
+	Volume(Volume),
+	Shuffle(Shuffle),
+	Add(Add<Extra>),
+	AddMany(AddMany<Extra>),
+	Seek(Seek),
+	Skip(Skip),
+	Back(Back),
+	SetIndex(SetIndex),
+	Remove(Remove),
+	RemoveRange(RemoveRange),
+	/// Already validated by the caller, `Kernel` only sends the error.
+	Restore(Result<AudioState<Extra>, RestoreError>),
+	OutputDevice(Option<String>),
+	OutputLatency(Latency),
+}
@@ -0,0 +1,134 @@
+//! All signals as data.
+
+//---------------------------------------------------------------------------------------------------- use
+use crate::{
+	config::RuntimeConfig,
+	extra_data::ExtraData,
+	output::Latency,
+	signal::{
+		Add,AddMany,Back,Clear,RemoveRange,Remove,Repeat,
+		Restore,Seek,SetIndex,Shuffle,SignalError,Skip,Volume,
+	},
+	state::AudioState,
+};
+use strum::{
+	AsRefStr,EnumCount,EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	state::AudioStateSnapshot,
+};
+
+//---------------------------------------------------------------------------------------------------- Signal
+/// Any [`Engine`] signal, as data
+///
+/// Each variant is the input of the [`Engine`] method
+/// of the same name, and is applied with [`Engine::apply`].
+///
+/// This (and [`Response`]) can be serialized with `serde` and
+/// `bincode`, making it a uniform format for IPC, scripting,
+/// logging, replaying signals, etc.
+///
+/// ```rust,ignore
+/// # use sansan::{*,signal::*};
+/// let mut engine = Engine::<()>::init(Default::default());
+///
+/// // e.g. received from another process.
+/// let signals = [
+///     Signal::Volume(Volume::new(0.5)),
+///     Signal::Repeat(Repeat::Queue),
+///     Signal::RemoveRange(RemoveRange::from(0..2)),
+/// ];
+///
+/// for signal in signals {
+///     match engine.apply(signal) {
+///         Response::AudioState(audio_state) => println!("{audio_state:?}"),
+///         Response::Error(error) => eprintln!("{error}"),
+///     }
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Clone,Debug,PartialEq)]
+#[derive(AsRefStr,EnumCount,EnumVariantNames,IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Signal<Extra: ExtraData> {
+	/// [`Engine::toggle`]
+	Toggle,
+	/// [`Engine::play`]
+	Play,
+	/// [`Engine::pause`]
+	Pause,
+	/// [`Engine::next`]
+	Next,
+	/// [`Engine::previous`]
+	Previous,
+	/// [`Engine::stop`]
+	Stop,
+	/// [`Engine::clear`]
+	Clear(Clear),
+	/// [`Engine::restore`]
+	Restore(Restore<Extra>),
+	/// [`Engine::repeat`]
+	Repeat(Repeat),
+	/// [`Engine::volume`]
+	Volume(Volume),
+	/// [`Engine::shuffle`]
+	Shuffle(Shuffle),
+	/// [`Engine::add`]
+	Add(Add<Extra>),
+	/// [`Engine::add_many`]
+	AddMany(AddMany<Extra>),
+	/// [`Engine::seek`]
+	Seek(Seek),
+	/// [`Engine::skip`]
+	Skip(Skip),
+	/// [`Engine::back`]
+	Back(Back),
+	/// [`Engine::set_index`]
+	SetIndex(SetIndex),
+	/// [`Engine::remove`]
+	Remove(Remove),
+	/// [`Engine::remove_range`]
+	RemoveRange(RemoveRange),
+	/// [`Engine::output_device`]
+	OutputDevice(Option<String>),
+	/// [`Engine::output_latency`]
+	OutputLatency(Latency),
+	/// [`Engine::config_update`]
+	///
+	/// This replaces the whole [`RuntimeConfig`].
+	ConfigUpdate(RuntimeConfig),
+}
+
+//---------------------------------------------------------------------------------------------------- Response
+/// The response to a [`Signal`]
+///
+/// This is returned by [`Engine::apply`].
+///
+/// Unlike the [`Engine`] methods (which return an [`AudioStateSnapshot`]),
+/// this owns a copy of the [`AudioState`], so that it can be serialized.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Clone,Debug,PartialEq)]
+#[derive(AsRefStr,EnumCount,EnumVariantNames,IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Response<Extra: ExtraData> {
+	/// The [`Signal`] was applied, this is the [`AudioState`] right after.
+	AudioState(AudioState<Extra>),
+	/// The [`Signal`] failed, nothing was changed.
+	Error(SignalError),
+}
+
+impl<Extra: ExtraData> From<Response<Extra>> for Result<AudioState<Extra>, SignalError> {
+	fn from(response: Response<Extra>) -> Self {
+		match response {
+			Response::AudioState(audio_state) => Ok(audio_state),
+			Response::Error(error) => Err(error),
+		}
+	}
+}
@@ -114,6 +114,17 @@ impl<Extra: ExtraData> Source<Extra> {
 	pub const fn is_byte(&self) -> bool {
 		matches!(self, Self::Byte { .. })
 	}
+	#[must_use]
+	/// If `self` and `other` contain the same audio, ignoring the `Extra` data.
+	///
+	/// This compares the PATHs of [`Self::Path`]'s and the bytes of [`Self::Byte`]'s.
+	pub fn same_audio(&self, other: &Self) -> bool {
+		match (self, other) {
+			(Self::Path { source: a, .. }, Self::Path { source: b, .. }) => a == b,
+			(Self::Byte { source: a, .. }, Self::Byte { source: b, .. }) => std::sync::Arc::ptr_eq(a, b) || a == b,
+			_ => false,
+		}
+	}
 }
 
 //---------------------------------------------------------------------------------------------------- Source::from
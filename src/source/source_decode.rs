@@ -3,8 +3,9 @@
 //---------------------------------------------------------------------------------------------------- Use
 use crate::{
 	source::Source,
-	error::SourceError,
+	error::{SourceError,DecodeError},
 	extra_data::ExtraData,
+	macros::{debug2,trace2,warn2},
 };
 use std::{
 	time::Duration,
@@ -15,7 +16,9 @@ use std::{
 	borrow::Cow,
 };
 use symphonia::core::{
-	formats::{FormatReader,FormatOptions},
+	audio::{AudioBuffer,Signal,SignalSpec},
+	errors::Error as SymphoniaError,
+	formats::{FormatReader,FormatOptions,Packet},
 	io::{MediaSourceStream, MediaSourceStreamOptions},
 	probe::Hint,
 	meta::{MetadataOptions,Limit},
@@ -149,6 +152,268 @@ impl SourceDecode {
 	}
 }
 
+//---------------------------------------------------------------------------------------------------- Track
+/// The decoder and info of the track a [`SourceDecode`] is decoding.
+type Track = (Box<dyn Decoder>, u32, f32, TimeBase);
+
+/// Select the first track in `reader` with a known codec, and create a decoder for it.
+fn track(reader: &dyn FormatReader) -> Result<Track, SourceError> {
+	// TODO:
+	// These lazy's should be initialized early on in the `Engine` init phase.
+	let codecs = symphonia::default::get_codecs();
+
+	// Select the first track with a known codec.
+	let Some(track) = reader
+		.tracks()
+		.iter()
+		.find(|t| {
+			// Make sure it is not null.
+			t.codec_params.codec != symphonia::core::codecs::CODEC_TYPE_NULL &&
+			// And it exists in our codec registry.
+			codecs.get_codec(t.codec_params.codec).is_some()
+		})
+	else {
+		return Err(SourceError::Current);
+	};
+
+	// Create a decoder for the track.
+	//
+	// TODO: this can be cached so aren't boxing each time.
+	let decoder = match get_codecs().make(&track.codec_params, &DECODER_OPTIONS) {
+		Ok(d) => d,
+		Err(e) => return Err(SourceError::Decoder(e.into())),
+	};
+
+	// Get sample rate.
+	let Some(sample_rate) = track.codec_params.sample_rate else {
+		return Err(SourceError::SampleRate);
+	};
+
+	// Get timebase.
+	let Some(timebase) = track.codec_params.time_base else {
+		return Err(SourceError::TimeBase);
+	};
+
+	// Calculate total runtime of audio.
+	let Some(n_frames) = track.codec_params.n_frames else {
+		return Err(SourceError::Frames);
+	};
+	let time_total = timebase.calc_time(n_frames);
+	let secs_total = time_total.seconds as f32 + time_total.frac as f32;
+
+	Ok((decoder, sample_rate, secs_total, timebase))
+}
+
+impl SourceDecode {
+	#[cold]
+	#[inline(never)]
+	/// Re-select the track and re-create the decoder.
+	///
+	/// This is needed when the [`FormatReader`]'s track list changes
+	/// (`symphonia`'s `ResetRequired` from `next_packet()`), as the
+	/// old decoder may be for a track that no longer exists.
+	pub(crate) fn reset_track(&mut self) -> Result<(), SourceError> {
+		let (decoder, sample_rate, secs_total, timebase) = track(&*self.reader)?;
+		self.decoder     = decoder;
+		self.sample_rate = sample_rate;
+		self.secs_total  = secs_total;
+		self.timebase    = timebase;
+		Ok(())
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- PacketDecode
+/// Corrupt packet handling state, shared by `Decode` and `Render`.
+///
+/// Corrupt packets are skipped (and the gap they leave filled
+/// with silence) until `threshold` of them happen in a row.
+pub(crate) struct PacketDecode {
+	/// How many corrupt packets in a row we will skip before reporting a [`DecodeError`].
+	pub(crate) threshold: usize,
+	/// How many corrupt packets in a row we've skipped.
+	pub(crate) count: usize,
+	/// How many corrupt packets in total we've skipped in the current [Source].
+	pub(crate) total: usize,
+	/// The last successfully decoded audio spec and capacity.
+	///
+	/// This is used to create silent [`AudioBuffer`]'s that fill
+	/// the gap left by corrupt packets, so that timing is preserved.
+	///
+	/// This is `None` if nothing has been decoded yet in the current [Source].
+	pub(crate) silence_spec: Option<(SignalSpec, u64)>,
+	/// Set once `threshold` was passed, the caller should `take()` and report this.
+	pub(crate) error: Option<DecodeError>,
+}
+
+impl PacketDecode {
+	/// Create a new [`PacketDecode`] with a `threshold`.
+	pub(crate) const fn new(threshold: usize) -> Self {
+		Self {
+			threshold,
+			count: 0,
+			total: 0,
+			silence_spec: None,
+			error: None,
+		}
+	}
+
+	/// Reset the state for a new [Source].
+	pub(crate) fn reset(&mut self) {
+		self.count = 0;
+		self.total = 0;
+		self.silence_spec = None;
+		self.error = None;
+	}
+
+	/// A packet was decoded correctly into `audio`.
+	fn decoded(&mut self, audio: &AudioBuffer<f32>) {
+		// We're decoding fine again (if we weren't before).
+		self.count = 0;
+
+		// Remember the spec in case we need to fill gaps with silence.
+		self.silence_spec = Some((*audio.spec(), audio.capacity() as u64));
+	}
+
+	/// Create a silent [`AudioBuffer`] the length of `packet`.
+	///
+	/// This fills the gap left by a corrupt packet such that
+	/// the timing of the rest of the track is preserved.
+	///
+	/// If nothing has been decoded yet, we don't know the
+	/// spec of the audio, so `None` is returned.
+	pub(crate) fn silence(&self, packet: &Packet) -> Option<AudioBuffer<f32>> {
+		let (spec, capacity) = self.silence_spec?;
+
+		// Keep the same capacity as the real audio buffers
+		// (if possible) so [Audio] doesn't re-open the output.
+		let frames = packet.dur;
+		if frames == 0 {
+			return None;
+		}
+		let capacity = std::cmp::max(capacity, frames);
+
+		let mut audio = AudioBuffer::<f32>::new(capacity, spec);
+		#[allow(clippy::cast_possible_truncation)]
+		audio.render_silence(Some(frames as usize));
+
+		trace2!("PacketDecode - silence(), frames: {frames}");
+		Some(audio)
+	}
+
+	/// Skip a corrupt packet, from either `next_packet()` or `decode()`.
+	///
+	/// These are skipped until `threshold` is reached in a row,
+	/// after which `error` is set for the caller to report.
+	///
+	/// Returns `true` if `error` was set.
+	pub(crate) fn skip(&mut self, error: SymphoniaError) -> bool {
+		self.count = self.count.saturating_add(1);
+		self.total = self.total.saturating_add(1);
+
+		if self.count > self.threshold {
+			self.count = 0;
+			self.error = Some(DecodeError::from(error));
+			true
+		} else {
+			warn2!("PacketDecode - skipping corrupt packet ({}/{}): {error}", self.count, self.threshold);
+			false
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Next
+/// The result of [`SourceDecode::next`].
+pub(crate) enum Next {
+	/// Decoded audio (or silence filling a corrupt packet), its timestamp, and the packet it came from.
+	Audio(AudioBuffer<f32>, Time, Packet),
+	/// A corrupt packet was skipped without filling the gap.
+	Skipped(Option<Packet>),
+	/// We're done decoding.
+	End,
+	/// The track list changed and there is no longer a playable
+	/// track, or the [`FormatReader`] can't recover from corrupt data.
+	Source(SourceError),
+}
+
+impl SourceDecode {
+	/// Read and decode the next packet.
+	///
+	/// Corrupt packets are handled by `packets`, if its `error`
+	/// is set after this returns, it should be reported.
+	pub(crate) fn next(&mut self, packets: &mut PacketDecode) -> Next {
+		let packet = match self.reader.next_packet() {
+			Ok(p) => p,
+
+			// This "end of stream" error is currently the only way
+			// a [FormatReader] can indicate the media is complete.
+			Err(SymphoniaError::IoError(_)) => return Next::End,
+
+			// The track list changed, our decoder must
+			// be re-created for the (possibly new) track.
+			Err(SymphoniaError::ResetRequired) => {
+				debug2!("SourceDecode - next_packet() reset required, re-creating decoder");
+
+				// The new track may have a different spec.
+				packets.silence_spec = None;
+
+				return match self.reset_track() {
+					Ok(()) => Next::Skipped(None),
+					Err(e) => Next::Source(e),
+				};
+			},
+
+			// The container data is corrupt, the [FormatReader]
+			// will attempt to resync on the next valid packet.
+			//
+			// If it still hasn't after `threshold` packets, it most
+			// likely never will, so give up on this [Source] instead of
+			// spinning on it forever, `Kernel` acts on this with the
+			// `ErrorCallback` for [`SourceError`]'s.
+			Err(e) => {
+				if packets.skip(e) {
+					if let Some(error) = packets.error.take() {
+						return Next::Source(SourceError::Decoder(error));
+					}
+				}
+				return Next::Skipped(None);
+			},
+		};
+
+		match self.decoder.decode(&packet) {
+			Ok(decoded) => {
+				// Convert and take ownership of audio buffer.
+				let mut audio = decoded.make_equivalent::<f32>();
+				decoded.convert(&mut audio);
+				packets.decoded(&audio);
+
+				let time = self.timebase.calc_time(packet.ts);
+				return Next::Audio(audio, time, packet);
+			},
+
+			// The decoder must be reset, but the
+			// next packet should decode correctly.
+			Err(SymphoniaError::ResetRequired) => {
+				debug2!("SourceDecode - decode() reset required, resetting decoder");
+				self.decoder.reset();
+			},
+
+			// The packet is corrupt, skip to the next one.
+			Err(e) => {
+				packets.skip(e);
+			},
+		}
+
+		// The packet is lost, fill the gap it left with silence.
+		match packets.silence(&packet) {
+			Some(audio) => {
+				let time = self.timebase.calc_time(packet.ts);
+				Next::Audio(audio, time, packet)
+			},
+			None => Next::Skipped(Some(packet)),
+		}
+	}
+}
+
 //---------------------------------------------------------------------------------------------------- MediaSourceStream -> SourceDecode
 impl TryFrom<MediaSourceStream> for SourceDecode {
 	type Error = SourceError;
@@ -162,49 +427,7 @@ impl TryFrom<MediaSourceStream> for SourceDecode {
 		)?;
 
 		let reader = result.format;
-
-		// TODO:
-		// These lazy's should be initialized early on in the `Engine` init phase.
-		let codecs = symphonia::default::get_codecs();
-
-		// Select the first track with a known codec.
-		let Some(track) = reader
-			.tracks()
-			.iter()
-			.find(|t| {
-				// Make sure it is not null.
-				t.codec_params.codec != symphonia::core::codecs::CODEC_TYPE_NULL &&
-				// And it exists in our codec registry.
-				codecs.get_codec(t.codec_params.codec).is_some()
-			})
-		else {
-			return Err(SourceError::Current);
-		};
-
-		// Create a decoder for the track.
-		//
-		// TODO: this can be cached so aren't boxing each time.
-		let decoder = match get_codecs().make(&track.codec_params, &DECODER_OPTIONS) {
-			Ok(d) => d,
-			Err(e) => return Err(SourceError::Decoder(e.into())),
-		};
-
-		// Get sample rate.
-		let Some(sample_rate) = track.codec_params.sample_rate else {
-			return Err(SourceError::SampleRate);
-		};
-
-		// Get timebase.
-		let Some(timebase) = track.codec_params.time_base else {
-			return Err(SourceError::TimeBase);
-		};
-
-		// Calculate total runtime of audio.
-		let Some(n_frames) = track.codec_params.n_frames else {
-			return Err(SourceError::Frames);
-		};
-		let time_total = timebase.calc_time(n_frames);
-		let secs_total = time_total.seconds as f32 + time_total.frac as f32;
+		let (decoder, sample_rate, secs_total, timebase) = track(&*reader)?;
 
 		Ok(Self {
 			reader,
@@ -240,4 +463,151 @@ impl<Extra: ExtraData> TryFrom<Source<Extra>> for SourceDecode {
 			},
 		}
 	}
-}
\ No newline at end of file
+}
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+	use super::*;
+	use symphonia::core::audio::Channels;
+	use pretty_assertions::assert_eq;
+
+	/// A `PacketDecode` that has already decoded 1 stereo 44.1kHz packet.
+	fn init(threshold: usize) -> PacketDecode {
+		let mut packets = PacketDecode::new(threshold);
+		packets.silence_spec = Some((
+			SignalSpec::new(44_100, Channels::FRONT_LEFT | Channels::FRONT_RIGHT),
+			1152,
+		));
+		packets
+	}
+
+	/// A corrupt packet.
+	fn packet(ts: u64, dur: u64) -> Packet {
+		Packet::new_from_slice(0, ts, dur, &[])
+	}
+
+	/// A `symphonia` decode error.
+	const fn error() -> SymphoniaError {
+		SymphoniaError::DecodeError("corrupt")
+	}
+
+	#[test]
+	fn skip_packet_fill_silence() {
+		let mut packets = init(16);
+
+		// A corrupt packet is skipped and replaced with silence of the same length.
+		assert!(!packets.skip(error()));
+		let audio = packets.silence(&packet(44_100, 576)).unwrap();
+		assert_eq!(audio.frames(), 576);
+		assert_eq!(audio.capacity(), 1152);
+		assert_eq!(audio.spec().channels.count(), 2);
+		assert!(audio.chan(0).iter().chain(audio.chan(1)).all(|s| *s == 0.0));
+
+		// Nothing was reported.
+		assert!(packets.error.is_none());
+		assert_eq!(packets.total, 1);
+	}
+
+	#[test]
+	fn no_silence_without_spec() {
+		// Nothing has been decoded yet, so the spec is unknown.
+		let mut packets = init(16);
+		packets.silence_spec = None;
+		assert!(packets.silence(&packet(0, 576)).is_none());
+
+		// Empty packets leave no gap.
+		assert!(init(16).silence(&packet(0, 0)).is_none());
+
+		// A new source forgets the spec.
+		let mut packets = init(16);
+		packets.skip(error());
+		packets.reset();
+		assert!(packets.silence(&packet(0, 576)).is_none());
+		assert_eq!(packets.total, 0);
+	}
+
+	#[test]
+	fn threshold() {
+		let mut packets = init(2);
+
+		// Errors are skipped until the threshold.
+		assert!(!packets.skip(error()));
+		assert!(!packets.skip(error()));
+		assert!(packets.error.is_none());
+
+		// Then reported, and the count restarts.
+		assert!(packets.skip(error()));
+		assert!(matches!(packets.error.take(), Some(DecodeError::Decode("corrupt"))));
+		assert_eq!(packets.count, 0);
+
+		assert!(!packets.skip(error()));
+		assert!(!packets.skip(error()));
+		assert!(packets.skip(error()));
+		assert!(matches!(packets.error.take(), Some(DecodeError::Decode("corrupt"))));
+		assert_eq!(packets.total, 6);
+	}
+
+	/// A [`FormatReader`] that can't resync, every packet is corrupt.
+	struct DeadReader(Box<dyn FormatReader>);
+
+	impl FormatReader for DeadReader {
+		fn try_new(_: MediaSourceStream, _: &FormatOptions) -> symphonia::core::errors::Result<Self> {
+			unreachable!()
+		}
+		fn cues(&self) -> &[symphonia::core::formats::Cue] {
+			self.0.cues()
+		}
+		fn metadata(&mut self) -> symphonia::core::meta::Metadata<'_> {
+			self.0.metadata()
+		}
+		fn seek(
+			&mut self,
+			mode: symphonia::core::formats::SeekMode,
+			to: symphonia::core::formats::SeekTo,
+		) -> symphonia::core::errors::Result<symphonia::core::formats::SeekedTo> {
+			self.0.seek(mode, to)
+		}
+		fn tracks(&self) -> &[symphonia::core::formats::Track] {
+			self.0.tracks()
+		}
+		fn next_packet(&mut self) -> symphonia::core::errors::Result<Packet> {
+			Err(error())
+		}
+		fn into_inner(self: Box<Self>) -> MediaSourceStream {
+			self.0.into_inner()
+		}
+	}
+
+	#[test]
+	// A `FormatReader` that never recovers ends the `Source`.
+	fn dead_reader() {
+		let mut source = SourceDecode::try_from(crate::tests::source(0)).unwrap();
+		source.reader = Box::new(DeadReader(source.reader));
+		let mut packets = PacketDecode::new(2);
+
+		// Skipped until the threshold...
+		assert!(matches!(source.next(&mut packets), Next::Skipped(None)));
+		assert!(matches!(source.next(&mut packets), Next::Skipped(None)));
+
+		// ...then given up on, instead of being skipped forever.
+		assert!(matches!(
+			source.next(&mut packets),
+			Next::Source(SourceError::Decoder(DecodeError::Decode("corrupt"))),
+		));
+		// It is reported as a `SourceError`, not a `DecodeError`.
+		assert!(packets.error.is_none());
+	}
+
+	#[test]
+	fn threshold_zero() {
+		let mut packets = init(0);
+
+		// Every corrupt packet is reported.
+		for _ in 0..3 {
+			assert!(packets.skip(error()));
+			assert!(packets.error.take().is_some());
+		}
+		assert_eq!(packets.total, 3);
+	}
+}
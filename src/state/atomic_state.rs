@@ -8,8 +8,16 @@ use crate::{
 		DEFAULT_BACK_THRESHOLD_F32,
 		DEFAULT_ELAPSED_REFRESH_RATE_F32,
 	},
+	output::{OutputBackend,SampleFormat},
+	state::Priority,
+};
+#[cfg(feature = "stats")]
+use crate::state::AtomicStats;
+use std::sync::{
+	Mutex,
+	PoisonError,
+	atomic::{AtomicBool,AtomicUsize,Ordering},
 };
-use std::sync::atomic::{AtomicBool,Ordering};
 use crossbeam::atomic::AtomicCell;
 
 //----------------------------------------------------------------------------------------------------
@@ -23,6 +31,22 @@ const _: () = {
 		crossbeam::atomic::AtomicCell::<Option<f32>>::is_lock_free(),
 		"crossbeam::atomic::AtomicCell::<Option<f32>> is not lock-free on the target platform.",
 	);
+	assert!(
+		crossbeam::atomic::AtomicCell::<Option<OutputBackend>>::is_lock_free(),
+		"crossbeam::atomic::AtomicCell::<Option<OutputBackend>> is not lock-free on the target platform.",
+	);
+	assert!(
+		crossbeam::atomic::AtomicCell::<Option<SampleFormat>>::is_lock_free(),
+		"crossbeam::atomic::AtomicCell::<Option<SampleFormat>> is not lock-free on the target platform.",
+	);
+	assert!(
+		crossbeam::atomic::AtomicCell::<Option<u32>>::is_lock_free(),
+		"crossbeam::atomic::AtomicCell::<Option<u32>> is not lock-free on the target platform.",
+	);
+	assert!(
+		crossbeam::atomic::AtomicCell::<Priority>::is_lock_free(),
+		"crossbeam::atomic::AtomicCell::<Priority> is not lock-free on the target platform.",
+	);
 };
 
 //---------------------------------------------------------------------------------------------------- AtomicState
@@ -39,6 +63,11 @@ pub(crate) struct AtomicState {
 	pub(crate) elapsed_refresh_rate: AtomicCell<f32>,
 	/// TODO
 	pub(crate) queue_end_clear: AtomicBool,
+	/// The whole `RuntimeConfig` the above are copied from.
+	///
+	/// This is only locked by `Engine`/`EngineHandle`/`Autosave`,
+	/// never by the actors, use `config()` and `config_update()`.
+	config: Mutex<RuntimeConfig>,
 
 	//---
 	/// TODO
@@ -51,6 +80,34 @@ pub(crate) struct AtomicState {
 	pub(crate) volume: AtomicVolume,
 	/// TODO
 	pub(crate) elapsed: AtomicCell<Option<f32>>,
+	/// The audio output backend currently in use (`None` if no output is open yet).
+	pub(crate) output_backend: AtomicCell<Option<OutputBackend>>,
+	/// The sample format of the audio output currently in use (`None` if no output is open yet).
+	pub(crate) output_format: AtomicCell<Option<SampleFormat>>,
+	/// How many `Glitch::Underrun`'s have occurred.
+	pub(crate) underruns: AtomicUsize,
+	/// How many `Glitch::Overrun`'s have occurred.
+	pub(crate) overruns: AtomicUsize,
+	/// How many `Glitch::LateWrite`'s have occurred.
+	pub(crate) late_writes: AtomicUsize,
+	/// How many corrupt packets `Decode` has skipped.
+	pub(crate) packets_skipped: AtomicUsize,
+	/// The negotiated latency of the audio output currently in use, in microseconds
+	/// (`None` if no output is open yet, or the output doesn't know its latency).
+	pub(crate) output_latency: AtomicCell<Option<u32>>,
+	/// The scheduling priority of `Audio`.
+	pub(crate) priority_audio: AtomicCell<Priority>,
+	/// The scheduling priority of `Decode`.
+	pub(crate) priority_decode: AtomicCell<Priority>,
+	/// Are the OS media controls active?
+	pub(crate) media_controls: AtomicBool,
+	/// Is there at least 1 `Event` subscriber?
+	///
+	/// `Kernel` only generates `Event`'s if so.
+	pub(crate) events: AtomicBool,
+	/// Diagnostics, only with the `stats` feature.
+	#[cfg(feature = "stats")]
+	pub(crate) stats: AtomicStats,
 }
 
 impl AtomicState {
@@ -62,10 +119,24 @@ impl AtomicState {
 		elapsed_refresh_rate: AtomicCell::new(DEFAULT_ELAPSED_REFRESH_RATE_F32),
 
 		queue_end_clear: AtomicBool::new(true),
+		config: Mutex::new(RuntimeConfig::DEFAULT),
 		playing: AtomicBool::new(false),
 		repeat: AtomicRepeat::DEFAULT,
 		volume: AtomicVolume::DEFAULT,
 		elapsed: AtomicCell::new(None),
+		output_backend: AtomicCell::new(None),
+		output_format: AtomicCell::new(None),
+		output_latency: AtomicCell::new(None),
+		underruns: AtomicUsize::new(0),
+		overruns: AtomicUsize::new(0),
+		late_writes: AtomicUsize::new(0),
+		packets_skipped: AtomicUsize::new(0),
+		priority_audio: AtomicCell::new(Priority::DEFAULT),
+		priority_decode: AtomicCell::new(Priority::DEFAULT),
+		media_controls: AtomicBool::new(false),
+		events: AtomicBool::new(false),
+		#[cfg(feature = "stats")]
+		stats: AtomicStats::new(),
 	};
 
 	///
@@ -74,12 +145,29 @@ impl AtomicState {
 		self.elapsed_refresh_rate.store(config.elapsed_refresh_rate.as_secs_f32());
 		self.queue_end_clear.store(config.queue_end_clear, Ordering::Release);
 	}
+
+	/// Returns the current `RuntimeConfig`.
+	pub(crate) fn config(&self) -> RuntimeConfig {
+		*self.config.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	/// Update the `RuntimeConfig` with `f`, and the atomic fields copied from it.
+	///
+	/// The lock is held throughout, so concurrent updates don't lose each other's changes.
+	pub(crate) fn config_update<F>(&self, f: F)
+	where
+		F: FnOnce(&mut RuntimeConfig)
+	{
+		let mut config = self.config.lock().unwrap_or_else(PoisonError::into_inner);
+		f(&mut config);
+		self.update_from_config(&config);
+	}
 }
 
 impl From<RuntimeConfig> for AtomicState {
 	fn from(s: RuntimeConfig) -> Self {
 		let this = Self::DEFAULT;
-		this.update_from_config(&s);
+		this.config_update(|c| *c = s);
 		this
 	}
 }
@@ -0,0 +1,198 @@
+//! Atomic engine diagnostics.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	render::RenderSpec,
+	state::{Stats,Messages},
+};
+use std::{
+	time::{Duration,Instant},
+	sync::atomic::{AtomicBool,AtomicU16,AtomicU32,AtomicUsize,Ordering},
+};
+
+//---------------------------------------------------------------------------------------------------- AtomicStats
+/// The shared counters behind `Stats`.
+///
+/// Each actor updates its own counters with `stats!()`,
+/// this only exists with the `stats` feature enabled.
+///
+/// All accesses are `Ordering::Relaxed`,
+/// these are only used for observability.
+#[derive(Debug)]
+#[allow(clippy::missing_docs_in_private_items)]
+pub(crate) struct AtomicStats {
+	pub(crate) decode_buffer:      AtomicUsize,
+	pub(crate) audio_backlog:      AtomicUsize,
+	pub(crate) gc_backlog:         AtomicUsize,
+	pub(crate) packets_decoded:    AtomicUsize,
+	pub(crate) packets_per_second: AtomicUsize,
+	pub(crate) decode_micros:      AtomicUsize, // Average decode time per packet in the last second
+	pub(crate) resampling:         AtomicBool,
+	pub(crate) output_sample_rate: AtomicU32,   // `0` == no output spec yet
+	pub(crate) output_channels:    AtomicU16,
+	pub(crate) signal_micros:      AtomicUsize, // Last `Engine` signal round-trip
+	pub(crate) messages_kernel:    AtomicUsize,
+	pub(crate) messages_decode:    AtomicUsize,
+	pub(crate) messages_audio:     AtomicUsize,
+	pub(crate) messages_gc:        AtomicUsize,
+	pub(crate) messages_caller:    AtomicUsize,
+}
+
+impl AtomicStats {
+	/// Everything set to `0`.
+	pub(crate) const fn new() -> Self {
+		Self {
+			decode_buffer:      AtomicUsize::new(0),
+			audio_backlog:      AtomicUsize::new(0),
+			gc_backlog:         AtomicUsize::new(0),
+			packets_decoded:    AtomicUsize::new(0),
+			packets_per_second: AtomicUsize::new(0),
+			decode_micros:      AtomicUsize::new(0),
+			resampling:         AtomicBool::new(false),
+			output_sample_rate: AtomicU32::new(0),
+			output_channels:    AtomicU16::new(0),
+			signal_micros:      AtomicUsize::new(0),
+			messages_kernel:    AtomicUsize::new(0),
+			messages_decode:    AtomicUsize::new(0),
+			messages_audio:     AtomicUsize::new(0),
+			messages_gc:        AtomicUsize::new(0),
+			messages_caller:    AtomicUsize::new(0),
+		}
+	}
+
+	#[inline]
+	/// Increment a counter by `1`.
+	pub(crate) fn inc(counter: &AtomicUsize) {
+		counter.fetch_add(1, Ordering::Relaxed);
+	}
+
+	#[inline]
+	/// Store a `Duration` into a microsecond counter.
+	pub(crate) fn store_duration(counter: &AtomicUsize, duration: Duration) {
+		counter.store(usize::try_from(duration.as_micros()).unwrap_or(usize::MAX), Ordering::Relaxed);
+	}
+
+	/// Store the spec of the audio being sent to the output.
+	pub(crate) fn store_output_spec(&self, spec: RenderSpec, resampling: bool) {
+		self.output_sample_rate.store(spec.sample_rate, Ordering::Relaxed);
+		self.output_channels.store(spec.channels, Ordering::Relaxed);
+		self.resampling.store(resampling, Ordering::Relaxed);
+	}
+
+	/// Create a `Stats` snapshot.
+	pub(crate) fn snapshot(&self) -> Stats {
+		/// Load a counter.
+		fn load(counter: &AtomicUsize) -> usize {
+			counter.load(Ordering::Relaxed)
+		}
+
+		/// Load a microsecond counter.
+		fn micros(counter: &AtomicUsize) -> Duration {
+			Duration::from_micros(load(counter) as u64)
+		}
+
+		let sample_rate = self.output_sample_rate.load(Ordering::Relaxed);
+		let output_spec = (sample_rate != 0).then(|| RenderSpec {
+			sample_rate,
+			channels: self.output_channels.load(Ordering::Relaxed),
+		});
+
+		Stats {
+			decode_buffer:      load(&self.decode_buffer),
+			audio_backlog:      load(&self.audio_backlog),
+			gc_backlog:         load(&self.gc_backlog),
+			packets_decoded:    load(&self.packets_decoded),
+			packets_per_second: load(&self.packets_per_second),
+			decode_time:        micros(&self.decode_micros),
+			resampling:         self.resampling.load(Ordering::Relaxed),
+			output_spec,
+			signal_latency:     micros(&self.signal_micros),
+			messages: Messages {
+				kernel: load(&self.messages_kernel),
+				decode: load(&self.messages_decode),
+				audio:  load(&self.messages_audio),
+				gc:     load(&self.messages_gc),
+				caller: load(&self.messages_caller),
+			},
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- DecodeRate
+/// Tracks `Decode`'s packets per second and average decode time.
+///
+/// Each second, the totals of the last second are stored into `AtomicStats`.
+#[derive(Debug)]
+pub(crate) struct DecodeRate {
+	/// When the current 1 second window started.
+	start: Instant,
+	/// Packets decoded in the current window.
+	packets: usize,
+	/// Total time spent decoding in the current window.
+	time: Duration,
+}
+
+impl DecodeRate {
+	/// Start a new window.
+	pub(crate) fn new() -> Self {
+		Self {
+			start: Instant::now(),
+			packets: 0,
+			time: Duration::ZERO,
+		}
+	}
+
+	/// Record a packet that took `time` to decode.
+	pub(crate) fn packet(&mut self, stats: &AtomicStats, time: Duration) {
+		AtomicStats::inc(&stats.packets_decoded);
+		self.packets += 1;
+		self.time += time;
+
+		if self.start.elapsed() >= Duration::from_secs(1) {
+			stats.packets_per_second.store(self.packets, Ordering::Relaxed);
+			AtomicStats::store_duration(&stats.decode_micros, self.time / u32::try_from(self.packets).unwrap_or(u32::MAX));
+			*self = Self::new();
+		}
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- Tests
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[test]
+	fn snapshot() {
+		let stats = AtomicStats::new();
+		assert_eq!(stats.snapshot(), Stats::default());
+
+		AtomicStats::inc(&stats.messages_kernel);
+		AtomicStats::inc(&stats.messages_kernel);
+		AtomicStats::store_duration(&stats.signal_micros, Duration::from_micros(123));
+		stats.store_output_spec(RenderSpec { sample_rate: 44_100, channels: 2 }, true);
+
+		let snapshot = stats.snapshot();
+		assert_eq!(snapshot.messages.kernel, 2);
+		assert_eq!(snapshot.signal_latency, Duration::from_micros(123));
+		assert_eq!(snapshot.output_spec, Some(RenderSpec { sample_rate: 44_100, channels: 2 }));
+		assert!(snapshot.resampling);
+	}
+
+	#[test]
+	fn decode_rate() {
+		let stats = AtomicStats::new();
+		let mut rate = DecodeRate::new();
+
+		rate.packet(&stats, Duration::from_micros(10));
+		rate.packet(&stats, Duration::from_micros(30));
+		assert_eq!(stats.packets_decoded.load(Ordering::Relaxed), 2);
+
+		// Force the window to end.
+		rate.start -= Duration::from_secs(1);
+		rate.packet(&stats, Duration::from_micros(20));
+		assert_eq!(stats.packets_decoded.load(Ordering::Relaxed), 3);
+		assert_eq!(stats.packets_per_second.load(Ordering::Relaxed), 3);
+		assert_eq!(stats.decode_micros.load(Ordering::Relaxed), 20);
+	}
+}
@@ -7,6 +7,9 @@ use crate::{
 	extra_data::ExtraData,
 	state::current::Current,
 };
+
+#[allow(unused_imports)] // docs
+use crate::config::InitConfig;
 use someday::{Reader, Commit, CommitRef};
 use std::{
 	sync::Arc,
@@ -17,6 +20,11 @@ use std::{
 
 //---------------------------------------------------------------------------------------------------- AudioState
 /// TODO
+///
+/// This struct is `#[non_exhaustive]`, new fields may be added
+/// without a breaking change, so it cannot be constructed with a
+/// struct literal outside of `sansan`, use [`AudioState::DEFAULT`].
+#[non_exhaustive]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
 #[derive(Clone,Debug,PartialEq)]
@@ -39,6 +47,19 @@ pub struct AudioState<Extra: ExtraData> {
 
 	/// The current song queue.
 	pub queue: VecDeque<Source<Extra>>,
+
+	/// Is the current audio output path bit-perfect?
+	///
+	/// This is `true` when [`InitConfig::bit_perfect`] is enabled _and_
+	/// the samples reach the output completely untouched, i.e. the output
+	/// was opened at the audio's native sample rate and channel count with
+	/// an `f32` sample format (no resampling, no channel mapping, no integer
+	/// conversion or dither, no volume).
+	///
+	/// This is always `false` if [`InitConfig::bit_perfect`] is disabled.
+	///
+	/// This field is ignored when restoring an [`AudioState`].
+	pub bit_perfect: bool,
 }
 
 //---------------------------------------------------------------------------------------------------- AudioState Impl
@@ -50,6 +71,7 @@ impl<Extra: ExtraData> AudioState<Extra> {
 		volume:  Volume::DEFAULT,
 		current: None,
 		queue:   VecDeque::new(),
+		bit_perfect: false,
 	};
 }
 
@@ -5,6 +5,7 @@ use crate::{
 	extra_data::ExtraData,
 	state::{AudioState,AudioStateSnapshot,AtomicState},
 	signal::{Repeat, Volume},
+	output::Glitches,
 };
 use someday::Reader;
 use std::{
@@ -62,6 +63,28 @@ impl<Extra: ExtraData> AudioStateReader<Extra> {
 	pub fn elapsed(&self) -> Option<f32> {
 		self.atomic.elapsed.load()
 	}
+
+	#[inline]
+	#[must_use]
+	/// How many playback glitches have occurred since the `Engine` was created?
+	///
+	/// See [`Glitch`](crate::output::Glitch) for what each counter means.
+	pub fn glitches(&self) -> Glitches {
+		Glitches {
+			underruns: self.atomic.underruns.load(Ordering::Relaxed),
+			overruns: self.atomic.overruns.load(Ordering::Relaxed),
+			late_writes: self.atomic.late_writes.load(Ordering::Relaxed),
+		}
+	}
+
+	#[inline]
+	#[must_use]
+	/// How many corrupt packets have been skipped since the `Engine` was created?
+	///
+	/// See [`InitConfig::error_decode_packet_threshold`](crate::config::InitConfig::error_decode_packet_threshold).
+	pub fn packets_skipped(&self) -> usize {
+		self.atomic.packets_skipped.load(Ordering::Relaxed)
+	}
 }
 
 //---------------------------------------------------------------------------------------------------- TESTS
@@ -12,8 +12,22 @@ pub use audio_state_reader::AudioStateReader;
 mod audio_state_snapshot;
 pub use audio_state_snapshot::AudioStateSnapshot;
 
+mod session;
+pub use session::Session;
+
 mod atomic_state;
 pub(crate) use atomic_state::AtomicState;
 
+mod stats;
+pub use stats::{Stats,Messages};
+
+#[cfg(feature = "stats")]
+mod atomic_stats;
+#[cfg(feature = "stats")]
+pub(crate) use atomic_stats::{AtomicStats,DecodeRate};
+
+mod priority;
+pub use priority::{Priority,Priorities};
+
 mod current;
 pub use current::Current;
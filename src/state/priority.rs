@@ -0,0 +1,57 @@
+//! Thread priority.
+
+//---------------------------------------------------------------------------------------------------- use
+use strum::{
+	AsRefStr,Display,EnumCount,EnumIter,EnumString,
+	EnumVariantNames,IntoStaticStr,
+};
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::{InitConfig,Realtime},
+};
+
+//---------------------------------------------------------------------------------------------------- Priority
+/// The scheduling priority of one of the [`Engine`]'s threads
+///
+/// See [`Realtime`] and [`Engine::priorities`].
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[derive(AsRefStr,Display,EnumCount,EnumIter,EnumString,EnumVariantNames,IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+	#[default]
+	/// Normal priority, promotion was not requested (or has not happened yet).
+	Normal,
+	/// The thread was promoted to real-time priority.
+	Realtime,
+	/// Promotion was requested, but the OS denied it.
+	///
+	/// The thread continues with normal priority.
+	Denied,
+}
+
+impl Priority {
+	/// Returns [`Self::Normal`].
+	pub const DEFAULT: Self = Self::Normal;
+}
+
+//---------------------------------------------------------------------------------------------------- Priorities
+/// The [`Priority`] of each of the [`Engine`]'s audio threads
+///
+/// This is returned by [`Engine::priorities`].
+#[derive(Copy,Clone,Default,Debug,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Priorities {
+	/// The thread writing to the audio output device.
+	///
+	/// This is always [`Priority::Normal`] with [`InitConfig::pull`],
+	/// as the host application's thread is the audio thread.
+	pub audio: Priority,
+	/// The thread decoding audio.
+	pub decode: Priority,
+}
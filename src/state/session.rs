@@ -0,0 +1,252 @@
+//! Persistent session.
+
+//---------------------------------------------------------------------------------------------------- Use
+use crate::{
+	config::RuntimeConfig,
+	extra_data::ExtraData,
+	state::AudioState,
+};
+
+#[cfg(feature = "bincode")]
+use crate::error::SessionError;
+#[cfg(feature = "bincode")]
+use std::path::Path;
+
+#[allow(unused_imports)] // docs
+use crate::{
+	Engine,
+	config::{InitConfig,SessionConfig},
+	state::Current,
+};
+
+//---------------------------------------------------------------------------------------------------- Session
+/// A saved playback session.
+///
+/// This is everything needed to pick up where the [`Engine`] left off:
+/// the [`AudioState`] (queue, [`Current`] index and elapsed time,
+/// repeat, volume) and the [`RuntimeConfig`].
+///
+/// The current session can be retrieved with [`Engine::session`], saved
+/// with [`Engine::save_session`], and automatically loaded/saved with
+/// [`InitConfig::session`].
+///
+/// ## Format
+/// With the `bincode` feature, [`Session::save`] and [`Session::to_bytes`] write:
+///
+/// | Bytes  | Data |
+/// |--------|------|
+/// | `0..6` | [`Session::MAGIC`]
+/// | `6..8` | [`Session::VERSION`] as a little-endian `u16`
+/// | `8..`  | The `Session` encoded with `bincode`'s standard config
+///
+/// [`Session::load`] and [`Session::from_bytes`] will migrate
+/// sessions written by older versions of the format.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+#[derive(Clone,Debug,PartialEq)]
+pub struct Session<Extra: ExtraData> {
+	/// The saved audio state.
+	pub audio_state: AudioState<Extra>,
+	/// The saved runtime config.
+	pub config: RuntimeConfig,
+}
+
+//---------------------------------------------------------------------------------------------------- Session Impl
+impl<Extra: ExtraData> Session<Extra> {
+	/// The bytes every session file starts with.
+	pub const MAGIC: [u8; 6] = *b"sansan";
+
+	/// The current version of the session format.
+	///
+	/// This is bumped each time the encoded layout of [`Session`] changes.
+	pub const VERSION: u16 = 1;
+}
+
+#[cfg(feature = "bincode")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bincode")))]
+impl<Extra> Session<Extra>
+where
+	Extra: ExtraData + bincode::Encode + bincode::Decode,
+{
+	/// The length of the header, i.e. [`Session::MAGIC`] + [`Session::VERSION`].
+	const HEADER_LEN: usize = Self::MAGIC.len() + std::mem::size_of::<u16>();
+
+	/// Encode this `Session` into bytes, with the header.
+	///
+	/// # Errors
+	/// This errors if `bincode` fails to encode the `Session`.
+	pub fn to_bytes(&self) -> Result<Vec<u8>, SessionError> {
+		let mut bytes = Vec::with_capacity(Self::HEADER_LEN);
+		bytes.extend_from_slice(&Self::MAGIC);
+		bytes.extend_from_slice(&Self::VERSION.to_le_bytes());
+
+		bincode::encode_into_std_write(self, &mut bytes, bincode::config::standard())
+			.map_err(|e| SessionError::Encode(e.to_string()))?;
+
+		Ok(bytes)
+	}
+
+	/// Decode a `Session` from bytes created with [`Session::to_bytes`].
+	///
+	/// Sessions from older format versions are migrated.
+	///
+	/// # Errors
+	/// This errors if the header is missing or from a newer
+	/// version than [`Session::VERSION`], or the data is malformed.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, SessionError> {
+		if bytes.len() < Self::HEADER_LEN || bytes[..Self::MAGIC.len()] != Self::MAGIC {
+			return Err(SessionError::Header);
+		}
+
+		let version = u16::from_le_bytes([bytes[Self::MAGIC.len()], bytes[Self::MAGIC.len() + 1]]);
+
+		Self::migrate(version, &bytes[Self::HEADER_LEN..])
+	}
+
+	/// Save this `Session` to a file.
+	///
+	/// The session is written to a temporary file next to `path` first,
+	/// which then replaces `path`, so a crash while saving never leaves
+	/// behind a half-written session.
+	///
+	/// # Errors
+	/// This errors if encoding or any file IO fails.
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SessionError> {
+		let path = path.as_ref();
+		let bytes = self.to_bytes()?;
+
+		let mut tmp = path.as_os_str().to_owned();
+		tmp.push(".tmp");
+
+		std::fs::write(&tmp, bytes)?;
+		std::fs::rename(&tmp, path)?;
+
+		Ok(())
+	}
+
+	/// Load a `Session` from a file created with [`Session::save`].
+	///
+	/// # Errors
+	/// This errors if the file could not be read, or [`Session::from_bytes`] fails.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, SessionError> {
+		Self::from_bytes(&std::fs::read(path)?)
+	}
+
+	/// Decode the `payload` (the bytes after the header)
+	/// written by format `version` into the current `Session`.
+	///
+	/// When [`Session::VERSION`] is bumped, the previous layout should be
+	/// kept around as its own type, decoded here, and converted, e.g:
+	///
+	/// ```ignore
+	/// if version == 1 {
+	///     return Self::decode::<SessionV1<Extra>>(payload).map(Self::from);
+	/// }
+	/// ```
+	fn migrate(version: u16, payload: &[u8]) -> Result<Self, SessionError> {
+		if version == Self::VERSION {
+			return Self::decode(payload);
+		}
+
+		Err(SessionError::Version(version))
+	}
+
+	/// Decode a `bincode` payload.
+	fn decode<T: bincode::Decode>(payload: &[u8]) -> Result<T, SessionError> {
+		bincode::decode_from_slice(payload, bincode::config::standard())
+			.map(|(t, _)| t)
+			.map_err(|e| SessionError::Decode(e.to_string()))
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- TESTS
+#[cfg(test)]
+#[cfg(any(feature = "serde", feature = "bincode"))]
+mod tests {
+	use super::*;
+	use crate::{
+		signal::{Repeat,Volume},
+		state::Current,
+	};
+	use pretty_assertions::assert_eq;
+	use std::time::Duration;
+
+	/// A `Session` with a non-default everything.
+	fn session() -> Session<usize> {
+		let mut audio_state = AudioState::DEFAULT;
+		for i in 0..5 {
+			audio_state.queue.push_back(crate::tests::source(i));
+		}
+		audio_state.current = Some(Current {
+			source: audio_state.queue[3].clone(),
+			index: 3,
+			elapsed: 12.5,
+		});
+		audio_state.repeat = Repeat::Queue;
+		audio_state.volume = Volume::new(0.25);
+
+		let mut config = RuntimeConfig::DEFAULT;
+		config.queue_end_clear = false;
+		config.back_threshold = Duration::from_secs(10);
+
+		Session { audio_state, config }
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	// The `serde` path works with any format, not only our `bincode` one.
+	fn json() {
+		let session = session();
+		let json = serde_json::to_string(&session).unwrap();
+		assert_eq!(serde_json::from_str::<Session<usize>>(&json).unwrap(), session);
+	}
+
+	#[test]
+	#[cfg(feature = "bincode")]
+	fn bytes() {
+		let session = session();
+		let bytes = session.to_bytes().unwrap();
+
+		assert_eq!(bytes[..6], *b"sansan");
+		assert_eq!(bytes[6..8], 1_u16.to_le_bytes());
+		assert_eq!(Session::from_bytes(&bytes).unwrap(), session);
+	}
+
+	#[test]
+	#[cfg(feature = "bincode")]
+	fn file() {
+		let dir = std::env::temp_dir().join(format!("sansan_session_{}", std::process::id()));
+		std::fs::create_dir_all(&dir).unwrap();
+		let path = dir.join("session.bin");
+
+		let session = session();
+		session.save(&path).unwrap();
+		assert!(!dir.join("session.bin.tmp").exists());
+		assert_eq!(Session::load(&path).unwrap(), session);
+
+		assert!(matches!(
+			Session::<usize>::load(dir.join("missing.bin")),
+			Err(SessionError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound,
+		));
+
+		std::fs::remove_dir_all(dir).unwrap();
+	}
+
+	#[test]
+	#[cfg(feature = "bincode")]
+	fn invalid() {
+		let mut bytes = session().to_bytes().unwrap();
+
+		// Truncated payload.
+		assert!(matches!(Session::<usize>::from_bytes(&bytes[..bytes.len() - 1]), Err(SessionError::Decode(_))));
+
+		// Newer version.
+		bytes[6..8].copy_from_slice(&2_u16.to_le_bytes());
+		assert!(matches!(Session::<usize>::from_bytes(&bytes), Err(SessionError::Version(2))));
+
+		// Not a session.
+		bytes[0] = b'x';
+		assert!(matches!(Session::<usize>::from_bytes(&bytes), Err(SessionError::Header)));
+		assert!(matches!(Session::<usize>::from_bytes(b"sansan"), Err(SessionError::Header)));
+	}
+}
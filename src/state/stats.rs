@@ -0,0 +1,79 @@
+//! Engine diagnostics.
+
+//---------------------------------------------------------------------------------------------------- use
+use std::time::Duration;
+use crate::render::RenderSpec;
+
+#[allow(unused_imports)] // docs
+use crate::Engine;
+
+//---------------------------------------------------------------------------------------------------- Stats
+/// A snapshot of the [`Engine`]'s internals
+///
+/// This is returned by [`Engine::stats`], which requires the `stats` feature.
+///
+/// These values are live and constantly changing, they are
+/// meant for observability (logging, metrics, diagnosing
+/// stutters in the field), not for driving playback logic.
+///
+/// Each value is updated independently, so a [`Stats`] may
+/// contain values from slightly different points in time.
+#[derive(Copy,Clone,Debug,Default,PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Stats {
+	/// How many decoded audio buffers `Decode` is holding,
+	/// waiting for `Audio` to be ready to receive them.
+	pub decode_buffer: usize,
+	/// How many decoded audio buffers have been sent to `Audio`, but not yet played.
+	pub audio_backlog: usize,
+	/// How many objects are waiting to be dropped by the `Gc` actor.
+	///
+	/// If this keeps growing, `Gc` is not keeping up.
+	pub gc_backlog: usize,
+
+	/// How many packets have been decoded in total.
+	pub packets_decoded: usize,
+	/// How many packets were decoded in the last second.
+	pub packets_per_second: usize,
+	/// The average time taken to decode a packet in the last second.
+	pub decode_time: Duration,
+
+	/// Is audio currently being resampled before being sent to the output?
+	pub resampling: bool,
+	/// The spec of the audio currently being sent to the output.
+	///
+	/// This is the spec _before_ any resampling,
+	/// `None` if no audio has been played yet.
+	pub output_spec: Option<RenderSpec>,
+
+	/// The round-trip latency of the last `Engine`
+	/// signal, e.g. [`Engine::play`], [`Engine::add`].
+	///
+	/// This is how long `Kernel` took to receive, handle,
+	/// and respond to the signal (as seen by the `Engine`).
+	pub signal_latency: Duration,
+
+	/// How many messages each actor has handled.
+	pub messages: Messages,
+}
+
+//---------------------------------------------------------------------------------------------------- Messages
+/// How many messages each of the [`Engine`]'s actors have handled
+///
+/// See [`Stats::messages`].
+#[derive(Copy,Clone,Debug,Default,PartialEq,PartialOrd,Eq,Ord,Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bincode", derive(bincode::Encode, bincode::Decode))]
+pub struct Messages {
+	/// `Kernel`, which handles signals from the `Engine` and the other actors.
+	pub kernel: usize,
+	/// `Decode`, which decodes audio.
+	pub decode: usize,
+	/// `Audio`, which writes audio to the output (audio buffers are counted).
+	pub audio: usize,
+	/// `Gc`, which drops objects (each object is counted).
+	pub gc: usize,
+	/// `Caller`, which calls the [`Callbacks`](crate::config::Callbacks).
+	pub caller: usize,
+}
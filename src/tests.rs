@@ -8,6 +8,7 @@ use crate::{
 	state::{AudioState,Current,AtomicState},
 	extra_data::ExtraData,
 };
+use std::sync::{Mutex,MutexGuard,PoisonError};
 
 //---------------------------------------------------------------------------------------------------- Test Init Helpers
 /// Create a `Source` with a specified `usize` as the `Data`.
@@ -16,6 +17,13 @@ pub(crate) fn source(data: usize) -> Source<usize> {
 	Source::from((path, data))
 }
 
+/// Create a `Source` with invalid audio bytes and a specified `usize` as the `Data`.
+///
+/// Loading this will always produce a `SourceError`.
+pub(crate) fn source_broken(data: usize) -> Source<usize> {
+	Source::from((vec![0_u8; 64], data))
+}
+
 /// Returns `Sources` with `0..=9` as the `Data`.
 pub(crate) fn sources() -> Sources<usize> {
 	Sources::from_1_and_iter(
@@ -24,8 +32,23 @@ pub(crate) fn sources() -> Sources<usize> {
 	].into_iter())
 }
 
+/// The dummy output devices are global, tests that
+/// change them (or depend on them) must hold this lock.
+static DUMMY_DEVICES: Mutex<()> = Mutex::new(());
+
+/// Lock the dummy output devices for the duration of a test.
+pub(crate) fn dummy_devices_lock() -> MutexGuard<'static, ()> {
+	// A failed test shouldn't fail the others.
+	DUMMY_DEVICES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 /// Init the `Engine` with a default `InitConfig`.
 pub(crate) fn init() -> Engine::<usize> {
+	init_with_config(InitConfig::DEFAULT)
+}
+
+/// Init the `Engine` with a custom `InitConfig`.
+pub(crate) fn init_with_config(config: InitConfig<usize>) -> Engine::<usize> {
 	// Set custom panic hook.
 	// No threads should be panicking in tests.
 	std::panic::set_hook(Box::new(move |panic_info| {
@@ -34,7 +57,7 @@ pub(crate) fn init() -> Engine::<usize> {
 		std::process::exit(1);
 	}));
 
-	Engine::<usize>::init(InitConfig::DEFAULT)
+	Engine::<usize>::init(config)
 }
 
 /// Init the `Engine` with 10 sources in the queue and a modified audio state.
@@ -86,4 +109,17 @@ pub(crate) fn sources_70_80_90() -> Sources<usize> {
 /// Return 3 `Source`'s with `77, 88, 99` as the `Data`.
 pub(crate) fn sources_77_88_99() -> Sources<usize> {
 	Sources::from_1_and_iter(source(77), [source(88), source(99)].into_iter())
-}
\ No newline at end of file
+}
+//---------------------------------------------------------------------------------------------------- Audio Helpers
+/// Create a stereo 44.1kHz `AudioBuffer` with `frames` frames,
+/// the left channel set to `left` and the right channel set to `right`.
+pub(crate) fn audio(frames: usize, left: f32, right: f32) -> symphonia::core::audio::AudioBuffer<f32> {
+	use symphonia::core::audio::{AudioBuffer,Channels,Signal,SignalSpec};
+
+	let spec = SignalSpec::new(44_100, Channels::FRONT_LEFT | Channels::FRONT_RIGHT);
+	let mut audio = AudioBuffer::<f32>::new(frames as u64, spec);
+	audio.render_reserved(Some(frames));
+	audio.chan_mut(0).fill(left);
+	audio.chan_mut(1).fill(right);
+	audio
+}